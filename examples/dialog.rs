@@ -0,0 +1,155 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::callbacks::widget_id_for_name;
+use pushrod::render::engine::Engine;
+use pushrod::render::widget::Widget;
+use pushrod::render::widget_config::CONFIG_COLOR_TEXT;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::dialog_widget::*;
+use pushrod::widgets::push_button_widget::PushButtonWidget;
+use pushrod::widgets::text_widget::{TextJustify, TextWidget};
+use sdl2::pixels::Color;
+
+#[macro_export]
+macro_rules! cast {
+    ($a:expr, $b:expr, $c:ident) => {
+        $a[$b]
+            .widget
+            .borrow_mut()
+            .as_any()
+            .downcast_mut::<$c>()
+            .unwrap()
+    };
+}
+
+/// Shows the result of a dialog in the status text.
+fn show_result(widgets: &[pushrod::render::widget_cache::WidgetContainer], result: DialogResult) {
+    let text_id = widget_id_for_name(widgets, String::from("status"));
+
+    cast!(widgets, text_id, TextWidget).set_text(format!("Result: {:?}", result));
+}
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render dialog demo", 500, 300)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(500, 300, 60);
+    let dialog_points = make_points(75, 60);
+    let dialog_size = make_size(350, 180);
+    let mut message_button = PushButtonWidget::new(
+        make_points(20, 20),
+        make_size(140, 40),
+        String::from("Message"),
+        18,
+    );
+    let mut confirm_button = PushButtonWidget::new(
+        make_points(180, 20),
+        make_size(140, 40),
+        String::from("Confirm"),
+        18,
+    );
+    let mut prompt_button = PushButtonWidget::new(
+        make_points(340, 20),
+        make_size(140, 40),
+        String::from("Prompt"),
+        18,
+    );
+    let mut status_widget = TextWidget::new(
+        String::from("assets/OpenSans-Regular.ttf"),
+        sdl2::ttf::FontStyle::NORMAL,
+        16,
+        TextJustify::Left,
+        String::from("Close the window to see a confirmation."),
+        make_points(20, 250),
+        make_size(460, 30),
+    );
+    let mut message_dialog = DialogWidget::message_box(
+        dialog_points.clone(),
+        dialog_size.clone(),
+        String::from("Message"),
+        String::from(
+            "This is a message box.  Everything behind it is dimmed, and cannot be clicked.",
+        ),
+    );
+    let mut confirm_dialog = DialogWidget::confirm(
+        dialog_points.clone(),
+        dialog_size.clone(),
+        String::from("Confirm"),
+        String::from("Save changes?"),
+    );
+    let mut prompt_dialog = DialogWidget::prompt(
+        dialog_points.clone(),
+        dialog_size.clone(),
+        String::from("Prompt"),
+        String::from("Enter your name:"),
+        String::from("Pushrod"),
+    );
+    let mut quit_dialog = DialogWidget::confirm(
+        dialog_points,
+        dialog_size,
+        String::from("Quit"),
+        String::from("Are you sure you want to quit?"),
+    );
+
+    status_widget.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+
+    message_button.on_click(|_, _widgets, _layouts| {
+        let dialog_id = widget_id_for_name(_widgets, String::from("message_dialog"));
+
+        cast!(_widgets, dialog_id, DialogWidget).open();
+    });
+
+    confirm_button.on_click(|_, _widgets, _layouts| {
+        let dialog_id = widget_id_for_name(_widgets, String::from("confirm_dialog"));
+
+        cast!(_widgets, dialog_id, DialogWidget).open();
+    });
+
+    prompt_button.on_click(|_, _widgets, _layouts| {
+        let dialog_id = widget_id_for_name(_widgets, String::from("prompt_dialog"));
+
+        cast!(_widgets, dialog_id, DialogWidget).open();
+    });
+
+    message_dialog.on_result(|_, _widgets, _layouts, result| show_result(_widgets, result));
+    confirm_dialog.on_result(|_, _widgets, _layouts, result| show_result(_widgets, result));
+    prompt_dialog.on_result(|_, _widgets, _layouts, result| show_result(_widgets, result));
+    quit_dialog.set_button_labels(vec![String::from("Quit"), String::from("Stay")]);
+    quit_dialog.on_result(|_, _widgets, _layouts, result| {
+        if result == DialogResult::DialogOk {
+            std::process::exit(0);
+        }
+    });
+
+    engine.add_widget(Box::new(message_button), String::from("message_button"));
+    engine.add_widget(Box::new(confirm_button), String::from("confirm_button"));
+    engine.add_widget(Box::new(prompt_button), String::from("prompt_button"));
+    engine.add_widget(Box::new(status_widget), String::from("status"));
+    engine.add_widget(Box::new(message_dialog), String::from("message_dialog"));
+    engine.add_widget(Box::new(confirm_dialog), String::from("confirm_dialog"));
+    engine.add_widget(Box::new(prompt_dialog), String::from("prompt_dialog"));
+    engine.add_widget(Box::new(quit_dialog), String::from("quit_dialog"));
+
+    // Instead of quitting right away, ask first.
+    engine.on_exit(|engine| {
+        engine
+            .get_widget_cache()
+            .get_container_by_name(String::from("quit_dialog"))
+            .widget
+            .borrow_mut()
+            .as_any()
+            .downcast_mut::<DialogWidget>()
+            .unwrap()
+            .open();
+
+        false
+    });
+
+    engine.run(sdl_context, window);
+}
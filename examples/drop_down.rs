@@ -0,0 +1,82 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::callbacks::widget_id_for_name;
+use pushrod::render::engine::Engine;
+use pushrod::render::widget::Widget;
+use pushrod::render::widget_config::CONFIG_COLOR_TEXT;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::drop_down_widget::*;
+use pushrod::widgets::text_widget::{TextJustify, TextWidget};
+use sdl2::pixels::Color;
+
+#[macro_export]
+macro_rules! cast {
+    ($a:expr, $b:expr, $c:ident) => {
+        $a[$b]
+            .widget
+            .borrow_mut()
+            .as_any()
+            .downcast_mut::<$c>()
+            .unwrap()
+    };
+}
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render drop down demo", 400, 300)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(400, 300, 60);
+    let mut widget1 = DropDownWidget::new(make_points(20, 20), make_size(200, 30));
+    let mut widget2 = DropDownWidget::new(make_points(20, 250), make_size(200, 30));
+    let mut text_widget = TextWidget::new(
+        String::from("assets/OpenSans-Regular.ttf"),
+        sdl2::ttf::FontStyle::NORMAL,
+        16,
+        TextJustify::Left,
+        String::from("Select a color"),
+        make_points(20, 60),
+        make_size(360, 30),
+    );
+
+    text_widget.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+
+    for color in &[
+        "Red", "Orange", "Yellow", "Green", "Blue", "Indigo", "Violet",
+    ] {
+        widget1.add_item(String::from(*color));
+    }
+
+    widget1.set_selected(0);
+    widget1.on_selected(|x, _widgets, _layouts, selected_item| {
+        let text_id = widget_id_for_name(_widgets, String::from("text1"));
+
+        cast!(_widgets, text_id, TextWidget).set_text(format!(
+            "Selected color {}: {}",
+            selected_item,
+            x.get_selected_item().unwrap()
+        ));
+    });
+
+    // This list is near the bottom of the window, so its popup opens above it.
+    for i in 1..=30 {
+        widget2.add_item(format!("Item {}", i));
+    }
+
+    widget2.on_selected(|x, _widgets, _layouts, _selected_item| {
+        eprintln!("Selected: {}", x.get_selected_item().unwrap());
+    });
+
+    engine.add_widget(Box::new(widget1), String::from("widget1"));
+    engine.add_widget(Box::new(widget2), String::from("widget2"));
+
+    // This is added after the first drop down, but is still covered by its popup.
+    engine.add_widget(Box::new(text_widget), String::from("text1"));
+
+    engine.run(sdl_context, window);
+}
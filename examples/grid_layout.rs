@@ -0,0 +1,81 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::layouts::grid_layout::GridLayout;
+use pushrod::render::engine::Engine;
+use pushrod::render::layout::{Layout, LayoutPosition};
+use pushrod::render::widget::Widget;
+use pushrod::render::widget_config::{
+    PaddingConstraint, CONFIG_BORDER_WIDTH, CONFIG_COLOR_BORDER, CONFIG_COLOR_TEXT,
+};
+use pushrod::render::{make_points_origin, make_size};
+use pushrod::widgets::push_button_widget::PushButtonWidget;
+use pushrod::widgets::text_field_widget::TextFieldWidget;
+use pushrod::widgets::text_widget::{TextJustify, TextWidget};
+use sdl2::pixels::Color;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render grid layout demo", 400, 220)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(400, 220, 60);
+    let mut layout = GridLayout::new(
+        0,
+        0,
+        400,
+        220,
+        vec![2, 4],
+        PaddingConstraint::new(20, 20, 20, 20, 10),
+    );
+    let labels = ["Name:", "E-mail:", "Phone:"];
+
+    layout.set_column_weight(0, 1);
+    layout.set_column_weight(1, 3);
+
+    for (row, label) in labels.iter().enumerate() {
+        let mut text_widget = TextWidget::new(
+            String::from("assets/OpenSans-Regular.ttf"),
+            sdl2::ttf::FontStyle::NORMAL,
+            16,
+            TextJustify::Right,
+            String::from(*label),
+            make_points_origin(),
+            make_size(0, 0),
+        );
+
+        text_widget
+            .get_config()
+            .set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+
+        let field_widget =
+            TextFieldWidget::new(make_points_origin(), make_size(0, 0), String::from(""), 16);
+
+        let text_id = engine.add_widget(Box::new(text_widget), format!("label{}", row));
+        let field_id = engine.add_widget(Box::new(field_widget), format!("field{}", row));
+
+        layout.insert_widget(text_id, LayoutPosition::new(0, row as i32));
+        layout.insert_widget(field_id, LayoutPosition::new(1, row as i32));
+    }
+
+    let mut button = PushButtonWidget::new(
+        make_points_origin(),
+        make_size(0, 0),
+        String::from("Save"),
+        18,
+    );
+
+    button.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+    button.set_numeric(CONFIG_BORDER_WIDTH, 2);
+
+    let button_id = engine.add_widget(Box::new(button), String::from("button"));
+
+    layout.insert_widget_spanned(button_id, LayoutPosition::new(0, 3), 2, 1);
+    engine.add_layout(Box::new(layout));
+
+    engine.run(sdl_context, window);
+}
@@ -0,0 +1,69 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::hidden_window::HiddenWindowCanvas;
+use pushrod::render::widget::Widget;
+use pushrod::render::widget_config::{CONFIG_BORDER_WIDTH, CONFIG_COLOR_BORDER};
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::checkbox_widget::CheckboxWidget;
+use pushrod::widgets::progress_widget::ProgressWidget;
+use pushrod::widgets::push_button_widget::PushButtonWidget;
+use sdl2::pixels::Color;
+use std::env;
+
+/// Renders a small set of `Widget`s into a hidden `Window`.  Run with `save <file.png>` to write the
+/// rendered image, or `compare <file.png>` to compare the rendered image against a golden image.
+/// Set `SDL_VIDEODRIVER=dummy` to run without a display.
+pub fn main() {
+    let args: Vec<String> = env::args().collect();
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let mut hidden_window = HiddenWindowCanvas::new(&video_subsystem, 400, 180).unwrap();
+    let mut engine = Engine::new(400, 180, 60);
+    let mut button = PushButtonWidget::new(
+        make_points(20, 20),
+        make_size(360, 40),
+        String::from("Hidden Window Button"),
+        20,
+    );
+
+    button.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+    button.set_numeric(CONFIG_BORDER_WIDTH, 2);
+
+    let checkbox = CheckboxWidget::new(
+        make_points(20, 80),
+        make_size(360, 30),
+        String::from(" Checked Checkbox"),
+        20,
+        true,
+    );
+    let mut progress = ProgressWidget::new(make_points(20, 130), make_size(360, 30), 50);
+
+    progress.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+    progress.set_numeric(CONFIG_BORDER_WIDTH, 1);
+
+    engine.add_widget(Box::new(button), String::from("button"));
+    engine.add_widget(Box::new(checkbox), String::from("checkbox"));
+    engine.add_widget(Box::new(progress), String::from("progress"));
+
+    hidden_window.render(&mut engine);
+
+    match (args.get(1).map(|x| x.as_str()), args.get(2)) {
+        (Some("save"), Some(file)) => {
+            hidden_window.save_png(file).unwrap();
+            eprintln!("Saved {}", file);
+        }
+        (Some("compare"), Some(file)) => {
+            let mismatched = hidden_window.compare_png(file, 2).unwrap();
+
+            if mismatched > 0 {
+                eprintln!("{} pixels do not match {}", mismatched, file);
+                std::process::exit(1);
+            }
+
+            eprintln!("Image matches {}", file);
+        }
+        _ => eprintln!("Usage: hidden_window [save|compare] <file.png>"),
+    }
+}
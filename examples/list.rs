@@ -21,7 +21,8 @@ pub fn main() {
         .build()
         .unwrap();
     let mut engine = Engine::new(400, 300, 60);
-    let mut widget1 = ListWidget::new(make_points(20, 20), make_size(200, 260));
+    let mut widget1 = ListWidget::new(make_points(20, 20), make_size(170, 260));
+    let mut widget2 = ListWidget::new(make_points(210, 20), make_size(170, 260));
 
     widget1.set_color(CONFIG_COLOR_BASE, Color::RGB(255, 255, 255));
     widget1.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
@@ -38,7 +39,20 @@ pub fn main() {
         eprintln!("Selected: {}", selected_item);
     });
 
+    widget2.set_color(CONFIG_COLOR_BASE, Color::RGB(255, 255, 255));
+    widget2.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+    widget2.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+    widget2.set_selection_mode(ListSelectionMode::MultipleSelection);
+    widget2.set_row_height(24);
+    widget2.set_font(String::from("assets/OpenSans-Regular.ttf"), 14);
+    widget2.set_items((1..=300).map(|x| format!("Device {}", x)).collect());
+
+    widget2.on_selected(|x, _widgets, _layout, _selected_item| {
+        eprintln!("Selected: {:?}", x.get_selected());
+    });
+
     engine.add_widget(Box::new(widget1), String::from("widget1"));
+    engine.add_widget(Box::new(widget2), String::from("widget2"));
 
     engine.run(sdl_context, window);
 }
@@ -0,0 +1,192 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::callbacks::widget_id_for_name;
+use pushrod::render::engine::Engine;
+use pushrod::render::widget::{BaseWidget, Widget};
+use pushrod::render::widget_config::{
+    CONFIG_BORDER_WIDTH, CONFIG_COLOR_BASE, CONFIG_COLOR_BORDER, CONFIG_COLOR_TEXT,
+};
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::context_menu_widget::ContextMenuWidget;
+use pushrod::widgets::menu_bar_widget::MenuBarWidget;
+use pushrod::widgets::popup_menu::MenuItem;
+use pushrod::widgets::text_widget::{TextJustify, TextWidget};
+use sdl2::pixels::Color;
+
+#[macro_export]
+macro_rules! cast {
+    ($a:expr, $b:expr, $c:ident) => {
+        $a[$b]
+            .widget
+            .borrow_mut()
+            .as_any()
+            .downcast_mut::<$c>()
+            .unwrap()
+    };
+}
+
+/// Creates a colored box that a context menu can be opened over.
+fn make_box(x: i32, color: Color) -> BaseWidget {
+    let mut widget = BaseWidget::new(make_points(x, 80), make_size(230, 150));
+
+    widget.get_config().set_color(CONFIG_COLOR_BASE, color);
+    widget
+        .get_config()
+        .set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+    widget.get_config().set_numeric(CONFIG_BORDER_WIDTH, 1);
+    widget
+}
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render menu demo", 500, 300)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(500, 300, 60);
+    let mut menu_bar = MenuBarWidget::new(make_points(0, 0), make_size(500, 28));
+    let mut context_menu = ContextMenuWidget::new(vec![
+        MenuItem::new(20, String::from("Cut")).with_shortcut(String::from("Ctrl+X")),
+        MenuItem::new(21, String::from("Copy")).with_shortcut(String::from("Ctrl+C")),
+        MenuItem::new(22, String::from("Paste"))
+            .with_shortcut(String::from("Ctrl+V"))
+            .with_enabled(false),
+        MenuItem::separator(),
+        MenuItem::submenu(
+            String::from("Color"),
+            vec![
+                MenuItem::new(30, String::from("Red")),
+                MenuItem::new(31, String::from("Green")),
+                MenuItem::new(32, String::from("Blue")),
+            ],
+        ),
+    ]);
+    let mut text_widget = TextWidget::new(
+        String::from("assets/OpenSans-Regular.ttf"),
+        sdl2::ttf::FontStyle::NORMAL,
+        16,
+        TextJustify::Left,
+        String::from("Choose a menu item, or right-click on a box."),
+        make_points(20, 250),
+        make_size(460, 30),
+    );
+
+    text_widget.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+
+    menu_bar.add_menu(
+        String::from("File"),
+        vec![
+            MenuItem::new(1, String::from("New")).with_shortcut(String::from("Ctrl+N")),
+            MenuItem::new(2, String::from("Open...")).with_shortcut(String::from("Ctrl+O")),
+            MenuItem::submenu(
+                String::from("Open Recent"),
+                vec![
+                    MenuItem::new(3, String::from("notes.txt")),
+                    MenuItem::new(4, String::from("todo.txt")),
+                ],
+            ),
+            MenuItem::new(5, String::from("Save"))
+                .with_shortcut(String::from("Ctrl+S"))
+                .with_enabled(false),
+            MenuItem::separator(),
+            MenuItem::new(6, String::from("Quit")).with_shortcut(String::from("Ctrl+Q")),
+        ],
+    );
+    menu_bar.add_menu(
+        String::from("View"),
+        vec![
+            MenuItem::new(10, String::from("Show Grid")).with_check(true),
+            MenuItem::new(11, String::from("Show Rulers")).with_check(false),
+            MenuItem::separator(),
+            MenuItem::submenu(
+                String::from("Zoom"),
+                vec![
+                    MenuItem::new(12, String::from("Zoom In"))
+                        .with_shortcut(String::from("Ctrl++")),
+                    MenuItem::new(13, String::from("Zoom Out"))
+                        .with_shortcut(String::from("Ctrl+-")),
+                    MenuItem::submenu(
+                        String::from("Preset"),
+                        vec![
+                            MenuItem::new(14, String::from("50%")),
+                            MenuItem::new(15, String::from("100%")),
+                            MenuItem::new(16, String::from("200%")),
+                        ],
+                    ),
+                ],
+            ),
+        ],
+    );
+    menu_bar.add_menu(
+        String::from("Help"),
+        vec![MenuItem::new(40, String::from("About"))],
+    );
+
+    menu_bar.on_selected(|x, _widgets, _layouts, id| {
+        let text_id = widget_id_for_name(_widgets, String::from("text1"));
+        let item = x.get_item(id).unwrap();
+        let text = if item.checkable {
+            format!(
+                "{} is now {}",
+                item.label,
+                if item.checked { "on" } else { "off" }
+            )
+        } else {
+            format!("Selected {} (ID {})", item.label, id)
+        };
+
+        if id == 6 {
+            std::process::exit(0);
+        }
+
+        cast!(_widgets, text_id, TextWidget).set_text(text);
+    });
+
+    context_menu.on_selected(|x, _widgets, _layouts, id| {
+        let text_id = widget_id_for_name(_widgets, String::from("text1"));
+        let source_id = x.get_source_widget_id() as usize;
+        let color = match id {
+            30 => Some(Color::RGB(255, 128, 128)),
+            31 => Some(Color::RGB(128, 255, 128)),
+            32 => Some(Color::RGB(128, 128, 255)),
+            _ => None,
+        };
+
+        if let Some(color) = color {
+            let mut source = _widgets[source_id].widget.borrow_mut();
+
+            source.get_config().set_color(CONFIG_COLOR_BASE, color);
+            source.get_config().set_invalidated(true);
+        }
+
+        cast!(_widgets, text_id, TextWidget).set_text(format!(
+            "{} on {}",
+            x.get_item(id).unwrap().label,
+            _widgets[source_id].get_widget_name()
+        ));
+    });
+
+    let box1_id = engine.add_widget(
+        Box::new(make_box(20, Color::RGB(255, 255, 200))),
+        String::from("box1"),
+    );
+    let box2_id = engine.add_widget(
+        Box::new(make_box(250, Color::RGB(200, 255, 255))),
+        String::from("box2"),
+    );
+
+    engine.add_widget(Box::new(text_widget), String::from("text1"));
+    engine.add_widget(Box::new(menu_bar), String::from("menu_bar"));
+
+    let context_menu_id = engine.add_widget(Box::new(context_menu), String::from("context_menu"));
+
+    // Both boxes share the same context menu.
+    engine.set_context_menu(box1_id, context_menu_id);
+    engine.set_context_menu(box2_id, context_menu_id);
+
+    engine.run(sdl_context, window);
+}
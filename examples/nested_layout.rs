@@ -0,0 +1,58 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::layouts::horizontal_layout::HorizontalLayout;
+use pushrod::layouts::vertical_layout::VerticalLayout;
+use pushrod::render::engine::Engine;
+use pushrod::render::layout::{Anchor, Layout};
+use pushrod::render::widget::{BaseWidget, Widget};
+use pushrod::render::widget_config::{
+    PaddingConstraint, CONFIG_BORDER_WIDTH, CONFIG_COLOR_BORDER, CONFIG_STRETCH,
+};
+use pushrod::render::{make_points_origin, make_size};
+use sdl2::pixels::Color;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render nested layout demo", 400, 300)
+        .position_centered()
+        .resizable()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(400, 300, 60);
+    let mut outer_layout =
+        VerticalLayout::new(0, 0, 400, 300, PaddingConstraint::new(10, 10, 10, 10, 10));
+
+    for row in 0..3 {
+        let mut row_layout =
+            HorizontalLayout::new(0, 0, 0, 0, PaddingConstraint::new(0, 0, 0, 0, 10));
+
+        for column in 0..=row {
+            let mut widget = BaseWidget::new(make_points_origin(), make_size(0, 0));
+
+            widget
+                .get_config()
+                .set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+            widget.get_config().set_numeric(CONFIG_BORDER_WIDTH, 2);
+            widget.get_config().set_numeric(CONFIG_STRETCH, column + 1);
+
+            let widget_id =
+                engine.add_widget(Box::new(widget), format!("widget{}_{}", row, column));
+
+            row_layout.append_widget(widget_id);
+        }
+
+        let row_layout_id = engine.add_layout(Box::new(row_layout));
+
+        outer_layout.append_layout(row_layout_id);
+    }
+
+    let outer_layout_id = engine.add_layout(Box::new(outer_layout));
+
+    engine.set_layout_anchor(outer_layout_id, Anchor::new(true, true, true, true));
+
+    engine.run(sdl_context, window);
+}
@@ -12,12 +12,12 @@ pub fn main() {
     let sdl_context = sdl2::init().unwrap();
     let video_subsystem = sdl_context.video().unwrap();
     let window = video_subsystem
-        .window("pushrod-render progress demo", 400, 180)
+        .window("pushrod-render progress demo", 480, 280)
         .position_centered()
         .opengl()
         .build()
         .unwrap();
-    let mut engine = Engine::new(400, 180, 60);
+    let mut engine = Engine::new(480, 280, 60);
     let mut widget1 = ProgressWidget::new(make_points(20, 20), make_size(360, 40), 25);
 
     widget1.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(255, 0, 0));
@@ -25,14 +25,46 @@ pub fn main() {
     let mut widget2 = ProgressWidget::new(make_points(20, 70), make_size(360, 40), 50);
 
     widget2.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(255, 0, 0));
+    widget2.set_label_visible(true);
 
-    let mut widget3 = ProgressWidget::new(make_points(20, 120), make_size(360, 40), 75);
+    // A custom range, with a formatter for the label.
+    let mut widget3 = ProgressWidget::new(make_points(20, 120), make_size(360, 40), 0);
 
     widget3.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(255, 0, 0));
+    widget3.set_range(0.0, 2048.0);
+    widget3.set_value(1536.0);
+    widget3.set_label_formatter(|value, _percentage| format!("{} of 2048 KB", value));
+
+    let mut widget4 = ProgressWidget::new(make_points(20, 170), make_size(360, 40), 75);
+
+    widget4.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(0, 160, 0));
+    widget4.set_segments(10);
+
+    // An indeterminate progress bar, for work that cannot report how far along it is.
+    let mut widget5 = ProgressWidget::new(make_points(20, 220), make_size(360, 40), 0);
+
+    widget5.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(0, 0, 255));
+    widget5.set_indeterminate(true);
+    widget5.set_label_formatter(|_value, _percentage| String::from("Importing..."));
+
+    let mut widget6 = ProgressWidget::new(make_points(400, 20), make_size(24, 240), 60);
+
+    widget6.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(255, 0, 0));
+    widget6.set_orientation(ProgressOrientation::ProgressVertical);
+
+    let mut widget7 = ProgressWidget::new(make_points(436, 20), make_size(24, 240), 0);
+
+    widget7.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(0, 0, 255));
+    widget7.set_orientation(ProgressOrientation::ProgressVertical);
+    widget7.set_indeterminate(true);
 
     engine.add_widget(Box::new(widget1), String::from("widget1"));
     engine.add_widget(Box::new(widget2), String::from("widget2"));
     engine.add_widget(Box::new(widget3), String::from("widget3"));
+    engine.add_widget(Box::new(widget4), String::from("widget4"));
+    engine.add_widget(Box::new(widget5), String::from("widget5"));
+    engine.add_widget(Box::new(widget6), String::from("widget6"));
+    engine.add_widget(Box::new(widget7), String::from("widget7"));
 
     engine.run(sdl_context, window);
 }
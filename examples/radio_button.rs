@@ -0,0 +1,52 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::radio_button_widget::*;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render radio button demo", 400, 180)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(400, 180, 60);
+    let group = RadioGroup::new();
+
+    group.set_selected(Some(1));
+    group.on_selection_changed(|_, _widgets, _layouts, index| {
+        eprintln!("Selected radio button {}", index);
+    });
+
+    let widget1 = RadioButtonWidget::new(
+        make_points(20, 20),
+        make_size(360, 30),
+        String::from(" Radio Item 1"),
+        22,
+        group.clone(),
+    );
+    let widget2 = RadioButtonWidget::new(
+        make_points(20, 70),
+        make_size(360, 30),
+        String::from(" Radio Item 2"),
+        22,
+        group.clone(),
+    );
+    let widget3 = RadioButtonWidget::new(
+        make_points(20, 120),
+        make_size(360, 30),
+        String::from(" Radio Item 3"),
+        22,
+        group,
+    );
+
+    engine.add_widget(Box::new(widget1), String::from("widget1"));
+    engine.add_widget(Box::new(widget2), String::from("widget2"));
+    engine.add_widget(Box::new(widget3), String::from("widget3"));
+
+    engine.run(sdl_context, window);
+}
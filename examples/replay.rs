@@ -0,0 +1,75 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::event_recorder::{EventPlayer, EventRecorder};
+use pushrod::render::hidden_window::HiddenWindowCanvas;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::text_field_widget::TextFieldWidget;
+use std::env;
+
+fn build_engine() -> Engine {
+    let mut engine = Engine::new(400, 70, 60);
+    let widget = TextFieldWidget::new(
+        make_points(20, 20),
+        make_size(360, 30),
+        String::from(""),
+        18,
+    );
+
+    engine.add_widget(Box::new(widget), String::from("text_field"));
+    engine
+}
+
+/// Run with `record <file>` to record a session of typing into a text field, and with
+/// `replay <file>` to replay the session into a hidden window, printing the resulting text.  Set
+/// `SDL_VIDEODRIVER=dummy` to replay without a display.
+pub fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    match (args.get(1).map(|x| x.as_str()), args.get(2)) {
+        (Some("record"), Some(file)) => {
+            let sdl_context = sdl2::init().unwrap();
+            let video_subsystem = sdl_context.video().unwrap();
+            let window = video_subsystem
+                .window("pushrod-render event recorder demo", 400, 70)
+                .position_centered()
+                .opengl()
+                .build()
+                .unwrap();
+            let mut engine = build_engine();
+
+            engine.set_event_recorder(EventRecorder::new(file).unwrap());
+            engine.run(sdl_context, window);
+        }
+        (Some("replay"), Some(file)) => {
+            let sdl_context = sdl2::init().unwrap();
+            let video_subsystem = sdl_context.video().unwrap();
+            let mut hidden_window = HiddenWindowCanvas::new(&video_subsystem, 400, 70).unwrap();
+            let mut player = EventPlayer::load(file).unwrap();
+
+            // The clock is started before the Engine is built, so that the Engine and its Widgets
+            // take their start times from the replayed clock.
+            player.start_clock(0);
+
+            let mut engine = build_engine();
+
+            player.play(&mut engine, hidden_window.get_canvas());
+
+            let widget_cache = engine.get_widget_cache();
+            let mut widget = widget_cache
+                .get_container_by_name(String::from("text_field"))
+                .widget
+                .borrow_mut();
+            let text_field = widget.as_any().downcast_mut::<TextFieldWidget>().unwrap();
+            let text = TextFieldWidget::get_text(text_field);
+
+            eprintln!(
+                "Replayed {} events, text field contains: {}",
+                player.len(),
+                text
+            );
+        }
+        _ => eprintln!("Usage: replay [record|replay] <file>"),
+    }
+}
@@ -0,0 +1,63 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::widget::Widget;
+use pushrod::render::widget_config::CONFIG_COLOR_TEXT;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::scroll_view_widget::*;
+use pushrod::widgets::text_field_widget::*;
+use pushrod::widgets::text_widget::*;
+use sdl2::pixels::Color;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render scroll view demo", 400, 300)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(400, 300, 60);
+    let mut scroll_view = ScrollViewWidget::new(
+        make_points(20, 20),
+        make_size(360, 260),
+        make_size(500, 1020),
+    );
+
+    scroll_view.on_scrolled(|_, _widgets, _layouts, position| {
+        eprintln!("Scrolled to: {}x{}", position[0], position[1]);
+    });
+
+    let scroll_view_id = engine.add_widget(Box::new(scroll_view), String::from("scroll_view"));
+
+    for i in 0..20 {
+        let mut label = TextWidget::new(
+            String::from("assets/OpenSans-Regular.ttf"),
+            sdl2::ttf::FontStyle::NORMAL,
+            16,
+            TextJustify::Right,
+            format!("Field {}:", i + 1),
+            make_points(10, 10 + i * 50),
+            make_size(100, 30),
+        );
+        let text_field = TextFieldWidget::new(
+            make_points(120, 10 + i * 50),
+            make_size(370, 30),
+            String::new(),
+            16,
+        );
+
+        label.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+
+        engine.add_widget_to_parent(scroll_view_id, Box::new(label), format!("label{}", i + 1));
+        engine.add_widget_to_parent(
+            scroll_view_id,
+            Box::new(text_field),
+            format!("field{}", i + 1),
+        );
+    }
+
+    engine.run(sdl_context, window);
+}
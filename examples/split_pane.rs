@@ -0,0 +1,116 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::layouts::vertical_layout::VerticalLayout;
+use pushrod::render::engine::Engine;
+use pushrod::render::layout::{Layout, LayoutItem};
+use pushrod::render::widget_config::PaddingConstraint;
+use pushrod::render::{make_points_origin, make_size};
+use pushrod::widgets::push_button_widget::PushButtonWidget;
+use pushrod::widgets::split_pane_widget::{SplitPaneOrientation, SplitPaneSide, SplitPaneWidget};
+use pushrod::widgets::text_area_widget::TextAreaWidget;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render split pane demo", 600, 400)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(600, 400, 60);
+    let mut outer_split = SplitPaneWidget::new(
+        make_points_origin(),
+        make_size(600, 400),
+        SplitPaneOrientation::SplitPaneHorizontal,
+    );
+
+    // The divider position would normally be restored from the application's saved settings.
+    outer_split.set_min_sizes(100, 150);
+    outer_split.set_divider_position(220);
+    outer_split.on_divider_moved(|_, _widgets, _layouts, position| {
+        eprintln!("Outer divider moved: {}", position);
+    });
+
+    let outer_id = engine.add_widget(Box::new(outer_split), String::from("outer"));
+    let text_area = TextAreaWidget::new(
+        make_points_origin(),
+        make_size(220, 400),
+        String::from(
+            "Drag the dividers to resize the panes.  Double click a divider to collapse a pane, \
+             and double click it again to restore it.",
+        ),
+        14,
+    );
+    let text_area_id =
+        engine.add_widget_to_parent(outer_id, Box::new(text_area), String::from("text"));
+
+    // The second pane is split again, with a column of buttons placed by a Layout in its top pane.
+    let mut inner_split = SplitPaneWidget::new(
+        make_points_origin(),
+        make_size(374, 400),
+        SplitPaneOrientation::SplitPaneVertical,
+    );
+
+    inner_split.set_min_sizes(60, 60);
+    inner_split.set_collapse_side(SplitPaneSide::SplitPaneSecond);
+    inner_split.on_divider_moved(|_, _widgets, _layouts, position| {
+        eprintln!("Inner divider moved: {}", position);
+    });
+
+    let inner_id =
+        engine.add_widget_to_parent(outer_id, Box::new(inner_split), String::from("inner"));
+    let mut button_layout =
+        VerticalLayout::new(0, 0, 0, 0, PaddingConstraint::new(10, 10, 10, 10, 10));
+
+    for i in 1..=3 {
+        let button = PushButtonWidget::new(
+            make_points_origin(),
+            make_size(0, 0),
+            format!("Button {}", i),
+            18,
+        );
+        let button_id =
+            engine.add_widget_to_parent(inner_id, Box::new(button), format!("button{}", i));
+
+        button_layout.append_widget(button_id);
+    }
+
+    let button_layout_id = engine.add_layout(Box::new(button_layout));
+    let notes = TextAreaWidget::new(
+        make_points_origin(),
+        make_size(374, 200),
+        String::from("Double click the divider above to collapse this pane."),
+        14,
+    );
+    let notes_id = engine.add_widget_to_parent(inner_id, Box::new(notes), String::from("notes"));
+
+    for (split_id, first, second) in &[
+        (
+            outer_id,
+            LayoutItem::Widget(text_area_id),
+            LayoutItem::Widget(inner_id),
+        ),
+        (
+            inner_id,
+            LayoutItem::Layout(button_layout_id),
+            LayoutItem::Widget(notes_id),
+        ),
+    ] {
+        let mut split_widget = engine
+            .get_widget_cache()
+            .get_container_by_id(*split_id)
+            .widget
+            .borrow_mut();
+        let split = split_widget
+            .as_any()
+            .downcast_mut::<SplitPaneWidget>()
+            .unwrap();
+
+        split.set_first_pane(*first);
+        split.set_second_pane(*second);
+    }
+
+    engine.run(sdl_context, window);
+}
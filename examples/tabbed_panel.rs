@@ -0,0 +1,116 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::callbacks::widget_id_for_name;
+use pushrod::render::engine::Engine;
+use pushrod::render::layout::LayoutItem;
+use pushrod::render::widget::{BaseWidget, Widget};
+use pushrod::render::widget_config::{CONFIG_COLOR_BASE, CONFIG_COLOR_BORDER};
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::push_button_widget::PushButtonWidget;
+use pushrod::widgets::tabbed_panel_widget::TabbedPanelWidget;
+use pushrod::widgets::text_widget::{TextJustify, TextWidget};
+use sdl2::pixels::Color;
+
+#[macro_export]
+macro_rules! cast {
+    ($a:expr, $b:expr, $c:ident) => {
+        $a[$b]
+            .widget
+            .borrow_mut()
+            .as_any()
+            .downcast_mut::<$c>()
+            .unwrap()
+    };
+}
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render tabbed panel demo", 500, 300)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(500, 300, 60);
+    let mut panel = TabbedPanelWidget::new(make_points(20, 20), make_size(460, 260));
+
+    panel.on_tab_selected(|_, _widgets, _layouts, selected_tab| {
+        eprintln!("Selected tab: {}", selected_tab);
+    });
+    panel.on_tab_closed(|_, _widgets, _layouts, closed_tab, page| {
+        eprintln!("Closed tab: {} (page {:?})", closed_tab, page);
+    });
+
+    let panel_id = engine.add_widget(Box::new(panel), String::from("panel"));
+    let mut page_ids = Vec::new();
+
+    // Each page is a plain Widget containing its own children.  The panel moves and sizes the
+    // pages to fit below the tabs, so they can be created at any position.
+    for i in 1..=8 {
+        let mut page = BaseWidget::new(make_points(0, 0), make_size(458, 229));
+
+        page.set_color(CONFIG_COLOR_BASE, Color::RGB(255, 255, 255));
+        page.set_color(CONFIG_COLOR_BORDER, Color::RGB(255, 255, 255));
+
+        let page_id = engine.add_widget_to_parent(panel_id, Box::new(page), format!("page{}", i));
+        let text = TextWidget::new(
+            String::from("assets/OpenSans-Regular.ttf"),
+            sdl2::ttf::FontStyle::NORMAL,
+            24,
+            TextJustify::Center,
+            format!("This is page {}", i),
+            make_points(20, 20),
+            make_size(418, 40),
+        );
+
+        engine.add_widget_to_parent(page_id, Box::new(text), format!("text{}", i));
+        page_ids.push(page_id);
+    }
+
+    let mut rename_button = PushButtonWidget::new(
+        make_points(129, 100),
+        make_size(200, 32),
+        String::from("Rename this tab"),
+        18,
+    );
+
+    rename_button.on_click(|_, _widgets, _layouts| {
+        let panel_id = widget_id_for_name(_widgets, String::from("panel"));
+        let title = match cast!(_widgets, panel_id, TabbedPanelWidget).get_tab_title(0) {
+            Some("Welcome") => String::from("Renamed"),
+            _ => String::from("Welcome"),
+        };
+
+        cast!(_widgets, panel_id, TabbedPanelWidget).rename_tab(0, title);
+    });
+
+    engine.add_widget_to_parent(page_ids[0], Box::new(rename_button), String::from("rename"));
+
+    {
+        let mut panel_widget = engine
+            .get_widget_cache()
+            .get_container_by_id(panel_id)
+            .widget
+            .borrow_mut();
+        let panel = panel_widget
+            .as_any()
+            .downcast_mut::<TabbedPanelWidget>()
+            .unwrap();
+
+        for (i, page_id) in page_ids.iter().enumerate() {
+            let title = if i == 0 {
+                String::from("Welcome")
+            } else {
+                format!("Tab {}", i + 1)
+            };
+            let tab = panel.add_tab(title, LayoutItem::Widget(*page_id));
+
+            // Every tab except the first can be closed.
+            panel.set_tab_closable(tab, i > 0);
+        }
+    }
+
+    engine.run(sdl_context, window);
+}
@@ -0,0 +1,58 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::table_widget::*;
+use pushrod::widgets::text_widget::TextJustify;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render table demo", 500, 400)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(500, 400, 60);
+    let mut widget1 = TableWidget::new(
+        make_points(20, 20),
+        make_size(460, 360),
+        vec![
+            TableColumn::new(String::from("Name"), 200, TextJustify::Left),
+            TableColumn::new(String::from("Status"), 120, TextJustify::Center),
+            TableColumn::new(String::from("Count"), 100, TextJustify::Right),
+        ],
+    );
+    let statuses = ["Running", "Stopped", "Paused"];
+
+    for i in 0..40 {
+        widget1.add_row(vec![
+            format!("Process {}", i + 1),
+            String::from(statuses[i % 3]),
+            format!("{}", (i * 37) % 101),
+        ]);
+    }
+
+    widget1.on_selected(|x, _widgets, _layout, selected_row| {
+        if selected_row >= 0 {
+            eprintln!("Selected: {:?}", x.get_rows()[selected_row as usize]);
+        } else {
+            eprintln!("Selection cleared");
+        }
+    });
+
+    widget1.on_cell_clicked(|x, _widgets, _layout, row, column| {
+        eprintln!(
+            "Clicked cell ({}, {}): {}",
+            row,
+            column,
+            x.get_cell(row, column).unwrap()
+        );
+    });
+
+    engine.add_widget(Box::new(widget1), String::from("widget1"));
+
+    engine.run(sdl_context, window);
+}
@@ -0,0 +1,50 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::text_area_widget::*;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render text area demo", 640, 400)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(640, 400, 60);
+    let mut notes = TextAreaWidget::new(
+        make_points(20, 20),
+        make_size(290, 360),
+        String::from(
+            "This text area wraps long lines at word boundaries, so that they fit inside of \
+             the widget.\n\nUse the arrow keys, Home, End, Page Up and Page Down to move \
+             around, and hold Shift to select text.  Press Enter to start a new line.",
+        ),
+        16,
+    );
+    let mut config =
+        TextAreaWidget::new(make_points(330, 20), make_size(290, 360), String::new(), 16);
+
+    notes.on_text_changed(|widget, _widgets, _layouts, _text| {
+        eprintln!("Notes changed: {} lines", widget.get_line_count());
+    });
+
+    config.set_word_wrap(false);
+
+    for i in 1..=40 {
+        config.append_text(&format!(
+            "setting_{} = \"a long value that does not wrap, so the text scrolls sideways\"\n",
+            i
+        ));
+    }
+
+    config.set_cursor(0, 0);
+
+    engine.add_widget(Box::new(notes), String::from("notes"));
+    engine.add_widget(Box::new(config), String::from("config"));
+
+    engine.run(sdl_context, window);
+}
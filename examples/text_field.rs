@@ -0,0 +1,45 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::text_field_widget::*;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render text field demo", 400, 130)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(400, 130, 60);
+    let mut widget1 = TextFieldWidget::new(
+        make_points(20, 20),
+        make_size(360, 30),
+        String::from("Editable text"),
+        18,
+    );
+    let mut widget2 = TextFieldWidget::new(
+        make_points(20, 70),
+        make_size(360, 30),
+        String::from(""),
+        18,
+    );
+
+    widget1.on_text_changed(|_, _widgets, _layouts, text| {
+        eprintln!("Text changed: {}", text);
+    });
+
+    widget2.set_placeholder(String::from("Type here and press Enter"));
+    widget2.set_max_length(32);
+    widget2.on_submit(|_, _widgets, _layouts, text| {
+        eprintln!("Submitted: {}", text);
+    });
+
+    engine.add_widget(Box::new(widget1), String::from("widget1"));
+    engine.add_widget(Box::new(widget2), String::from("widget2"));
+
+    engine.run(sdl_context, window);
+}
@@ -0,0 +1,162 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::widget::Widget;
+use pushrod::render::widget_config::{CONFIG_COLOR_HOVER, CONFIG_COLOR_SELECTED};
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::image_button_widget::ImageButtonWidget;
+use pushrod::widgets::tile_widget::TileWidget;
+use pushrod::widgets::toolbar_widget::ToolbarOrientation::{ToolbarHorizontal, ToolbarVertical};
+use pushrod::widgets::toolbar_widget::ToolbarWidget;
+use sdl2::pixels::Color;
+
+/// Creates a tile showing the numbered image in the assets directory.
+fn make_tile(number: u32, text: &str) -> TileWidget {
+    let mut tile = TileWidget::new(
+        make_points(0, 0),
+        make_size(60, 60),
+        format!("assets/{}.png", number),
+        String::from(text),
+    );
+
+    tile.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+    tile.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0x60, 0x60, 0xFF));
+    tile
+}
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render toolbar demo", 500, 400)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(500, 400, 60);
+    let toolbar_id = engine.add_widget(
+        Box::new(ToolbarWidget::new(
+            make_points(0, 0),
+            make_size(500, 64),
+            ToolbarHorizontal,
+        )),
+        String::from("toolbar"),
+    );
+    let side_bar_id = engine.add_widget(
+        Box::new(ToolbarWidget::new(
+            make_points(0, 64),
+            make_size(64, 336),
+            ToolbarVertical,
+        )),
+        String::from("side_bar"),
+    );
+
+    // The tools are added as children of the toolbars.  A `None` entry is a separator.
+    let open_id = engine.add_widget_to_parent(
+        toolbar_id,
+        Box::new(ImageButtonWidget::new(
+            make_points(0, 0),
+            make_size(100, 60),
+            String::from("Open"),
+            18,
+            String::from("assets/rust-48x48.jpg"),
+        )),
+        String::from("open"),
+    );
+    let mut tools = vec![Some((String::from("open"), open_id)), None];
+
+    for (i, name) in ["Pen", "Brush", "Fill", "Erase"].iter().enumerate() {
+        let tile_id = engine.add_widget_to_parent(
+            toolbar_id,
+            Box::new(make_tile(i as u32 + 1, name)),
+            name.to_lowercase(),
+        );
+
+        tools.push(Some((name.to_lowercase(), tile_id)));
+    }
+
+    tools.push(None);
+
+    for (i, name) in ["Grid", "Snap", "Rulers"].iter().enumerate() {
+        let tile_id = engine.add_widget_to_parent(
+            toolbar_id,
+            Box::new(make_tile(i as u32 + 5, name)),
+            name.to_lowercase(),
+        );
+
+        tools.push(Some((name.to_lowercase(), tile_id)));
+    }
+
+    let panels: Vec<(String, i32)> = ["Layers", "Colors", "History"]
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let tile_id = engine.add_widget_to_parent(
+                side_bar_id,
+                Box::new(make_tile(i as u32 + 1, name)),
+                name.to_lowercase(),
+            );
+
+            (name.to_lowercase(), tile_id)
+        })
+        .collect();
+
+    {
+        let mut toolbar_widget = engine
+            .get_widget_cache()
+            .get_container_by_id(toolbar_id)
+            .widget
+            .borrow_mut();
+        let toolbar = toolbar_widget
+            .as_any()
+            .downcast_mut::<ToolbarWidget>()
+            .unwrap();
+
+        for tool in tools {
+            match tool {
+                Some((name, tool_id)) => toolbar.add_tool(name, tool_id),
+                None => toolbar.add_separator(),
+            }
+        }
+
+        // The drawing tools form a toggle group: only one of them is selected at a time.  The
+        // other tiles toggle on their own.  Not all of them fit, so the rest are shown in the
+        // overflow menu.
+        for name in &["pen", "brush", "fill", "erase"] {
+            toolbar.set_tool_group(name, 1);
+        }
+
+        toolbar.select_tool("pen");
+        toolbar.on_tool_selected(|toolbar, _widgets, _layouts, name| {
+            eprintln!(
+                "Tool selected: {} (drawing tool: {:?})",
+                name,
+                toolbar.get_selected_tool(1)
+            );
+        });
+    }
+
+    {
+        let mut side_bar_widget = engine
+            .get_widget_cache()
+            .get_container_by_id(side_bar_id)
+            .widget
+            .borrow_mut();
+        let side_bar = side_bar_widget
+            .as_any()
+            .downcast_mut::<ToolbarWidget>()
+            .unwrap();
+
+        for (name, tile_id) in panels {
+            side_bar.add_tool(name.clone(), tile_id);
+            side_bar.set_tool_group(&name, 1);
+        }
+
+        side_bar.on_tool_selected(|_, _widgets, _layouts, name| {
+            eprintln!("Panel selected: {}", name);
+        });
+    }
+
+    engine.run(sdl_context, window);
+}
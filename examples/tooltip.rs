@@ -0,0 +1,64 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::widget::Widget;
+use pushrod::render::widget_config::{CONFIG_COLOR_HOVER, CONFIG_COLOR_SELECTED, CONFIG_TOOLTIP};
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::image_button_widget::ImageButtonWidget;
+use pushrod::widgets::tile_widget::TileWidget;
+use sdl2::pixels::Color;
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render tooltip demo", 400, 200)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(400, 200, 60);
+    let tooltips = [
+        "Create a new document",
+        "Open an existing document",
+        "Save the current document",
+        "Print the current document.  This tooltip is long, so it is wrapped, and it is kept \
+         inside of the window even though the tile is at the right edge.",
+    ];
+
+    for (i, tooltip) in tooltips.iter().enumerate() {
+        let mut tile = TileWidget::new(
+            make_points(10 + i as i32 * 95, 10),
+            make_size(85, 85),
+            format!("assets/{}.png", i + 1),
+            format!("{}", i + 1),
+        );
+
+        tile.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        tile.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0x60, 0x60, 0xFF));
+        tile.set_text(CONFIG_TOOLTIP, String::from(*tooltip));
+
+        engine.add_widget(Box::new(tile), format!("tile{}", i + 1));
+    }
+
+    let mut button = ImageButtonWidget::new(
+        make_points(10, 140),
+        make_size(380, 48),
+        String::from(" Hover here"),
+        20,
+        String::from("assets/rust-48x48.jpg"),
+    );
+
+    button.set_text(
+        CONFIG_TOOLTIP,
+        String::from("Tooltips near the bottom of the window open above the mouse."),
+    );
+    button.on_click(|_, _widgets, _layouts| {
+        eprintln!("Clicked: the tooltip is hidden until the mouse leaves the button.");
+    });
+
+    engine.add_widget(Box::new(button), String::from("button"));
+    engine.set_tooltip_delay(400);
+    engine.run(sdl_context, window);
+}
@@ -0,0 +1,88 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::tree_view_widget::*;
+use std::fs;
+use std::path::PathBuf;
+
+/// Rebuilds the path of a node from the labels of its ancestors.
+fn node_path(tree: &TreeViewWidget, node: usize) -> PathBuf {
+    let mut labels = vec![tree.get_label(node).unwrap().clone()];
+    let mut parent = tree.get_parent(node);
+
+    while let Some(id) = parent {
+        labels.push(tree.get_label(id).unwrap().clone());
+        parent = tree.get_parent(id);
+    }
+
+    labels.iter().rev().collect()
+}
+
+/// Adds the contents of the directory at `node` as its children.  Directories are marked as having
+/// children, so they are only read when they are expanded.
+fn load_directory(tree: &mut TreeViewWidget, node: usize) {
+    let path = node_path(tree, node);
+    let mut entries: Vec<(String, bool)> = match fs::read_dir(&path) {
+        Ok(entries) => entries
+            .filter_map(|x| x.ok())
+            .map(|x| {
+                (
+                    x.file_name().to_string_lossy().to_string(),
+                    x.path().is_dir(),
+                )
+            })
+            .collect(),
+        Err(_) => vec![],
+    };
+
+    entries.sort();
+
+    for (name, is_dir) in entries {
+        let child = tree.add_node(Some(node), name);
+
+        tree.set_has_children(child, is_dir);
+    }
+}
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render tree view demo", 500, 400)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(500, 400, 60);
+    let mut widget1 = TreeViewWidget::new(make_points(20, 20), make_size(460, 360));
+    let root = widget1.add_node(None, String::from("."));
+
+    widget1.on_load_children(load_directory);
+    widget1.set_has_children(root, true);
+    widget1.expand(root);
+
+    widget1.on_selected(|x, _widgets, _layout, selected_node| {
+        if selected_node >= 0 {
+            eprintln!(
+                "Selected: {}",
+                node_path(x, selected_node as usize).display()
+            );
+        } else {
+            eprintln!("Selection cleared");
+        }
+    });
+
+    widget1.on_expanded(|x, _widgets, _layout, node, expanded| {
+        eprintln!(
+            "{} {}",
+            if expanded { "Expanded" } else { "Collapsed" },
+            node_path(x, node).display()
+        );
+    });
+
+    engine.add_widget(Box::new(widget1), String::from("widget1"));
+
+    engine.run(sdl_context, window);
+}
@@ -0,0 +1,91 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::texture_cache::TextureCache;
+use pushrod::render::{make_points, make_size};
+use pushrod::widgets::list_widget::ListSelectionMode;
+use pushrod::widgets::virtual_list_widget::*;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+/// A log model that generates its rows on demand, so it does not need to store them.
+struct LogModel {
+    entries: usize,
+}
+
+impl ListModel for LogModel {
+    fn row_count(&self) -> usize {
+        self.entries
+    }
+
+    fn row_data(&self, row: usize) -> Vec<String> {
+        let level = match row % 7 {
+            0 => "ERROR",
+            3 => "WARN",
+            _ => "INFO",
+        };
+
+        vec![
+            String::from(level),
+            format!("{:06}", row),
+            format!("Log message number {}", row),
+        ]
+    }
+}
+
+pub fn main() {
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let window = video_subsystem
+        .window("pushrod-render virtual list demo", 500, 400)
+        .position_centered()
+        .opengl()
+        .build()
+        .unwrap();
+    let mut engine = Engine::new(500, 400, 60);
+    let mut widget1 = VirtualListWidget::new(
+        make_points(20, 20),
+        make_size(460, 360),
+        Box::new(LogModel { entries: 100_000 }),
+    );
+    let mut text_renderer = TextItemRenderer::new(String::from("assets/OpenSans-Regular.ttf"), 14);
+
+    text_renderer.set_column_widths(vec![80, 80]);
+
+    widget1.set_row_height(24);
+    widget1.set_selection_mode(ListSelectionMode::MultipleSelection);
+    widget1.set_renderer(Box::new(
+        move |c: &mut Canvas<Window>,
+              t: &mut TextureCache,
+              data: &[String],
+              area: Rect,
+              state: &ListItemState| {
+            let status_color = match data[0].as_str() {
+                "ERROR" => Color::RGB(220, 0, 0),
+                "WARN" => Color::RGB(230, 160, 0),
+                _ => Color::RGB(0, 160, 0),
+            };
+
+            c.set_draw_color(status_color);
+            c.fill_rect(Rect::new(area.x() + 4, area.y() + 6, 4, area.height() - 12))
+                .unwrap();
+
+            text_renderer.draw_item(c, t, data, area, state);
+        },
+    ));
+
+    widget1.on_selected(|x, _widgets, _layout, selected_item| {
+        eprintln!(
+            "Selected: {} ({} rows selected)",
+            selected_item,
+            x.get_selected().len()
+        );
+    });
+
+    engine.add_widget(Box::new(widget1), String::from("widget1"));
+
+    engine.run(sdl_context, window);
+}
@@ -13,64 +13,233 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::render::layout::{Layout, LayoutPosition};
+use crate::render::layout::{
+    distribute_space, get_item_size_hint, place_item, Layout, LayoutItem, LayoutPosition, SizeHint,
+};
+use crate::render::layout_cache::LayoutContainer;
 use crate::render::widget_cache::WidgetContainer;
 use crate::render::widget_config::PaddingConstraint;
+use crate::render::{Points, Size, SIZE_HEIGHT, SIZE_WIDTH};
+
+/// Index into the `layout` `Vec` that contains the number of columns in the grid.
+const GRID_COLUMNS: usize = 0;
+
+/// Index into the `layout` `Vec` that contains the number of rows in the grid.
+const GRID_ROWS: usize = 1;
 
 /// This is the `GridLayout` storage structure for the `GridLayout` implementation.
 pub struct GridLayout {
-    widget_ids: Vec<i32>,
-    //    widget_positions: Vec<LayoutPosition>,
-    //    origin: Points,
-    //    size: Size,
+    items: Vec<LayoutItem>,
+    item_positions: Vec<LayoutPosition>,
+    item_spans: Vec<(i32, i32)>,
+    origin: Points,
+    size: Size,
     padding: PaddingConstraint,
-    //    layout: Vec<i32>,
+    layout: Vec<i32>,
+    column_weights: Vec<u32>,
+    row_weights: Vec<u32>,
     invalidated: bool,
 }
 
-/// Creates a new `GridLayout` manager.
+/// Creates a new `GridLayout` manager.  The `layout` contains the number of columns and rows in
+/// the grid, in that order, either of which defaults to `1` if it is missing.  All columns and
+/// rows start with a weight of `1`, meaning that the space in the grid is divided evenly between
+/// them.
 impl GridLayout {
     pub fn new(
-        _x: i32,
-        _y: i32,
-        _w: u32,
-        _h: u32,
-        _layout: Vec<i32>,
+        x: i32,
+        y: i32,
+        w: u32,
+        h: u32,
+        layout: Vec<i32>,
         padding: PaddingConstraint,
     ) -> Self {
+        let columns = layout.get(GRID_COLUMNS).copied().unwrap_or(1).max(1) as usize;
+        let rows = layout.get(GRID_ROWS).copied().unwrap_or(1).max(1) as usize;
+
         Self {
-            widget_ids: Vec::new(),
-            //            widget_positions: Vec::new(),
-            //            origin: vec![x, y],
-            //            size: vec![w, h],
+            items: Vec::new(),
+            item_positions: Vec::new(),
+            item_spans: Vec::new(),
+            origin: vec![x, y],
+            size: vec![w, h],
             padding,
-            //            layout,
+            layout: vec![columns as i32, rows as i32],
+            column_weights: vec![1; columns],
+            row_weights: vec![1; rows],
             invalidated: false,
         }
     }
+
+    /// Adds an item to the `GridLayout`, given its top-left cell, and the number of columns and
+    /// rows that it spans.  Spans that extend past the edges of the grid are clipped to the grid.
+    pub fn insert_item_spanned(
+        &mut self,
+        item: LayoutItem,
+        item_position: LayoutPosition,
+        column_span: i32,
+        row_span: i32,
+    ) {
+        self.items.push(item);
+        self.item_positions.push(item_position);
+        self.item_spans.push((column_span.max(1), row_span.max(1)));
+        self.invalidated = true;
+    }
+
+    /// Adds a widget to the `GridLayout`, given its top-left cell, and the number of columns and
+    /// rows that it spans.
+    pub fn insert_widget_spanned(
+        &mut self,
+        widget_id: i32,
+        widget_position: LayoutPosition,
+        column_span: i32,
+        row_span: i32,
+    ) {
+        self.insert_item_spanned(
+            LayoutItem::Widget(widget_id),
+            widget_position,
+            column_span,
+            row_span,
+        );
+    }
+
+    /// Adds a `Layout` to the `GridLayout`, given its top-left cell, and the number of columns and
+    /// rows that it spans.
+    pub fn insert_layout_spanned(
+        &mut self,
+        layout_id: i32,
+        layout_position: LayoutPosition,
+        column_span: i32,
+        row_span: i32,
+    ) {
+        self.insert_item_spanned(
+            LayoutItem::Layout(layout_id),
+            layout_position,
+            column_span,
+            row_span,
+        );
+    }
+
+    /// Sets the relative weight of a column.  Space left in the grid after each column has been
+    /// given its minimum size is divided between the columns in proportion to their weights.  A
+    /// column with a weight of `0` stays at its minimum size.
+    pub fn set_column_weight(&mut self, column: i32, weight: u32) {
+        if column >= 0 && (column as usize) < self.column_weights.len() {
+            self.column_weights[column as usize] = weight;
+            self.invalidated = true;
+        }
+    }
+
+    /// Sets the relative weight of a row.  Space left in the grid after each row has been given its
+    /// minimum size is divided between the rows in proportion to their weights.  A row with a
+    /// weight of `0` stays at its minimum size.
+    pub fn set_row_weight(&mut self, row: i32, weight: u32) {
+        if row >= 0 && (row as usize) < self.row_weights.len() {
+            self.row_weights[row as usize] = weight;
+            self.invalidated = true;
+        }
+    }
+
+    /// Returns the minimum size of each cell along the axis specified by `axis`, which is either
+    /// `SIZE_WIDTH` or `SIZE_HEIGHT`.  This is the largest minimum size of the items that span only
+    /// that cell.  Items that span several cells do not affect the minimum sizes.
+    fn get_minimum_sizes(
+        &self,
+        widgets: &[WidgetContainer],
+        axis: usize,
+        cells: usize,
+    ) -> Vec<u32> {
+        let mut minimums = vec![0; cells];
+
+        for (i, item) in self.items.iter().enumerate() {
+            let (position, span) = if axis == SIZE_WIDTH {
+                (self.item_positions[i].x, self.item_spans[i].0)
+            } else {
+                (self.item_positions[i].y, self.item_spans[i].1)
+            };
+
+            if span == 1 && position >= 0 && (position as usize) < cells {
+                let min = get_item_size_hint(*item, widgets, axis).min;
+
+                minimums[position as usize] = minimums[position as usize].max(min);
+            }
+        }
+
+        minimums
+    }
+
+    /// Returns the offset and size of each cell along one axis of the grid, given the available
+    /// space, the padding on either side, and the minimum size and weight of each cell.
+    fn compute_cells(
+        &self,
+        available: u32,
+        padding_start: i32,
+        padding_end: i32,
+        minimums: &[u32],
+        weights: &[u32],
+    ) -> Vec<(i32, u32)> {
+        let spacing = self.padding.spacing.max(0);
+        let total_spacing = spacing * (weights.len() as i32 - 1);
+        let space = (available as i32 - padding_start - padding_end - total_spacing).max(0) as u32;
+        let hints: Vec<SizeHint> = minimums
+            .iter()
+            .zip(weights)
+            .map(|(min, weight)| SizeHint {
+                min: *min,
+                max: u32::MAX,
+                preferred: 0,
+                stretch: *weight,
+            })
+            .collect();
+        let mut position = padding_start;
+
+        distribute_space(space, &hints)
+            .into_iter()
+            .map(|cell_size| {
+                let cell = (position, cell_size);
+
+                position += cell_size as i32 + spacing;
+                cell
+            })
+            .collect()
+    }
 }
 
-/// This is the `Layout` implementation for the `GridLayout` manager.  This `Layout` manager will
-/// not reposition any objects within the bounds of the `Layout` until at least 2 objects have been
-/// added to the bounds of the `Layout`.
+/// This is the `Layout` implementation for the `GridLayout` manager.  `Widget`s and `Layout`s are
+/// placed in cells based on the `x` (column) and `y` (row) of their `LayoutPosition`.  Items that
+/// are positioned outside of the grid are not moved.  Each column and row is at least as large as
+/// the minimum size of the items in it, and items are not made larger than their maximum size.
+/// The stretch factors of items are not used: the weights of the columns and rows are used
+/// instead.
 impl Layout for GridLayout {
-    /// Adds a widget to the `HorizontalLayout` managed stack.
-    fn insert_widget(&mut self, _widget_id: i32, _widget_position: LayoutPosition) {
-        //        self.widget_ids.push(widget_id);
-        //        self.widget_positions.push(widget_position);
-        //        self.invalidated = true;
+    /// Adds an item to the `GridLayout` at the cell specified by `item_position`, spanning a
+    /// single cell.
+    fn insert_item(&mut self, item: LayoutItem, item_position: LayoutPosition) {
+        self.insert_item_spanned(item, item_position, 1, 1);
+    }
+
+    /// Appends an item to the `GridLayout`, placing it in the cell after the last item that
+    /// was added.  Cells are filled from left to right, then top to bottom.
+    fn append_item(&mut self, item: LayoutItem) {
+        let positions = self.item_positions.len();
+        let item_position = if self.item_positions.is_empty() {
+            LayoutPosition::new(0, 0)
+        } else {
+            let last_position = &self.item_positions[positions - 1];
+            let next_x = last_position.x + self.item_spans[positions - 1].0;
+
+            if next_x >= self.layout[GRID_COLUMNS] {
+                LayoutPosition::new(0, last_position.y + 1)
+            } else {
+                LayoutPosition::new(next_x, last_position.y)
+            }
+        };
+
+        self.insert_item(item, item_position);
     }
 
-    /// Appends a widget to the `HorizontalLayout` managed stack.
-    fn append_widget(&mut self, _widget_id: i32) {
-        //        let positions = self.widget_positions.len();
-        //        let widget_position = if self.widget_positions.is_empty() {
-        //            LayoutPosition::new(0, 0)
-        //        } else {
-        //            LayoutPosition::new(0, self.widget_positions[positions - 1].y + 1)
-        //        };
-        //
-        //        self.insert_widget(widget_id, widget_position);
+    fn get_items(&self) -> Vec<LayoutItem> {
+        self.items.clone()
     }
 
     fn set_padding(&mut self, padding: PaddingConstraint) {
@@ -82,69 +251,73 @@ impl Layout for GridLayout {
         self.padding
     }
 
-    /// Adjusts the layout of the `Widget`s managed by this `Layout` manager.  Currently only obeys
-    /// the spacing in the object.  The rest of the padding is not (yet) honored.
-    fn do_layout(&mut self, _widgets: &[WidgetContainer]) {
-        if self.widget_ids.len() <= 1 {
+    fn set_bounds(&mut self, origin: Points, size: Size) {
+        self.origin = origin;
+        self.size = size;
+        self.invalidated = true;
+    }
+
+    fn get_bounds(&self) -> (Points, Size) {
+        (self.origin.clone(), self.size.clone())
+    }
+
+    /// Adjusts the layout of the `Widget`s managed by this `Layout` manager.  The `top`, `bottom`,
+    /// `left` and `right` padding is applied around the edges of the grid, and the `spacing` is
+    /// applied between each of the cells.
+    fn do_layout(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.items.is_empty() {
             return;
         }
 
-        //        let offset_x: i32 = self.origin[0];
-        //        let offset_y: i32 = self.origin[1] + self.padding.top;
-        //        let num_widgets = self.widget_ids.len() as u32;
-        //        let widget_width = self.size[SIZE_WIDTH] / num_widgets as u32;
-        //        let widget_height = self.size[SIZE_HEIGHT] / num_widgets as u32;
-        //        let subtractor_right = ((self.padding.spacing as f64 / 2.0).ceil()) as u32;
-        //        let subtractor_left = ((self.padding.spacing as f64 / 2.0).floor()) as u32;
-        //        let subtractor_bottom = ((self.padding.spacing as f64 / 2.0).ceil()) as u32;
-        //        let subtractor_top = ((self.padding.spacing as f64 / 2.0).floor()) as u32;
-        //
-        //        for i in 0..num_widgets {
-        //            let set_x: i32;
-        //            let set_y: i32;
-        //            let mut set_height: u32 = widget_height;
-        //            let mut set_width: u32 = widget_width;
-        //            let widget_id = self.widget_ids[i as usize];
-        //
-        //            if i == 0 {
-        //                set_x = (i * set_width) as i32 + self.padding.left;
-        //                set_y = (i * set_height) as i32 + self.padding.top;
-        //                set_height = widget_height - subtractor_bottom - self.padding.top as u32;
-        //                set_width = widget_width - subtractor_right - self.padding.left as u32;
-        //            } else if i == num_widgets - 1 {
-        //                set_x = (i * set_width) as i32 + subtractor_left as i32;
-        //                set_y = (i * set_height) as i32 + subtractor_top as i32;
-        //                set_height = widget_height - subtractor_top - self.padding.bottom as u32;
-        //                set_width = widget_width - subtractor_left - self.padding.right as u32;
-        //            } else {
-        //                set_x = (i * set_width) as i32 + subtractor_left as i32;
-        //                set_y = (i * set_height) as i32 + subtractor_top as i32;
-        //                set_height = widget_height - subtractor_top - subtractor_bottom;
-        //                set_width = widget_width - subtractor_left - subtractor_right;
-        //            }
-        //
-        //            _widgets[widget_id as usize]
-        //                .widget
-        //                .borrow_mut()
-        //                .get_config()
-        //                .set_point(CONFIG_ORIGIN, offset_x + set_x, offset_y + set_y);
-        //
-        //            _widgets[widget_id as usize]
-        //                .widget
-        //                .borrow_mut()
-        //                .get_config()
-        //                .set_size(
-        //                    CONFIG_SIZE,
-        //                    self.size[SIZE_WIDTH] - self.padding.right as u32 - self.padding.left as u32,
-        //                    self.size[SIZE_HEIGHT] - self.padding.top as u32 - self.padding.bottom as u32,
-        //                );
-        //
-        //            _widgets[widget_id as usize]
-        //                .widget
-        //                .borrow_mut()
-        //                .get_config()
-        //                .set_invalidated(true);
-        //        }
+        let column_minimums =
+            self.get_minimum_sizes(_widgets, SIZE_WIDTH, self.column_weights.len());
+        let row_minimums = self.get_minimum_sizes(_widgets, SIZE_HEIGHT, self.row_weights.len());
+        let columns = self.compute_cells(
+            self.size[SIZE_WIDTH],
+            self.padding.left,
+            self.padding.right,
+            &column_minimums,
+            &self.column_weights,
+        );
+        let rows = self.compute_cells(
+            self.size[SIZE_HEIGHT],
+            self.padding.top,
+            self.padding.bottom,
+            &row_minimums,
+            &self.row_weights,
+        );
+
+        for i in 0..self.items.len() {
+            let position = &self.item_positions[i];
+            let (column_span, row_span) = self.item_spans[i];
+
+            if position.x < 0
+                || position.y < 0
+                || position.x >= self.layout[GRID_COLUMNS]
+                || position.y >= self.layout[GRID_ROWS]
+            {
+                continue;
+            }
+
+            let first_column = position.x as usize;
+            let last_column = ((position.x + column_span) as usize).min(columns.len()) - 1;
+            let first_row = position.y as usize;
+            let last_row = ((position.y + row_span) as usize).min(rows.len()) - 1;
+            let set_x = columns[first_column].0;
+            let set_y = rows[first_row].0;
+            let set_width = (columns[last_column].0 + columns[last_column].1 as i32 - set_x) as u32;
+            let set_height = (rows[last_row].0 + rows[last_row].1 as i32 - set_y) as u32;
+            let max_width = get_item_size_hint(self.items[i], _widgets, SIZE_WIDTH).max;
+            let max_height = get_item_size_hint(self.items[i], _widgets, SIZE_HEIGHT).max;
+
+            place_item(
+                self.items[i],
+                _widgets,
+                _layouts,
+                vec![self.origin[0] + set_x, self.origin[1] + set_y],
+                vec![set_width.min(max_width), set_height.min(max_height)],
+            );
+        }
 
         self.invalidated = false;
     }
@@ -153,3 +326,140 @@ impl Layout for GridLayout {
         self.invalidated
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::widget::BaseWidget;
+    use crate::render::widget_config::{CONFIG_MIN_SIZE, CONFIG_ORIGIN, CONFIG_SIZE};
+    use crate::render::{make_points, make_size};
+
+    fn make_widgets(count: i32) -> Vec<WidgetContainer> {
+        (0..=count)
+            .map(|i| {
+                WidgetContainer::new(
+                    Box::new(BaseWidget::new(make_points(0, 0), make_size(10, 10))),
+                    format!("widget{}", i),
+                    make_points(0, 0),
+                    i,
+                    0,
+                )
+            })
+            .collect()
+    }
+
+    fn get_placement(widgets: &[WidgetContainer], widget_id: usize) -> (Points, Size) {
+        let mut widget = widgets[widget_id].widget.borrow_mut();
+
+        (
+            widget.get_config().get_point(CONFIG_ORIGIN),
+            widget.get_config().get_size(CONFIG_SIZE),
+        )
+    }
+
+    #[test]
+    fn test_compute_cells_applies_padding_and_spacing() {
+        let grid = GridLayout::new(
+            0,
+            0,
+            100,
+            100,
+            vec![2, 2],
+            PaddingConstraint::new(5, 5, 5, 5, 10),
+        );
+
+        assert_eq!(
+            grid.compute_cells(100, 5, 5, &[0, 0], &[1, 1]),
+            vec![(5, 40), (55, 40)]
+        );
+    }
+
+    #[test]
+    fn test_compute_cells_with_zero_weights() {
+        let grid = GridLayout::new(
+            0,
+            0,
+            100,
+            100,
+            vec![2, 2],
+            PaddingConstraint::new(0, 0, 0, 0, 0),
+        );
+
+        assert_eq!(
+            grid.compute_cells(100, 0, 0, &[20, 0], &[0, 1]),
+            vec![(0, 20), (20, 80)]
+        );
+        assert_eq!(
+            grid.compute_cells(100, 0, 0, &[10, 10], &[0, 0]),
+            vec![(0, 10), (10, 10)]
+        );
+    }
+
+    #[test]
+    fn test_compute_cells_with_minimums_larger_than_space() {
+        let grid = GridLayout::new(
+            0,
+            0,
+            100,
+            100,
+            vec![2, 2],
+            PaddingConstraint::new(0, 0, 0, 0, 0),
+        );
+
+        assert_eq!(
+            grid.compute_cells(100, 0, 0, &[80, 80], &[1, 1]),
+            vec![(0, 80), (80, 80)]
+        );
+    }
+
+    #[test]
+    fn test_do_layout_clips_spans_at_grid_edge() {
+        let widgets = make_widgets(3);
+        let mut grid = GridLayout::new(
+            10,
+            20,
+            100,
+            100,
+            vec![2, 2],
+            PaddingConstraint::new(0, 0, 0, 0, 0),
+        );
+
+        grid.insert_widget_spanned(1, LayoutPosition::new(1, 1), 3, 3);
+        grid.insert_widget_spanned(2, LayoutPosition::new(0, 0), 2, 1);
+        grid.insert_widget(3, LayoutPosition::new(5, 5));
+        grid.do_layout(&widgets, &[]);
+
+        assert_eq!(get_placement(&widgets, 1), (vec![60, 70], vec![50, 50]));
+        assert_eq!(get_placement(&widgets, 2), (vec![10, 20], vec![100, 50]));
+        assert_eq!(get_placement(&widgets, 3), (vec![0, 0], vec![10, 10]));
+        assert!(!grid.needs_layout());
+    }
+
+    #[test]
+    fn test_do_layout_with_minimums_larger_than_space() {
+        let widgets = make_widgets(2);
+        let mut grid = GridLayout::new(
+            0,
+            0,
+            100,
+            100,
+            vec![2, 1],
+            PaddingConstraint::new(0, 0, 0, 0, 0),
+        );
+
+        for widget_id in 1..=2 {
+            widgets[widget_id]
+                .widget
+                .borrow_mut()
+                .get_config()
+                .set_size(CONFIG_MIN_SIZE, 80, 0);
+        }
+
+        grid.append_widget(1);
+        grid.append_widget(2);
+        grid.do_layout(&widgets, &[]);
+
+        assert_eq!(get_placement(&widgets, 1), (vec![0, 0], vec![80, 100]));
+        assert_eq!(get_placement(&widgets, 2), (vec![80, 0], vec![80, 100]));
+    }
+}
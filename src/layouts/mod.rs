@@ -23,5 +23,6 @@ pub mod horizontal_layout;
 /// a vertical display area.
 pub mod vertical_layout;
 
-/// This is a `GridLayout` manager.  It handles the even spacing of `Widget`s in a grid area.
+/// This is a `GridLayout` manager.  It handles the placement of `Widget`s in the rows and columns
+/// of a grid area, with optional spans and per-row and per-column weights.
 pub mod grid_layout;
@@ -13,15 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::render::layout::{Layout, LayoutPosition};
+use crate::render::layout::{
+    distribute_space, get_item_size_hint, place_item, Layout, LayoutItem, LayoutPosition, SizeHint,
+};
+use crate::render::layout_cache::LayoutContainer;
 use crate::render::widget_cache::WidgetContainer;
-use crate::render::widget_config::{PaddingConstraint, CONFIG_ORIGIN, CONFIG_SIZE};
+use crate::render::widget_config::PaddingConstraint;
 use crate::render::{Points, Size, SIZE_HEIGHT, SIZE_WIDTH};
 
 /// This is the `VerticalLayout` storage structure for the `VerticalLayout` implementation.
 pub struct VerticalLayout {
-    widget_ids: Vec<i32>,
-    widget_positions: Vec<LayoutPosition>,
+    items: Vec<LayoutItem>,
+    item_positions: Vec<LayoutPosition>,
     origin: Points,
     size: Size,
     padding: PaddingConstraint,
@@ -32,8 +35,8 @@ pub struct VerticalLayout {
 impl VerticalLayout {
     pub fn new(x: i32, y: i32, w: u32, h: u32, padding: PaddingConstraint) -> Self {
         Self {
-            widget_ids: Vec::new(),
-            widget_positions: Vec::new(),
+            items: Vec::new(),
+            item_positions: Vec::new(),
             origin: vec![x, y],
             size: vec![w, h],
             padding,
@@ -42,27 +45,31 @@ impl VerticalLayout {
     }
 }
 
-/// This is the `Layout` implementation for the `VerticalLayout` manager.  This `Layout` manager will
-/// not reposition any objects within the bounds of the `Layout` until at least 2 objects have been
-/// added to the bounds of the `Layout`.
+/// This is the `Layout` implementation for the `VerticalLayout` manager.  `Widget`s and `Layout`s
+/// are placed in the order in which they were added.  Each `Widget` receives its preferred size,
+/// and the remaining space is divided between items in proportion to their `CONFIG_STRETCH` factor.
 impl Layout for VerticalLayout {
-    /// Adds a widget to the `VerticalLayout` managed stack.
-    fn insert_widget(&mut self, widget_id: i32, widget_position: LayoutPosition) {
-        self.widget_ids.push(widget_id);
-        self.widget_positions.push(widget_position);
+    /// Adds an item to the `VerticalLayout` managed stack.
+    fn insert_item(&mut self, item: LayoutItem, item_position: LayoutPosition) {
+        self.items.push(item);
+        self.item_positions.push(item_position);
         self.invalidated = true;
     }
 
-    /// Appends a widget to the `VerticalLayout` managed stack.
-    fn append_widget(&mut self, widget_id: i32) {
-        let positions = self.widget_positions.len();
-        let widget_position = if self.widget_positions.is_empty() {
+    /// Appends an item to the `VerticalLayout` managed stack.
+    fn append_item(&mut self, item: LayoutItem) {
+        let positions = self.item_positions.len();
+        let item_position = if self.item_positions.is_empty() {
             LayoutPosition::new(0, 0)
         } else {
-            LayoutPosition::new(self.widget_positions[positions - 1].x + 1, 0)
+            LayoutPosition::new(0, self.item_positions[positions - 1].y + 1)
         };
 
-        self.insert_widget(widget_id, widget_position);
+        self.insert_item(item, item_position);
+    }
+
+    fn get_items(&self) -> Vec<LayoutItem> {
+        self.items.clone()
     }
 
     fn set_padding(&mut self, padding: PaddingConstraint) {
@@ -74,61 +81,53 @@ impl Layout for VerticalLayout {
         self.padding
     }
 
-    /// Adjusts the layout of the `Widget`s managed by this `Layout` manager.  Currently only obeys
-    /// the spacing in the object.  The rest of the padding is not (yet) honored.
-    fn do_layout(&mut self, _widgets: &[WidgetContainer]) {
-        if self.widget_ids.len() <= 1 {
+    fn set_bounds(&mut self, origin: Points, size: Size) {
+        self.origin = origin;
+        self.size = size;
+        self.invalidated = true;
+    }
+
+    fn get_bounds(&self) -> (Points, Size) {
+        (self.origin.clone(), self.size.clone())
+    }
+
+    /// Adjusts the layout of the `Widget`s managed by this `Layout` manager.  The `top`, `bottom`,
+    /// `left` and `right` padding is applied around the edges of the `Layout`, and the `spacing`
+    /// is applied between each item.  The height of the `Layout` is divided between the
+    /// items based on their size hints and stretch factors.  Contained `Layout`s are laid out
+    /// within the bounds they are given.
+    fn do_layout(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.items.is_empty() {
             return;
         }
 
-        let offset_x: i32 = self.origin[0];
-        let offset_y: i32 = self.origin[1];
-        let num_widgets = self.widget_ids.len() as u32;
-        let widget_height = self.size[SIZE_HEIGHT] / num_widgets as u32;
-        let subtractor_bottom = ((self.padding.spacing as f64 / 2.0).ceil()) as u32;
-        let subtractor_top = ((self.padding.spacing as f64 / 2.0).floor()) as u32;
-
-        for i in 0..num_widgets {
-            let set_y: i32;
-            let mut set_height: u32 = widget_height;
-            let widget_id = self.widget_ids[i as usize];
-
-            if i == 0 {
-                set_y = (i * set_height) as i32 + self.padding.top;
-                set_height = widget_height - subtractor_bottom - self.padding.top as u32;
-            } else if i == num_widgets - 1 {
-                set_y = (i * set_height) as i32 + subtractor_top as i32;
-                set_height = widget_height - subtractor_top - self.padding.bottom as u32;
-            } else {
-                set_y = (i * set_height) as i32 + subtractor_top as i32;
-                set_height = widget_height - subtractor_top - subtractor_bottom;
-            }
-
-            _widgets[widget_id as usize]
-                .widget
-                .borrow_mut()
-                .get_config()
-                .set_point(
-                    CONFIG_ORIGIN,
-                    offset_x + self.padding.left,
-                    offset_y + set_y,
-                );
-
-            _widgets[widget_id as usize]
-                .widget
-                .borrow_mut()
-                .get_config()
-                .set_size(
-                    CONFIG_SIZE,
-                    self.size[SIZE_WIDTH] - self.padding.right as u32 - self.padding.left as u32,
-                    set_height,
-                );
-
-            _widgets[widget_id as usize]
-                .widget
-                .borrow_mut()
-                .get_config()
-                .set_invalidated(true);
+        let spacing = self.padding.spacing.max(0) * (self.items.len() as i32 - 1);
+        let available_width =
+            (self.size[SIZE_WIDTH] as i32 - self.padding.left - self.padding.right).max(0) as u32;
+        let available_height =
+            (self.size[SIZE_HEIGHT] as i32 - self.padding.top - self.padding.bottom - spacing)
+                .max(0) as u32;
+        let hints: Vec<SizeHint> = self
+            .items
+            .iter()
+            .map(|item| get_item_size_hint(*item, _widgets, SIZE_HEIGHT))
+            .collect();
+        let heights = distribute_space(available_height, &hints);
+        let set_x = self.origin[0] + self.padding.left;
+        let mut set_y = self.origin[1] + self.padding.top;
+
+        for (i, item) in self.items.iter().enumerate() {
+            let max_width = get_item_size_hint(*item, _widgets, SIZE_WIDTH).max;
+
+            place_item(
+                *item,
+                _widgets,
+                _layouts,
+                vec![set_x, set_y],
+                vec![available_width.min(max_width), heights[i]],
+            );
+
+            set_y += heights[i] as i32 + self.padding.spacing.max(0);
         }
 
         self.invalidated = false;
@@ -139,6 +139,39 @@ mod macros {
                     }
                 }
             }
+
+            /// This function is a macro-created key pressed callback override, created by the
+            /// `default_widget_callbacks!()` macro.
+            fn key_pressed_callback(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer], _keycode: sdl2::keyboard::Keycode, _keymod: sdl2::keyboard::Mod) {
+                if self.get_callbacks().has_on_key_pressed() {
+                    if let Some(mut cb) = self.get_callbacks().on_key_pressed.take() {
+                        cb(self, _widgets, _layouts, _keycode, _keymod);
+                        self.get_callbacks().on_key_pressed = Some(cb);
+                    }
+                }
+            }
+
+            /// This function is a macro-created key released callback override, created by the
+            /// `default_widget_callbacks!()` macro.
+            fn key_released_callback(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer], _keycode: sdl2::keyboard::Keycode, _keymod: sdl2::keyboard::Mod) {
+                if self.get_callbacks().has_on_key_released() {
+                    if let Some(mut cb) = self.get_callbacks().on_key_released.take() {
+                        cb(self, _widgets, _layouts, _keycode, _keymod);
+                        self.get_callbacks().on_key_released = Some(cb);
+                    }
+                }
+            }
+
+            /// This function is a macro-created text input callback override, created by the
+            /// `default_widget_callbacks!()` macro.
+            fn text_input_callback(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer], _text: String) {
+                if self.get_callbacks().has_on_text_input() {
+                    if let Some(mut cb) = self.get_callbacks().on_text_input.take() {
+                        cb(self, _widgets, _layouts, _text);
+                        self.get_callbacks().on_text_input = Some(cb);
+                    }
+                }
+            }
         }
     }
 
@@ -16,6 +16,7 @@
 use crate::render::layout_cache::LayoutContainer;
 use crate::render::widget::Widget;
 use crate::render::widget_cache::WidgetContainer;
+use sdl2::keyboard::{Keycode, Mod};
 
 /// This is an `FnMut` type that takes no additional parameters, returning a mutable reference
 /// to the current `Widget`, and borrowing the `WidgetContainer` and `LayoutContainer` lists.
@@ -33,6 +34,17 @@ pub type FunctionPointParametersType =
 pub type FunctionClickParametersType =
     Option<Box<dyn FnMut(&mut dyn Widget, &[WidgetContainer], &[LayoutContainer], u8, u8, bool)>>;
 
+/// This is an `FnMut` that takes a `Keycode` and the `Mod` key modifiers that were active when the
+/// key event occurred, returning a mutable reference to the current `Widget`, and borrowing the
+/// `WidgetContainer` and `LayoutContainer` lists.
+pub type FunctionKeyParametersType =
+    Option<Box<dyn FnMut(&mut dyn Widget, &[WidgetContainer], &[LayoutContainer], Keycode, Mod)>>;
+
+/// This is an `FnMut` that takes a `String` of text that was entered, returning a mutable reference
+/// to the current `Widget`, and borrowing the `WidgetContainer` and `LayoutContainer` lists.
+pub type FunctionTextParametersType =
+    Option<Box<dyn FnMut(&mut dyn Widget, &[WidgetContainer], &[LayoutContainer], String)>>;
+
 /// This is a registry that contains a series of `FnMut` definitions for actions that can be applied
 /// to a `Widget`.  These can vary from a screen refresh (`tick`), to a mouse move event, etc.  Each
 /// callback gains access to the list of `WidgetContainer` objects stored by the cache.  This is
@@ -72,12 +84,27 @@ pub struct CallbackRegistry {
     /// the mouse button was pressed (`true`) or released (`false`).
     pub on_mouse_clicked: FunctionClickParametersType,
 
+    /// This function is called when a key is pressed while the `Widget` has focus.  It contains the
+    /// `Keycode` of the key that was pressed, along with the `Mod` modifier keys that were held.
+    pub on_key_pressed: FunctionKeyParametersType,
+
+    /// This function is called when a key is released while the `Widget` has focus.  It contains
+    /// the `Keycode` of the key that was released, along with the `Mod` modifier keys that were held.
+    pub on_key_released: FunctionKeyParametersType,
+
+    /// This function is called when text is entered while the `Widget` has focus.  It contains the
+    /// `String` of text that was entered, which may contain more than one character.
+    pub on_text_input: FunctionTextParametersType,
+
     has_on_tick: bool,
     has_on_mouse_entered: bool,
     has_on_mouse_exited: bool,
     has_on_mouse_moved: bool,
     has_on_mouse_scrolled: bool,
     has_on_mouse_clicked: bool,
+    has_on_key_pressed: bool,
+    has_on_key_released: bool,
+    has_on_text_input: bool,
 }
 
 /// Implementation of the `CallbackRegistry`.
@@ -91,12 +118,18 @@ impl CallbackRegistry {
             on_mouse_moved: None,
             on_mouse_scrolled: None,
             on_mouse_clicked: None,
+            on_key_pressed: None,
+            on_key_released: None,
+            on_text_input: None,
             has_on_tick: false,
             has_on_mouse_entered: false,
             has_on_mouse_exited: false,
             has_on_mouse_moved: false,
             has_on_mouse_scrolled: false,
             has_on_mouse_clicked: false,
+            has_on_key_pressed: false,
+            has_on_key_released: false,
+            has_on_text_input: false,
         }
     }
 
@@ -160,6 +193,36 @@ impl CallbackRegistry {
         self.has_on_mouse_clicked = true;
     }
 
+    /// Assigns an `FnMut` that will be called when a key is pressed while the `Widget` has focus.
+    /// If this is not set, this function will be bypassed.
+    pub fn on_key_pressed<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut dyn Widget, &[WidgetContainer], &[LayoutContainer], Keycode, Mod) + 'static,
+    {
+        self.on_key_pressed = Some(Box::new(callback));
+        self.has_on_key_pressed = true;
+    }
+
+    /// Assigns an `FnMut` that will be called when a key is released while the `Widget` has focus.
+    /// If this is not set, this function will be bypassed.
+    pub fn on_key_released<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut dyn Widget, &[WidgetContainer], &[LayoutContainer], Keycode, Mod) + 'static,
+    {
+        self.on_key_released = Some(Box::new(callback));
+        self.has_on_key_released = true;
+    }
+
+    /// Assigns an `FnMut` that will be called when text is entered while the `Widget` has focus.
+    /// If this is not set, this function will be bypassed.
+    pub fn on_text_input<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut dyn Widget, &[WidgetContainer], &[LayoutContainer], String) + 'static,
+    {
+        self.on_text_input = Some(Box::new(callback));
+        self.has_on_text_input = true;
+    }
+
     /// Tells the `Widget` whether or not an `on_tick` callback has been set.
     pub fn has_on_tick(&mut self) -> bool {
         self.has_on_tick
@@ -189,6 +252,21 @@ impl CallbackRegistry {
     pub fn has_on_mouse_clicked(&mut self) -> bool {
         self.has_on_mouse_clicked
     }
+
+    /// Tells the `Widget` whether or not an `on_key_pressed` callback has been set.
+    pub fn has_on_key_pressed(&mut self) -> bool {
+        self.has_on_key_pressed
+    }
+
+    /// Tells the `Widget` whether or not an `on_key_released` callback has been set.
+    pub fn has_on_key_released(&mut self) -> bool {
+        self.has_on_key_released
+    }
+
+    /// Tells the `Widget` whether or not an `on_text_input` callback has been set.
+    pub fn has_on_text_input(&mut self) -> bool {
+        self.has_on_text_input
+    }
 }
 
 pub fn widget_id_for_name(widgets: &[WidgetContainer], name: String) -> usize {
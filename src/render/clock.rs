@@ -0,0 +1,42 @@
+// Pushrod Rendering Library
+// Clock
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+thread_local! {
+    static MANUAL_TIME: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Returns the current time in milliseconds.  This is the time used by the `Engine` and by
+/// `Widget`s for anything that depends on the passing of time, such as tooltip delays, timers and
+/// animations.  Unless a manual time has been set on the current thread with `set_manual_time`,
+/// this is the system time.
+pub fn time_ms() -> u64 {
+    if let Some(time) = MANUAL_TIME.with(|x| x.get()) {
+        return time;
+    }
+
+    let since_the_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+
+    (since_the_epoch.as_secs() * 1_000) + u64::from(since_the_epoch.subsec_millis())
+}
+
+/// Sets the time returned by `time_ms` on the current thread to `time`, in milliseconds, so that
+/// time only moves when it is set again.  This is used by the `EventPlayer` to replay events at
+/// the time they were recorded.  Setting `None` returns to the system time.
+pub fn set_manual_time(time: Option<u64>) {
+    MANUAL_TIME.with(|x| x.set(time));
+}
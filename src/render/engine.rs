@@ -13,15 +13,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use sdl2::event::Event;
+use sdl2::event::{Event, WindowEvent};
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::mouse::MouseButton;
+use sdl2::render::Canvas;
 use sdl2::video::Window;
 use sdl2::Sdl;
 
-use crate::render::layout::Layout;
+use crate::render::clock::time_ms;
+use crate::render::event_recorder::EventRecorder;
+use crate::render::layout::{Anchor, Layout};
 use crate::render::layout_cache::LayoutCache;
+use crate::render::modifiers::set_mod_state;
 use crate::render::widget::{BaseWidget, Widget};
 use crate::render::widget_cache::WidgetCache;
-use crate::render::{make_points_origin, make_size};
+use crate::render::widget_config::{CONFIG_CONTEXT_MENU, CONFIG_SIZE};
+use crate::render::{make_points_origin, make_size, Size};
 use sdl2::pixels::Color;
 use std::thread::sleep;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -37,9 +44,19 @@ pub struct Engine {
     widget_cache: WidgetCache,
     layout_cache: LayoutCache,
     current_widget_id: i32,
+    captured_widget_id: i32,
+    focused_widget_id: i32,
+    modal_stack: Vec<(i32, i32)>,
+    context_menu: Option<(i32, i32)>,
+    mouse_point: (i32, i32),
+    hover_time: u64,
+    tooltip_delay: u64,
+    tooltip_point: Option<(i32, i32)>,
+    tooltip_suppressed: bool,
     frame_rate: u8,
     running: bool,
     on_exit: OnExitCallbackType,
+    recorder: Option<EventRecorder>,
 }
 
 /// This is the heart of the Pushrod event engine, and is what is used to drive the interaction
@@ -82,9 +99,19 @@ impl Engine {
             widget_cache: cache,
             layout_cache: LayoutCache::new(),
             current_widget_id: 0,
+            captured_widget_id: 0,
+            focused_widget_id: 0,
+            modal_stack: Vec::new(),
+            context_menu: None,
+            mouse_point: (0, 0),
+            hover_time: time_ms(),
+            tooltip_delay: 500,
+            tooltip_point: None,
+            tooltip_suppressed: false,
             frame_rate,
             running: true,
             on_exit: None,
+            recorder: None,
         }
     }
 
@@ -94,11 +121,146 @@ impl Engine {
         self.widget_cache.add_widget(widget, widget_name)
     }
 
+    /// Adds a `Widget` to the display list as a child of the `Widget` specified by `parent_id`.  The
+    /// point of origin of the `Widget` is relative to its parent.  Children are drawn after their
+    /// parent, clipped to the parent's bounds, and follow the parent when it is moved, hidden,
+    /// or disabled.  Returns the ID of the `Widget` that was added.
+    pub fn add_widget_to_parent(
+        &mut self,
+        parent_id: i32,
+        widget: Box<dyn Widget>,
+        widget_name: String,
+    ) -> i32 {
+        self.widget_cache
+            .add_widget_to_parent(parent_id, widget, widget_name)
+    }
+
     /// Adds a `Layout` to the `Layout` list.
     pub fn add_layout(&mut self, layout: Box<dyn Layout>) -> i32 {
         self.layout_cache.add_layout(layout)
     }
 
+    /// Anchors the `Layout` specified by `layout_id` to the edges of the `Window`.  When the
+    /// `Window` is resized, the `Layout`'s bounds are adjusted to keep the same distance from the
+    /// anchored edges, based on its bounds at the time this function is called.  This should only
+    /// be used on top-level `Layout`s, as `Layout`s contained in other `Layout`s receive their
+    /// bounds from their parent.
+    pub fn set_layout_anchor(&mut self, layout_id: i32, anchor: Anchor) {
+        let window_size = self.get_window_size();
+
+        self.layout_cache.set_anchor(layout_id, anchor, window_size);
+    }
+
+    /// Returns the current size of the application `Window`, as tracked by the base `Widget`.
+    pub fn get_window_size(&mut self) -> Size {
+        self.widget_cache
+            .get_container_by_id(0)
+            .widget
+            .borrow_mut()
+            .get_config()
+            .get_size(CONFIG_SIZE)
+    }
+
+    /// Resizes the application area to `w` by `h`.  The base `Widget` is resized, anchored `Layout`s
+    /// are given their new bounds, and all `Widget`s are invalidated so that the screen is redrawn.
+    /// This is called automatically when the `Window` is resized.
+    pub fn resize(&mut self, w: u32, h: u32) {
+        let window_size = self.get_window_size();
+
+        if window_size[0] == w && window_size[1] == h {
+            return;
+        }
+
+        self.widget_cache
+            .get_container_by_id(0)
+            .widget
+            .borrow_mut()
+            .set_size(make_size(w, h));
+        self.layout_cache.resize(make_size(w, h));
+        self.widget_cache.invalidate_all();
+    }
+
+    /// Moves keyboard focus to the `Widget` specified by `widget_id`.  The `Widget` must have its
+    /// `CONFIG_FOCUSABLE` toggle set, and be visible and enabled; otherwise, focus is cleared.
+    /// Specifying a `widget_id` of `0` clears the focus.
+    pub fn set_focused_widget(&mut self, widget_id: i32) {
+        let new_focus_id = if self.widget_cache.is_focusable(widget_id) {
+            widget_id
+        } else {
+            0
+        };
+
+        if new_focus_id == self.focused_widget_id {
+            return;
+        }
+
+        if self.focused_widget_id != 0 {
+            self.widget_cache.set_focused(
+                self.focused_widget_id,
+                false,
+                self.layout_cache.get_layout_cache(),
+            );
+        }
+
+        self.focused_widget_id = new_focus_id;
+
+        if self.focused_widget_id != 0 {
+            self.widget_cache.set_focused(
+                self.focused_widget_id,
+                true,
+                self.layout_cache.get_layout_cache(),
+            );
+        }
+    }
+
+    /// Returns the ID of the `Widget` that currently has keyboard focus.  A `0` indicates that no
+    /// `Widget` has focus.
+    pub fn get_focused_widget_id(&self) -> i32 {
+        self.focused_widget_id
+    }
+
+    /// Sets the context menu of the `Widget` specified by `widget_id` to the `Widget` specified by
+    /// `menu_id`, such as a `ContextMenuWidget`.  The context menu is opened at the mouse position
+    /// when the right mouse button is pressed over the `Widget`, or over any of its children that
+    /// do not have a context menu of their own.  Setting the context menu of the top-level `Widget`
+    /// (ID `0`) applies it to the entire `Window`.  A `menu_id` of `0` removes the context menu.
+    pub fn set_context_menu(&mut self, widget_id: i32, menu_id: i32) {
+        self.widget_cache
+            .get_container_by_id(widget_id)
+            .widget
+            .borrow_mut()
+            .get_config()
+            .set_numeric(CONFIG_CONTEXT_MENU, menu_id);
+    }
+
+    /// Sets the time that the mouse must rest over a `Widget` before its `CONFIG_TOOLTIP` is shown,
+    /// in milliseconds.  The default is `500`.
+    pub fn set_tooltip_delay(&mut self, tooltip_delay: u64) {
+        self.tooltip_delay = tooltip_delay;
+    }
+
+    /// Returns the frame rate of the `Engine`, in frames per second.
+    pub fn get_frame_rate(&self) -> u8 {
+        self.frame_rate
+    }
+
+    /// Returns the `WidgetCache` managed by this `Engine`, so that `Widget`s can be inspected or
+    /// changed outside of a callback.
+    pub fn get_widget_cache(&mut self) -> &mut WidgetCache {
+        &mut self.widget_cache
+    }
+
+    /// Records all events received from the event pump while the `Engine` is running, using the
+    /// `EventRecorder` specified.
+    pub fn set_event_recorder(&mut self, recorder: EventRecorder) {
+        self.recorder = Some(recorder);
+    }
+
+    /// Indicates whether or not the `Engine` is still running.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
     /// Sets running flag: `false` shuts down the engine.
     pub fn set_running(&mut self, state: bool) {
         self.running = state;
@@ -125,6 +287,375 @@ impl Engine {
         }
     }
 
+    /// Dispatches a single SDL2 `Event` to the `Widget`s managed by this `Engine`, exactly as if
+    /// it had been received from the event pump.  Mouse events are routed to the `Widget` under
+    /// the mouse, and pressing a mouse button closes any overlays owned by other `Widget`s.
+    /// Pressing the right mouse button also opens the context menu of the `Widget` under the mouse,
+    /// moving focus into it until it is closed.  Moving the mouse restarts the tooltip delay, and
+    /// pressing a mouse button hides the tooltip until the mouse moves to another `Widget`.
+    /// Keyboard events are routed to the focused `Widget`, and update the state of the modifier
+    /// keys returned by `modifiers::mod_state`.  A `Quit` event calls the `on_exit`
+    /// callback, stopping the `Engine` if it returns `true`.  While a modal `Widget` is shown, only
+    /// it and its children receive input.  This can be used to inject synthetic
+    /// events, such as when replaying events with an `EventPlayer`.
+    pub fn dispatch_event(&mut self, event: Event) {
+        self.update_modal();
+        self.update_context_menu();
+
+        if let Event::Window {
+            win_event: WindowEvent::Leave,
+            ..
+        } = event
+        {
+            self.hide_tooltip();
+        }
+
+        // The modifier keys are tracked for every key event, as only the focused Widget receives
+        // them.
+        match event {
+            Event::KeyDown { keymod, .. } | Event::KeyUp { keymod, .. } => set_mod_state(keymod),
+            _ => (),
+        }
+
+        match event {
+            Event::MouseButtonDown {
+                mouse_btn,
+                clicks,
+                x,
+                y,
+                ..
+            } => {
+                self.hide_tooltip();
+
+                // While a modal Widget is shown, clicks outside of it are ignored.
+                if !self.widget_cache.is_input_allowed(self.current_widget_id) {
+                    return;
+                }
+
+                self.widget_cache
+                    .dismiss_overlays(self.current_widget_id, self.layout_cache.get_layout_cache());
+                self.set_focused_widget(self.current_widget_id);
+                self.widget_cache.button_clicked(
+                    self.current_widget_id,
+                    mouse_btn as u8,
+                    clicks,
+                    true,
+                    self.layout_cache.get_layout_cache(),
+                );
+                self.widget_cache.child_clicked(
+                    self.current_widget_id,
+                    mouse_btn as u8,
+                    clicks,
+                    true,
+                    self.layout_cache.get_layout_cache(),
+                );
+
+                if self.widget_cache.captures_mouse(self.current_widget_id) {
+                    self.captured_widget_id = self.current_widget_id;
+                }
+
+                if mouse_btn == MouseButton::Right {
+                    let menu_id = self.widget_cache.open_context_menu(
+                        self.current_widget_id,
+                        x,
+                        y,
+                        self.layout_cache.get_layout_cache(),
+                    );
+
+                    if menu_id != 0 {
+                        let focus_id = self.focused_widget_id;
+
+                        self.set_focused_widget(menu_id);
+                        self.context_menu = Some((menu_id, focus_id));
+                    }
+                }
+            }
+
+            Event::MouseButtonUp {
+                mouse_btn, clicks, ..
+            } => {
+                self.widget_cache.button_clicked(
+                    -1,
+                    mouse_btn as u8,
+                    clicks,
+                    false,
+                    self.layout_cache.get_layout_cache(),
+                );
+
+                if self.widget_cache.is_input_allowed(self.current_widget_id) {
+                    self.widget_cache.child_clicked(
+                        self.current_widget_id,
+                        mouse_btn as u8,
+                        clicks,
+                        false,
+                        self.layout_cache.get_layout_cache(),
+                    );
+                }
+
+                self.captured_widget_id = 0;
+            }
+
+            Event::MouseMotion { x, y, .. } => {
+                self.mouse_point = (x, y);
+
+                // Moving the mouse restarts the tooltip delay, unless the tooltip of the Widget
+                // under the mouse is already shown.
+                if !self.update_current_widget() && self.tooltip_point.is_none() {
+                    self.hover_time = time_ms();
+                }
+
+                // A Widget that captures the mouse receives all movement while a button is held.
+                let moved_widget_id = if self.captured_widget_id != 0 {
+                    self.captured_widget_id
+                } else {
+                    self.current_widget_id
+                };
+
+                self.widget_cache.mouse_moved(
+                    moved_widget_id,
+                    vec![x, y],
+                    self.layout_cache.get_layout_cache(),
+                );
+            }
+
+            Event::MouseWheel { x, y, .. } => {
+                if self.widget_cache.is_input_allowed(self.current_widget_id) {
+                    self.widget_cache.mouse_scrolled(
+                        self.current_widget_id,
+                        vec![x, y],
+                        self.layout_cache.get_layout_cache(),
+                    );
+                }
+            }
+
+            Event::KeyDown {
+                keycode: Some(Keycode::Tab),
+                keymod,
+                ..
+            } if !keymod
+                .intersects(Mod::LCTRLMOD | Mod::RCTRLMOD | Mod::LALTMOD | Mod::RALTMOD) =>
+            {
+                let reverse = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+                let next_focus_id = self
+                    .widget_cache
+                    .find_next_focusable(self.focused_widget_id, reverse);
+
+                self.set_focused_widget(next_focus_id);
+            }
+
+            Event::KeyDown {
+                keycode: Some(keycode),
+                keymod,
+                ..
+            } => {
+                if self.focused_widget_id != 0 {
+                    self.widget_cache.key_pressed(
+                        self.focused_widget_id,
+                        keycode,
+                        keymod,
+                        self.layout_cache.get_layout_cache(),
+                    );
+                }
+            }
+
+            Event::KeyUp {
+                keycode: Some(keycode),
+                keymod,
+                ..
+            } => {
+                if self.focused_widget_id != 0 {
+                    self.widget_cache.key_released(
+                        self.focused_widget_id,
+                        keycode,
+                        keymod,
+                        self.layout_cache.get_layout_cache(),
+                    );
+                }
+            }
+
+            Event::TextInput { text, .. } => {
+                if self.focused_widget_id != 0 {
+                    self.widget_cache.text_input(
+                        self.focused_widget_id,
+                        text,
+                        self.layout_cache.get_layout_cache(),
+                    );
+                }
+            }
+
+            Event::Window {
+                win_event: WindowEvent::SizeChanged(w, h),
+                ..
+            }
+            | Event::Window {
+                win_event: WindowEvent::Resized(w, h),
+                ..
+            } => {
+                self.resize(w as u32, h as u32);
+            }
+
+            Event::Quit { .. } => {
+                if self.call_exit_callback() {
+                    self.running = false;
+                }
+            }
+
+            remaining_event => {
+                self.widget_cache.other_event(
+                    self.current_widget_id,
+                    remaining_event,
+                    self.layout_cache.get_layout_cache(),
+                );
+            }
+        }
+    }
+
+    /// Performs a single step of the `Engine` without drawing: `Widget`s are ticked, `Layout`s are
+    /// applied, and child `Widget`s are moved along with their parents.  This is called as part of
+    /// `draw_frame`, and can be used along with `dispatch_event` to drive the `Engine` without a
+    /// display.
+    pub fn step(&mut self) {
+        self.widget_cache.tick(self.layout_cache.get_layout_cache());
+        self.layout_cache
+            .do_layout(self.widget_cache.borrow_cache());
+        self.widget_cache.update_children();
+        self.update_modal();
+        self.update_context_menu();
+        self.update_tooltip();
+    }
+
+    /// Internal function that tracks modal `Widget`s as they are shown and hidden.  When a modal
+    /// `Widget` is shown, open overlays are closed, the focused `Widget` is remembered, and focus
+    /// moves to the first focusable `Widget` inside of the modal `Widget`.  When it is hidden
+    /// again, focus is restored to the `Widget` that had it before.  Either way, the mouse is
+    /// released, and the `Widget` under the mouse is found again, so that no input reaches a
+    /// `Widget` that the modal `Widget` covers.
+    fn update_modal(&mut self) {
+        let modal_id = self.widget_cache.get_modal_id();
+
+        if self.modal_stack.last().map(|x| x.0).unwrap_or(0) == modal_id {
+            return;
+        }
+
+        self.captured_widget_id = 0;
+        self.update_current_widget();
+
+        // Modal Widgets above the one that is shown now have been closed, so restore the focus
+        // that was saved when they were shown.
+        if modal_id == 0 || self.modal_stack.iter().any(|x| x.0 == modal_id) {
+            while let Some((closed_id, previous_focus_id)) = self.modal_stack.last().cloned() {
+                if closed_id == modal_id {
+                    break;
+                }
+
+                self.modal_stack.pop();
+                self.set_focused_widget(previous_focus_id);
+            }
+        }
+
+        if modal_id != 0 && self.modal_stack.last().map(|x| x.0) != Some(modal_id) {
+            self.modal_stack.push((modal_id, self.focused_widget_id));
+            self.widget_cache
+                .dismiss_overlays(modal_id, self.layout_cache.get_layout_cache());
+
+            let focus_id = if self.widget_cache.is_focusable(modal_id) {
+                modal_id
+            } else {
+                self.widget_cache.find_next_focusable(modal_id, false)
+            };
+
+            self.set_focused_widget(focus_id);
+        }
+    }
+
+    /// Internal function that finds the `Widget` under the mouse.  If it has changed, the previous
+    /// `Widget` is told that the mouse exited, the new one that the mouse entered, and the tooltip
+    /// delay is restarted.  Returns `true` if the `Widget` under the mouse has changed.
+    fn update_current_widget(&mut self) -> bool {
+        let cur_widget_id = self.current_widget_id;
+
+        self.current_widget_id = self
+            .widget_cache
+            .find_widget(self.mouse_point.0, self.mouse_point.1);
+
+        if cur_widget_id == self.current_widget_id {
+            return false;
+        }
+
+        self.hover_time = time_ms();
+        self.tooltip_point = None;
+        self.tooltip_suppressed = false;
+
+        self.widget_cache
+            .mouse_exited(cur_widget_id, self.layout_cache.get_layout_cache());
+        self.widget_cache
+            .mouse_entered(self.current_widget_id, self.layout_cache.get_layout_cache());
+
+        true
+    }
+
+    /// Internal function that restores focus when the open context menu is closed.  A context menu
+    /// can only be focused while it is open, so focus is returned to the `Widget` that had it when
+    /// the menu was opened, unless focus has moved somewhere else in the meantime.
+    fn update_context_menu(&mut self) {
+        if let Some((menu_id, focus_id)) = self.context_menu {
+            if !self.widget_cache.is_focusable(menu_id) {
+                self.context_menu = None;
+
+                if self.focused_widget_id == menu_id {
+                    self.set_focused_widget(focus_id);
+                }
+            }
+        }
+    }
+
+    /// Internal function that shows the tooltip of the `Widget` under the mouse once the mouse has
+    /// rested over it for the tooltip delay, and hides it if the `Widget` no longer has one.
+    fn update_tooltip(&mut self) {
+        let has_tooltip = !self
+            .widget_cache
+            .get_tooltip(self.current_widget_id)
+            .is_empty();
+
+        if !has_tooltip {
+            self.tooltip_point = None;
+        } else if self.tooltip_point.is_none()
+            && !self.tooltip_suppressed
+            && time_ms().saturating_sub(self.hover_time) >= self.tooltip_delay
+        {
+            self.tooltip_point = Some(self.mouse_point);
+        }
+    }
+
+    /// Internal function that hides the tooltip, and keeps it hidden until the mouse moves to
+    /// another `Widget`.
+    fn hide_tooltip(&mut self) {
+        self.tooltip_point = None;
+        self.tooltip_suppressed = true;
+    }
+
+    /// Performs a single frame of drawing against the `Canvas` specified: `Widget`s are ticked,
+    /// `Layout`s are applied, damaged areas are redrawn, and the result is copied to the `Canvas`,
+    /// with the modal `Widget` and any open overlays drawn over the top.  The `Canvas` is not
+    /// presented, so this can be used to draw against a hidden or software `Canvas`, such as the
+    /// one created by `HiddenWindowCanvas`.
+    pub fn draw_frame(&mut self, c: &mut Canvas<Window>) {
+        self.step();
+        self.widget_cache.draw_loop(c);
+        self.widget_cache.copy_back_buffer(c);
+        self.widget_cache.draw_modal(c);
+        self.widget_cache.draw_overlays(c);
+
+        match self.tooltip_point {
+            Some((x, y)) => {
+                let tooltip = self.widget_cache.get_tooltip(self.current_widget_id);
+
+                self.widget_cache.draw_tooltip(c, &tooltip, x, y);
+            }
+            None => self.widget_cache.clear_tooltip(),
+        }
+    }
+
     /// Main application run loop, controls interaction between the user and the application.
     pub fn run(&mut self, sdl: Sdl, window: Window) {
         let mut canvas = window
@@ -148,80 +679,21 @@ impl Engine {
                 .as_millis();
 
             for event in event_pump.poll_iter() {
-                match event {
-                    Event::MouseButtonDown {
-                        mouse_btn, clicks, ..
-                    } => {
-                        self.widget_cache.button_clicked(
-                            self.current_widget_id,
-                            mouse_btn as u8,
-                            clicks,
-                            true,
-                            self.layout_cache.get_layout_cache(),
-                        );
-                    }
-
-                    Event::MouseButtonUp {
-                        mouse_btn, clicks, ..
-                    } => {
-                        self.widget_cache.button_clicked(
-                            -1,
-                            mouse_btn as u8,
-                            clicks,
-                            false,
-                            self.layout_cache.get_layout_cache(),
-                        );
-                    }
-
-                    Event::MouseMotion { x, y, .. } => {
-                        let cur_widget_id = self.current_widget_id;
-
-                        self.current_widget_id = self.widget_cache.find_widget(x, y);
-
-                        if cur_widget_id != self.current_widget_id {
-                            self.widget_cache
-                                .mouse_exited(cur_widget_id, self.layout_cache.get_layout_cache());
-                            self.widget_cache.mouse_entered(
-                                self.current_widget_id,
-                                self.layout_cache.get_layout_cache(),
-                            );
-                        }
-
-                        self.widget_cache.mouse_moved(
-                            self.current_widget_id,
-                            vec![x, y],
-                            self.layout_cache.get_layout_cache(),
-                        );
-                    }
-
-                    Event::MouseWheel { x, y, .. } => {
-                        self.widget_cache.mouse_scrolled(
-                            self.current_widget_id,
-                            vec![x, y],
-                            self.layout_cache.get_layout_cache(),
-                        );
+                if let Some(recorder) = self.recorder.as_mut() {
+                    if let Err(e) = recorder.record(&event) {
+                        eprintln!("Unable to record event, recording stopped: {}", e);
+                        self.recorder = None;
                     }
+                }
 
-                    Event::Quit { .. } => {
-                        if self.call_exit_callback() {
-                            break 'running;
-                        }
-                    }
+                self.dispatch_event(event);
+            }
 
-                    remaining_event => {
-                        self.widget_cache.other_event(
-                            self.current_widget_id,
-                            remaining_event,
-                            self.layout_cache.get_layout_cache(),
-                        );
-                    }
-                }
+            if !self.running {
+                break 'running;
             }
 
-            self.widget_cache.tick(self.layout_cache.get_layout_cache());
-            self.layout_cache
-                .do_layout(self.widget_cache.borrow_cache());
-            self.widget_cache.draw_loop(&mut canvas);
+            self.draw_frame(&mut canvas);
 
             canvas.present();
 
@@ -0,0 +1,337 @@
+// Pushrod Rendering Library
+// Event Recording Library
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::clock::set_manual_time;
+use crate::render::engine::Engine;
+use sdl2::event::{Event, WindowEvent};
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::mouse::{MouseButton, MouseState, MouseWheelDirection};
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+use std::fs::{read_to_string, File};
+use std::io::{self, LineWriter, Write};
+use std::time::SystemTime;
+
+/// This is the storage object for the `EventRecorder`.  It stores the file being written to, and
+/// the time at which recording started.
+pub struct EventRecorder {
+    writer: LineWriter<File>,
+    start: SystemTime,
+}
+
+/// This is an `EventRecorder`, which writes SDL2 `Event`s to a file, one event per line, along with
+/// the number of milliseconds since recording started.  Only the events that are handled by the
+/// `Engine` are recorded: mouse motion, buttons and wheel, key presses and releases, text input,
+/// `Window` resizes, and quit requests.  Each line is written as soon as it is recorded, so that
+/// the file is complete even if the application exits unexpectedly.
+impl EventRecorder {
+    /// Creates a new `EventRecorder`, which writes to the `file` specified, replacing any existing
+    /// contents.
+    pub fn new(file: &str) -> Result<Self, String> {
+        let file = File::create(file).map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            writer: LineWriter::new(file),
+            start: SystemTime::now(),
+        })
+    }
+
+    /// Records an `Event`.  Events that cannot be replayed are ignored.  Returns an error if the
+    /// event could not be written to the file.
+    pub fn record(&mut self, event: &Event) -> io::Result<()> {
+        let timestamp = self.start.elapsed().map(|x| x.as_millis()).unwrap_or(0);
+
+        match event_to_string(event) {
+            Some(line) => writeln!(self.writer, "{} {}", timestamp, line),
+            None => Ok(()),
+        }
+    }
+}
+
+/// This is the storage object for the `EventPlayer`.  It stores the list of events to be played
+/// back, along with their timestamps, the position of the next event to play, and the time that
+/// the timestamps are relative to.
+pub struct EventPlayer {
+    events: Vec<(u32, Event)>,
+    position: usize,
+    base_time: u64,
+}
+
+/// This is an `EventPlayer`, which replays events recorded by an `EventRecorder` against an
+/// `Engine`.  Events are dispatched with `Engine::dispatch_event`, and a frame is drawn with
+/// `Engine::draw_frame` after each group of events that share the same timestamp, as `Widget`s
+/// may measure themselves while they are drawn.  Between events, frames are drawn at the frame
+/// rate of the `Engine`, as they would be while it is running.
+///
+/// The clock of the current thread (see `clock::set_manual_time`) is driven by the player, so
+/// that tooltip delays, timers and animations behave as they did while recording, regardless of
+/// the speed of the machine.  Call `start_clock` before the `Engine` and its `Widget`s are built,
+/// so that any times they take when they are created are on the same clock as the replayed
+/// events.  The clock is left at the time of the last frame drawn, until
+/// `clock::set_manual_time(None)` is called.
+///
+/// Any `Canvas` can be drawn against, such as the one in a `HiddenWindowCanvas`.
+impl EventPlayer {
+    /// Loads the events recorded to the `file` specified.
+    pub fn load(file: &str) -> Result<Self, String> {
+        let contents = read_to_string(file).map_err(|e| e.to_string())?;
+
+        EventPlayer::parse(&contents)
+    }
+
+    /// Parses events from a string, in the format written by an `EventRecorder`.  Blank lines and
+    /// lines starting with `#` are ignored.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut events = Vec::new();
+
+        for (line_number, line) in contents.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match string_to_event(line) {
+                Some(event) => events.push(event),
+                None => {
+                    return Err(format!(
+                        "Invalid event on line {}: {}",
+                        line_number + 1,
+                        line
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            events,
+            position: 0,
+            base_time: 0,
+        })
+    }
+
+    /// Sets the clock of the current thread to `base_time`, in milliseconds, and plays events at
+    /// `base_time` plus the time at which they were recorded.  This should be called before the
+    /// `Engine` and its `Widget`s are built.
+    pub fn start_clock(&mut self, base_time: u64) {
+        self.base_time = base_time;
+        set_manual_time(Some(base_time));
+    }
+
+    /// Returns the number of events that have been loaded.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Indicates whether or not any events have been loaded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Indicates whether or not all of the events have been played.
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.events.len()
+    }
+
+    /// Plays all of the remaining events against the `Engine`, drawing against the `Canvas`.
+    pub fn play(&mut self, engine: &mut Engine, c: &mut Canvas<Window>) {
+        self.play_until(engine, c, u32::MAX);
+    }
+
+    /// Plays the remaining events against the `Engine` that were recorded at or before the
+    /// `timestamp` specified, in milliseconds.  A frame is drawn against the `Canvas` after each
+    /// group of events that share the same timestamp, and at the frame rate of the `Engine` until
+    /// the next event.
+    pub fn play_until(&mut self, engine: &mut Engine, c: &mut Canvas<Window>, timestamp: u32) {
+        let frame_time = 1000 / u32::from(engine.get_frame_rate().max(1));
+
+        while self.position < self.events.len() && self.events[self.position].0 <= timestamp {
+            let (event_timestamp, event) = self.events[self.position].clone();
+
+            set_manual_time(Some(self.base_time + u64::from(event_timestamp)));
+            engine.dispatch_event(event);
+            self.position += 1;
+
+            let next_timestamp = self.events.get(self.position).map(|(next, _)| *next);
+
+            if next_timestamp == Some(event_timestamp) {
+                continue;
+            }
+
+            engine.draw_frame(c);
+
+            if let Some(next_timestamp) = next_timestamp.filter(|next| *next <= timestamp) {
+                let mut frame_timestamp = event_timestamp + frame_time;
+
+                while frame_timestamp < next_timestamp {
+                    set_manual_time(Some(self.base_time + u64::from(frame_timestamp)));
+                    engine.draw_frame(c);
+                    frame_timestamp += frame_time;
+                }
+            }
+        }
+    }
+}
+
+/// Converts an `Event` to a line of text.  Returns `None` if the `Event` is not recorded.
+fn event_to_string(event: &Event) -> Option<String> {
+    match event {
+        Event::MouseMotion { x, y, .. } => Some(format!("mouse_motion {} {}", x, y)),
+        Event::MouseButtonDown {
+            mouse_btn,
+            clicks,
+            x,
+            y,
+            ..
+        } => Some(format!(
+            "mouse_down {} {} {} {}",
+            *mouse_btn as u8, clicks, x, y
+        )),
+        Event::MouseButtonUp {
+            mouse_btn,
+            clicks,
+            x,
+            y,
+            ..
+        } => Some(format!(
+            "mouse_up {} {} {} {}",
+            *mouse_btn as u8, clicks, x, y
+        )),
+        Event::MouseWheel { x, y, .. } => Some(format!("mouse_wheel {} {}", x, y)),
+        Event::KeyDown {
+            keycode: Some(keycode),
+            keymod,
+            ..
+        } => Some(format!("key_down {} {}", *keycode as i32, keymod.bits())),
+        Event::KeyUp {
+            keycode: Some(keycode),
+            keymod,
+            ..
+        } => Some(format!("key_up {} {}", *keycode as i32, keymod.bits())),
+        Event::TextInput { text, .. } => Some(format!(
+            "text_input {}",
+            text.replace('\\', "\\\\").replace('\n', "\\n")
+        )),
+        Event::Window {
+            win_event: WindowEvent::SizeChanged(w, h),
+            ..
+        } => Some(format!("resized {} {}", w, h)),
+        Event::Quit { .. } => Some(String::from("quit")),
+        _ => None,
+    }
+}
+
+/// Converts a line of text back to a timestamp and `Event`.  Returns `None` if the line could not
+/// be parsed.
+fn string_to_event(line: &str) -> Option<(u32, Event)> {
+    let mut parts = line.splitn(3, ' ');
+    let timestamp: u32 = parts.next()?.parse().ok()?;
+    let kind = parts.next()?;
+    let arguments = parts.next().unwrap_or("");
+    let numbers: Vec<i32> = arguments
+        .split_whitespace()
+        .map(|x| x.parse::<i32>())
+        .collect::<Result<Vec<i32>, _>>()
+        .unwrap_or_default();
+
+    let event = match (kind, numbers.as_slice()) {
+        ("mouse_motion", [x, y]) => Event::MouseMotion {
+            timestamp,
+            window_id: 0,
+            which: 0,
+            mousestate: MouseState::from_sdl_state(0),
+            x: *x,
+            y: *y,
+            xrel: 0,
+            yrel: 0,
+        },
+        ("mouse_down", [button, clicks, x, y]) => Event::MouseButtonDown {
+            timestamp,
+            window_id: 0,
+            which: 0,
+            mouse_btn: MouseButton::from_ll(*button as u8),
+            clicks: *clicks as u8,
+            x: *x,
+            y: *y,
+        },
+        ("mouse_up", [button, clicks, x, y]) => Event::MouseButtonUp {
+            timestamp,
+            window_id: 0,
+            which: 0,
+            mouse_btn: MouseButton::from_ll(*button as u8),
+            clicks: *clicks as u8,
+            x: *x,
+            y: *y,
+        },
+        ("mouse_wheel", [x, y]) => Event::MouseWheel {
+            timestamp,
+            window_id: 0,
+            which: 0,
+            x: *x,
+            y: *y,
+            direction: MouseWheelDirection::Normal,
+        },
+        ("key_down", [keycode, keymod]) => Event::KeyDown {
+            timestamp,
+            window_id: 0,
+            keycode: Some(Keycode::from_i32(*keycode)?),
+            scancode: None,
+            keymod: Mod::from_bits_truncate(*keymod as u16),
+            repeat: false,
+        },
+        ("key_up", [keycode, keymod]) => Event::KeyUp {
+            timestamp,
+            window_id: 0,
+            keycode: Some(Keycode::from_i32(*keycode)?),
+            scancode: None,
+            keymod: Mod::from_bits_truncate(*keymod as u16),
+            repeat: false,
+        },
+        ("text_input", _) => Event::TextInput {
+            timestamp,
+            window_id: 0,
+            text: unescape(arguments),
+        },
+        ("resized", [w, h]) => Event::Window {
+            timestamp,
+            window_id: 0,
+            win_event: WindowEvent::SizeChanged(*w, *h),
+        },
+        ("quit", []) => Event::Quit { timestamp },
+        _ => return None,
+    };
+
+    Some((timestamp, event))
+}
+
+/// Reverses the escaping of backslashes and newlines performed when recording text input.
+fn unescape(text: &str) -> String {
+    let mut result = String::new();
+    let mut characters = text.chars();
+
+    while let Some(character) = characters.next() {
+        if character == '\\' {
+            match characters.next() {
+                Some('n') => result.push('\n'),
+                Some(other) => result.push(other),
+                None => result.push('\\'),
+            }
+        } else {
+            result.push(character);
+        }
+    }
+
+    result
+}
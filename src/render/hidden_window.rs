@@ -0,0 +1,156 @@
+// Pushrod Rendering Library
+// Hidden Window Rendering Library
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::engine::Engine;
+use sdl2::image::{LoadSurface, SaveSurface};
+use sdl2::pixels::PixelFormatEnum;
+use sdl2::render::Canvas;
+use sdl2::surface::Surface;
+use sdl2::video::Window;
+use sdl2::VideoSubsystem;
+
+/// This is the storage object for the `HiddenWindowCanvas`.  It stores a software `Canvas`
+/// attached to a hidden `Window`.
+pub struct HiddenWindowCanvas {
+    canvas: Canvas<Window>,
+    width: u32,
+    height: u32,
+}
+
+/// This is a `Canvas` that is attached to a hidden `Window`, and drawn by the software renderer,
+/// so that the `Widget` tree can be drawn off screen.  The pixels drawn by the software renderer
+/// are consistent between machines, making it suitable for comparing rendered output against
+/// golden images.
+///
+/// This still requires an SDL video subsystem.  To draw on a machine without a display, start
+/// the process with the `SDL_VIDEODRIVER` environment variable set to `dummy` (for example,
+/// `SDL_VIDEODRIVER=dummy cargo test`), or set the `SDL_VIDEODRIVER` hint before the video
+/// subsystem is initialized.  The video driver is left for the application to choose: this
+/// object never changes the environment of the process.
+///
+/// Example use:
+/// ```rust,no_run
+/// # use pushrod::render::engine::Engine;
+/// # use pushrod::render::hidden_window::HiddenWindowCanvas;
+/// # use pushrod::render::widget::BaseWidget;
+/// # use pushrod::render::{make_points, make_size};
+/// let sdl_context = sdl2::init().unwrap();
+/// let video_subsystem = sdl_context.video().unwrap();
+/// let mut hidden_window = HiddenWindowCanvas::new(&video_subsystem, 400, 300).unwrap();
+/// let mut engine = Engine::new(400, 300, 60);
+/// let widget = BaseWidget::new(make_points(20, 20), make_size(200, 100));
+///
+/// engine.add_widget(Box::new(widget), String::from("widget1"));
+/// hidden_window.render(&mut engine);
+///
+/// assert_eq!(hidden_window.compare_png("tests/golden/widget1.png", 2).unwrap(), 0);
+/// ```
+impl HiddenWindowCanvas {
+    /// Creates a new `HiddenWindowCanvas` of the given `width` and `height`, using a hidden
+    /// `Window` created by the `video_subsystem`.
+    pub fn new(video_subsystem: &VideoSubsystem, width: u32, height: u32) -> Result<Self, String> {
+        let window = video_subsystem
+            .window("pushrod-render hidden window", width, height)
+            .hidden()
+            .build()
+            .map_err(|e| e.to_string())?;
+        let canvas = window
+            .into_canvas()
+            .software()
+            .target_texture()
+            .build()
+            .map_err(|e| e.to_string())?;
+
+        Ok(Self {
+            canvas,
+            width,
+            height,
+        })
+    }
+
+    /// Retrieves the `Canvas` that is drawn against.
+    pub fn get_canvas(&mut self) -> &mut Canvas<Window> {
+        &mut self.canvas
+    }
+
+    /// Draws a single frame of the `Engine`'s `Widget` tree against this `Canvas`.  Any
+    /// `Layout`s are applied before drawing.
+    pub fn render(&mut self, engine: &mut Engine) {
+        engine.draw_frame(&mut self.canvas);
+    }
+
+    /// Reads the pixels currently drawn on the `Canvas`, in `ARGB8888` format.  Each row of the
+    /// image is `width * 4` bytes long.
+    pub fn read_pixels(&self) -> Result<Vec<u8>, String> {
+        self.canvas.read_pixels(None, PixelFormatEnum::ARGB8888)
+    }
+
+    /// Saves the pixels currently drawn on the `Canvas` as a PNG image to the `file` specified.
+    pub fn save_png(&self, file: &str) -> Result<(), String> {
+        let mut pixels = self.read_pixels()?;
+        let surface = Surface::from_data(
+            &mut pixels,
+            self.width,
+            self.height,
+            self.width * 4,
+            PixelFormatEnum::ARGB8888,
+        )?;
+
+        surface.save(file)
+    }
+
+    /// Compares the pixels currently drawn on the `Canvas` against the PNG image specified by
+    /// `file`.  Pixels match if each of their red, green, blue, and alpha values are within
+    /// `tolerance` of each other.  Returns the number of pixels that do not match, or an error if
+    /// the image could not be loaded, or is not the same size as the `Canvas`.
+    pub fn compare_png(&self, file: &str, tolerance: u8) -> Result<u32, String> {
+        let pixels = self.read_pixels()?;
+        let golden = Surface::from_file(file)?.convert_format(PixelFormatEnum::ARGB8888)?;
+
+        if golden.width() != self.width || golden.height() != self.height {
+            return Err(format!(
+                "Image size mismatch: {} is {}x{}, expected {}x{}",
+                file,
+                golden.width(),
+                golden.height(),
+                self.width,
+                self.height
+            ));
+        }
+
+        let row_width = (self.width * 4) as usize;
+        let golden_pitch = golden.pitch() as usize;
+        let mut mismatched: u32 = 0;
+
+        golden.with_lock(|golden_pixels| {
+            for y in 0..self.height as usize {
+                let row = &pixels[y * row_width..(y + 1) * row_width];
+                let golden_row = &golden_pixels[y * golden_pitch..y * golden_pitch + row_width];
+
+                for (pixel, golden_pixel) in row.chunks(4).zip(golden_row.chunks(4)) {
+                    if pixel
+                        .iter()
+                        .zip(golden_pixel)
+                        .any(|(a, b)| (*a as i32 - *b as i32).abs() > tolerance as i32)
+                    {
+                        mismatched += 1;
+                    }
+                }
+            }
+        });
+
+        Ok(mismatched)
+    }
+}
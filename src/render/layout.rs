@@ -13,8 +13,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::render::layout_cache::LayoutContainer;
 use crate::render::widget_cache::WidgetContainer;
-use crate::render::widget_config::PaddingConstraint;
+use crate::render::widget_config::{PaddingConstraint, WidgetConfig, CONFIG_ORIGIN, CONFIG_SIZE};
+use crate::render::{Points, Size, SIZE_HEIGHT, SIZE_WIDTH};
 
 /// This is a structure that describes the position of a `Widget` within its `Layout`.  `X` and
 /// `Y` coordinates are not given as physical positions on the screen, rather, their position in the
@@ -31,18 +33,294 @@ impl LayoutPosition {
     }
 }
 
-/// This is a `Layout` trait that is used by the `Engine` service, which stores a list of `Widget`s,
-/// their positions (based on matrix coordinates), and an entry point to trigger the layout compute
-/// action.
+/// This describes the edges of the `Window` that a top-level `Layout` is anchored to.  When the
+/// `Window` is resized, the distance between the `Layout` and each anchored edge is kept the same.
+/// A `Layout` that is anchored to both the `left` and `right` edges stretches horizontally, one
+/// anchored only to the `right` edge moves with the right edge, and one anchored only to the `left`
+/// edge (or neither edge) stays in place.  The same applies vertically to `top` and `bottom`.
+#[derive(Clone, Debug, Copy, Default)]
+pub struct Anchor {
+    pub left: bool,
+    pub top: bool,
+    pub right: bool,
+    pub bottom: bool,
+}
+
+/// Implementation of the `Anchor` that generates a new `Anchor` object.
+impl Anchor {
+    pub fn new(left: bool, top: bool, right: bool, bottom: bool) -> Self {
+        Self {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// Computes the new point of origin and size along a single axis, given the origin and size
+    /// at the time the anchor was applied, and the change in the size of the `Window`.
+    fn adjust(start: bool, end: bool, origin: i32, size: u32, delta: i32) -> (i32, u32) {
+        if start && end {
+            (origin, (size as i32 + delta).max(0) as u32)
+        } else if end {
+            (origin + delta, size)
+        } else {
+            (origin, size)
+        }
+    }
+
+    /// Computes the new bounds of a `Layout`, given its bounds and the size of the `Window` at the
+    /// time the anchor was applied, and the new size of the `Window`.
+    pub fn apply(
+        &self,
+        origin: &[i32],
+        size: &[u32],
+        reference_size: &[u32],
+        window_size: &[u32],
+    ) -> (Points, Size) {
+        let (x, w) = Anchor::adjust(
+            self.left,
+            self.right,
+            origin[0],
+            size[SIZE_WIDTH],
+            window_size[SIZE_WIDTH] as i32 - reference_size[SIZE_WIDTH] as i32,
+        );
+        let (y, h) = Anchor::adjust(
+            self.top,
+            self.bottom,
+            origin[1],
+            size[SIZE_HEIGHT],
+            window_size[SIZE_HEIGHT] as i32 - reference_size[SIZE_HEIGHT] as i32,
+        );
+
+        (vec![x, y], vec![w, h])
+    }
+}
+
+/// This describes an item that is managed by a `Layout`: either a `Widget`, or another `Layout`,
+/// referenced by its ID.  `Layout`s that are placed inside of another `Layout` receive their bounds
+/// from the `Layout` that contains them.
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub enum LayoutItem {
+    /// A `Widget`, referenced by its `Widget` ID.
+    Widget(i32),
+
+    /// A `Layout`, referenced by its `Layout` ID.
+    Layout(i32),
+}
+
+/// This is a structure that describes the sizing constraints of a `Widget` along a single axis.  It
+/// is built from the `CONFIG_MIN_SIZE`, `CONFIG_MAX_SIZE`, `CONFIG_PREFERRED_SIZE` and
+/// `CONFIG_STRETCH` settings of a `Widget`, and is used by `distribute_space`.
+#[derive(Clone, Debug, Copy)]
+pub struct SizeHint {
+    pub min: u32,
+    pub max: u32,
+    pub preferred: u32,
+    pub stretch: u32,
+}
+
+/// Implementation of the `SizeHint` that reads the hints from a `WidgetConfig`.
+impl SizeHint {
+    /// Creates a new `SizeHint` for the axis specified by `axis`, which is either `SIZE_WIDTH`
+    /// or `SIZE_HEIGHT`.
+    pub fn from_config(config: &WidgetConfig, axis: usize) -> Self {
+        let min = config.get_min_size()[axis];
+
+        Self {
+            min,
+            max: config.get_max_size()[axis].max(min),
+            preferred: config.get_preferred_size()[axis],
+            stretch: config.get_stretch().max(0) as u32,
+        }
+    }
+}
+
+/// Retrieves the `SizeHint` for a `LayoutItem` along the axis specified by `axis`.  `Widget`
+/// hints are read from the `Widget`'s configuration.  `Layout`s have no size hints, so they share
+/// the remaining space with a stretch factor of `1`.
+pub fn get_item_size_hint(item: LayoutItem, widgets: &[WidgetContainer], axis: usize) -> SizeHint {
+    match item {
+        LayoutItem::Widget(widget_id) => SizeHint::from_config(
+            widgets[widget_id as usize].widget.borrow_mut().get_config(),
+            axis,
+        ),
+        LayoutItem::Layout(_) => SizeHint {
+            min: 0,
+            max: u32::MAX,
+            preferred: 0,
+            stretch: 1,
+        },
+    }
+}
+
+/// Places a `LayoutItem` at the `origin` and `size` specified.  `Widget`s are moved, resized,
+/// and invalidated.  `Layout`s have their bounds set, and are laid out immediately, so that their
+/// own items are placed within the new bounds.
+pub fn place_item(
+    item: LayoutItem,
+    widgets: &[WidgetContainer],
+    layouts: &[LayoutContainer],
+    origin: Points,
+    size: Size,
+) {
+    match item {
+        LayoutItem::Widget(widget_id) => {
+            let mut widget = widgets[widget_id as usize].widget.borrow_mut();
+
+            widget
+                .get_config()
+                .set_point(CONFIG_ORIGIN, origin[0], origin[1]);
+            widget
+                .get_config()
+                .set_size(CONFIG_SIZE, size[SIZE_WIDTH], size[SIZE_HEIGHT]);
+            widget.get_config().set_invalidated(true);
+        }
+        LayoutItem::Layout(layout_id) => {
+            let mut layout = layouts[layout_id as usize].layout.borrow_mut();
+
+            layout.set_bounds(origin, size);
+            layout.do_layout(widgets, layouts);
+        }
+    }
+}
+
+/// Shows or hides a `LayoutItem`.  A `Widget` is shown or hidden along with its children.  For a
+/// `Layout`, every item that it contains is shown or hidden, including the items of any `Layout`s
+/// inside of it.
+pub fn set_item_visible(
+    item: LayoutItem,
+    widgets: &[WidgetContainer],
+    layouts: &[LayoutContainer],
+    visible: bool,
+) {
+    match item {
+        LayoutItem::Widget(widget_id) => {
+            let mut widget = widgets[widget_id as usize].widget.borrow_mut();
+
+            if visible {
+                widget.get_config().show();
+            } else {
+                widget.get_config().hide();
+            }
+        }
+        LayoutItem::Layout(layout_id) => {
+            let items = layouts[layout_id as usize].layout.borrow().get_items();
+
+            for item in items {
+                set_item_visible(item, widgets, layouts, visible);
+            }
+        }
+    }
+}
+
+/// Divides the `available` space between a number of items, based on their `SizeHint`s.  Each
+/// item starts at its preferred size (clamped to its minimum and maximum size), and any remaining
+/// space is handed out in proportion to the stretch factor of each item, never exceeding its
+/// maximum size.  If there is not enough space for the preferred sizes, items are shrunk towards
+/// their minimum size.  Returns the size of each item.
+pub fn distribute_space(available: u32, hints: &[SizeHint]) -> Vec<u32> {
+    let mut sizes: Vec<u32> = hints
+        .iter()
+        .map(|hint| hint.preferred.max(hint.min).min(hint.max))
+        .collect();
+    let total: u32 = sizes.iter().sum();
+
+    if total > available {
+        let excess = (total - available) as u64;
+        let shrinkable: u64 = sizes
+            .iter()
+            .zip(hints)
+            .map(|(size, hint)| (size - hint.min.min(*size)) as u64)
+            .sum();
+
+        if shrinkable > 0 {
+            for (size, hint) in sizes.iter_mut().zip(hints) {
+                let room = (*size - hint.min.min(*size)) as u64;
+
+                *size -= (room * excess.min(shrinkable)).div_ceil(shrinkable) as u32;
+            }
+        }
+
+        return sizes;
+    }
+
+    let mut remaining = available - total;
+    let mut expanding: Vec<usize> = (0..hints.len())
+        .filter(|i| hints[*i].stretch > 0 && sizes[*i] < hints[*i].max)
+        .collect();
+
+    while remaining > 0 && !expanding.is_empty() {
+        let total_stretch: u64 = expanding.iter().map(|i| hints[*i].stretch as u64).sum();
+        let mut given: u32 = 0;
+        let mut still_expanding = Vec::new();
+
+        for (position, i) in expanding.iter().enumerate() {
+            // The last item receives any leftover pixels from rounding.
+            let share = if position == expanding.len() - 1 {
+                remaining - given
+            } else {
+                (remaining as u64 * hints[*i].stretch as u64 / total_stretch) as u32
+            };
+            let room = hints[*i].max - sizes[*i];
+
+            if share >= room {
+                sizes[*i] = hints[*i].max;
+                given += room;
+            } else {
+                sizes[*i] += share;
+                given += share;
+                still_expanding.push(*i);
+            }
+        }
+
+        remaining -= given;
+        expanding = still_expanding;
+    }
+
+    sizes
+}
+
+/// This is a `Layout` trait that is used by the `Engine` service, which stores a list of `Widget`s
+/// and `Layout`s, their positions (based on matrix coordinates), and an entry point to trigger the
+/// layout compute action.
 pub trait Layout {
+    /// Adds a `LayoutItem` to the `Layout` manager, given its `LayoutPosition`, as a position
+    /// marker in the manager.
+    fn insert_item(&mut self, _item: LayoutItem, _item_position: LayoutPosition);
+
+    /// Adds a `LayoutItem` to the `Layout` manager, automatically adding it to the next available
+    /// `LayoutPosition`.
+    fn append_item(&mut self, _item: LayoutItem);
+
+    /// Returns the `LayoutItem`s managed by this `Layout`, in the order in which they were added.
+    fn get_items(&self) -> Vec<LayoutItem>;
+
     /// Adds a `Widget` by ID to the `Layout` manager, given its `LayoutPosition`, as a position
     /// marker in the manager.
-    fn insert_widget(&mut self, _widget_id: i32, _widget_position: LayoutPosition);
+    fn insert_widget(&mut self, _widget_id: i32, _widget_position: LayoutPosition) {
+        self.insert_item(LayoutItem::Widget(_widget_id), _widget_position);
+    }
 
     /// Adds a `Widget` by ID to the `Layout` manager, automatically adding it to the next available
     /// `LayoutPosition`.  Use this as a way to add a widget if you don't need to specify a
     /// `LayoutPosition`.
-    fn append_widget(&mut self, _widget_id: i32);
+    fn append_widget(&mut self, _widget_id: i32) {
+        self.append_item(LayoutItem::Widget(_widget_id));
+    }
+
+    /// Adds a `Layout` by ID to this `Layout` manager, given its `LayoutPosition`.  The bounds of
+    /// the added `Layout` are controlled by this `Layout`, and it is laid out whenever this
+    /// `Layout` is laid out.
+    fn insert_layout(&mut self, _layout_id: i32, _layout_position: LayoutPosition) {
+        self.insert_item(LayoutItem::Layout(_layout_id), _layout_position);
+    }
+
+    /// Adds a `Layout` by ID to this `Layout` manager, automatically adding it to the next
+    /// available `LayoutPosition`.
+    fn append_layout(&mut self, _layout_id: i32) {
+        self.append_item(LayoutItem::Layout(_layout_id));
+    }
 
     /// Changes the `PaddingConstraint` for this `Layout`.
     fn set_padding(&mut self, padding: PaddingConstraint);
@@ -50,11 +328,81 @@ pub trait Layout {
     /// Retrieves the current `PaddingConstraint`.
     fn get_padding(&self) -> PaddingConstraint;
 
+    /// Changes the point of origin and size of the area managed by this `Layout`.
+    fn set_bounds(&mut self, origin: Points, size: Size);
+
+    /// Retrieves the point of origin and size of the area managed by this `Layout`.
+    fn get_bounds(&self) -> (Points, Size);
+
     /// Performs a layout, applying the `WidgetContainer` list at the time, so that referenced
-    /// `Widget`s can be adjusted as necessary.
-    fn do_layout(&mut self, _widgets: &[WidgetContainer]);
+    /// `Widget`s can be adjusted as necessary.  The `LayoutContainer` list is used to lay out any
+    /// `Layout`s that are contained in this `Layout`.
+    fn do_layout(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]);
 
     /// Indicates whether or not the `Layout` needs to have `do_layout` re-run.  This is generally
-    /// needed when the `LayoutPosition` changes, or when `PaddingConstraint`s change.
+    /// needed when the `LayoutPosition` changes, when `PaddingConstraint`s change, or when the
+    /// bounds of the `Layout` change.
     fn needs_layout(&self) -> bool;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hint(min: u32, max: u32, preferred: u32, stretch: u32) -> SizeHint {
+        SizeHint {
+            min,
+            max,
+            preferred,
+            stretch,
+        }
+    }
+
+    #[test]
+    fn test_distribute_space_by_stretch() {
+        let hints = [hint(0, u32::MAX, 0, 1), hint(0, u32::MAX, 0, 3)];
+
+        assert_eq!(distribute_space(100, &hints), vec![25, 75]);
+    }
+
+    #[test]
+    fn test_distribute_space_gives_rounding_to_last_item() {
+        let hints = [hint(0, u32::MAX, 0, 1); 3];
+
+        assert_eq!(distribute_space(100, &hints), vec![33, 33, 34]);
+    }
+
+    #[test]
+    fn test_distribute_space_respects_maximum() {
+        let hints = [hint(0, 10, 0, 1), hint(0, u32::MAX, 0, 1)];
+
+        assert_eq!(distribute_space(100, &hints), vec![10, 90]);
+    }
+
+    #[test]
+    fn test_distribute_space_keeps_preferred_without_stretch() {
+        let hints = [hint(0, u32::MAX, 30, 0), hint(0, u32::MAX, 20, 1)];
+
+        assert_eq!(distribute_space(100, &hints), vec![30, 70]);
+        assert_eq!(distribute_space(100, &hints[..1]), vec![30]);
+    }
+
+    #[test]
+    fn test_distribute_space_shrinks_towards_minimum() {
+        let hints = [hint(20, u32::MAX, 40, 1), hint(0, u32::MAX, 40, 1)];
+
+        assert_eq!(distribute_space(50, &hints), vec![30, 20]);
+    }
+
+    #[test]
+    fn test_distribute_space_never_shrinks_below_minimum() {
+        let hints = [hint(20, u32::MAX, 0, 1), hint(30, u32::MAX, 0, 1)];
+
+        assert_eq!(distribute_space(10, &hints), vec![20, 30]);
+    }
+
+    #[test]
+    fn test_distribute_space_with_no_items() {
+        assert!(distribute_space(100, &[]).is_empty());
+    }
+}
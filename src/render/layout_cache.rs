@@ -13,14 +13,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::render::layout::Layout;
+use crate::render::layout::{Anchor, Layout};
 use crate::render::widget_cache::WidgetContainer;
+use crate::render::{Points, Size};
 use std::cell::RefCell;
 
-/// This is a container object that stores a `Layout` object, and its ID.
+/// This is a container object that stores a `Layout` object, and its ID.  If the `Layout` is
+/// anchored to the edges of the `Window`, it also stores the `Anchor`, along with the bounds of
+/// the `Layout` and the size of the `Window` at the time the `Anchor` was set.
 pub struct LayoutContainer {
     pub layout: RefCell<Box<dyn Layout>>,
     layout_id: i32,
+    anchor: Option<(Anchor, Points, Size, Size)>,
 }
 
 /// This is an implementation that allows for creation of a `LayoutContainer`.
@@ -30,6 +34,7 @@ impl LayoutContainer {
         Self {
             layout: RefCell::new(layout),
             layout_id,
+            anchor: None,
         }
     }
 
@@ -64,19 +69,43 @@ impl LayoutCache {
         &mut self.cache[id as usize]
     }
 
+    /// Anchors the `Layout` specified by `layout_id` to the edges of the `Window`.  The current
+    /// bounds of the `Layout` and the `window_size` are used as the reference point when the
+    /// `Window` is resized.
+    pub fn set_anchor(&mut self, layout_id: i32, anchor: Anchor, window_size: Size) {
+        let container = &mut self.cache[layout_id as usize];
+        let (origin, size) = container.layout.borrow().get_bounds();
+
+        container.anchor = Some((anchor, origin, size, window_size));
+    }
+
+    /// Applies the new `window_size` to all anchored `Layout`s, changing their bounds.  Their
+    /// `needs_layout` flag is set, so that they are laid out in the next call to `do_layout`.
+    pub fn resize(&mut self, window_size: Size) {
+        for x in &self.cache {
+            if let Some((anchor, origin, size, reference_size)) = &x.anchor {
+                let (new_origin, new_size) =
+                    anchor.apply(origin, size, reference_size, &window_size);
+
+                x.layout.borrow_mut().set_bounds(new_origin, new_size);
+            }
+        }
+    }
+
     /// Retrieves a borrowed slice of the `LayoutContainer` cache that can be sent to callbacks.
     pub fn get_layout_cache(&self) -> &[LayoutContainer] {
         &self.cache
     }
 
     /// Performs the `do_layout` call on `Layout` objects only if their `needs_layout` flag is set
-    /// to `true`.
+    /// to `true`.  `Layout`s that are contained in other `Layout`s are laid out again whenever
+    /// the `Layout` containing them is laid out.
     pub fn do_layout(&self, widgets: &[WidgetContainer]) {
         for x in &self.cache {
             let needs_layout = x.layout.borrow().needs_layout();
 
             if needs_layout {
-                x.layout.borrow_mut().do_layout(widgets);
+                x.layout.borrow_mut().do_layout(widgets, &self.cache);
             }
         }
     }
@@ -88,3 +88,19 @@ pub mod layout_cache;
 
 /// This is a caching object that stores Textures for fonts and images.
 pub mod texture_cache;
+
+/// This is a `Canvas` on a hidden `Window` using the software renderer, which allows the `Widget`
+/// tree to be drawn off screen, and compared against golden PNG images.
+pub mod hidden_window;
+
+/// This is an event recorder and player, which records the events received by the `Engine` to a
+/// file, and replays them against an `Engine`.
+pub mod event_recorder;
+
+/// This is the clock used by the `Engine` and `Widget`s, which can be driven manually so that
+/// events can be replayed at the time they were recorded.
+pub mod clock;
+
+/// This is the state of the modifier keys, which is tracked by the `Engine`, so that `Widget`s can
+/// check it when they are clicked.
+pub mod modifiers;
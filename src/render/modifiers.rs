@@ -0,0 +1,35 @@
+// Pushrod Rendering Library
+// Modifier Key State
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sdl2::keyboard::Mod;
+use std::cell::Cell;
+
+thread_local! {
+    static MOD_STATE: Cell<Mod> = const { Cell::new(Mod::NOMOD) };
+}
+
+/// Returns the modifier keys that are currently held down, such as `Shift` and `Ctrl`.  Keyboard
+/// events are only sent to the focused `Widget`, so `Widget`s use this to check the modifier keys
+/// when they are clicked, such as to extend a selection.
+pub fn mod_state() -> Mod {
+    MOD_STATE.with(|x| x.get())
+}
+
+/// Sets the modifier keys returned by `mod_state` on the current thread.  This is called by the
+/// `Engine` for every key event it dispatches, whether it was received from the event pump, or
+/// replayed by an `EventPlayer`.
+pub fn set_mod_state(keymod: Mod) {
+    MOD_STATE.with(|x| x.set(keymod));
+}
@@ -90,6 +90,76 @@ impl TextureCache {
 
         (font_texture, width, height)
     }
+
+    /// Renders a single line of text, given the font name, size, style, color and string.  Unlike
+    /// `render_text`, the text is never wrapped, so the `Texture` is exactly as wide as the text,
+    /// and matches the offsets returned by `measure_text`.  Transfers ownership of the `Texture` to
+    /// the calling function, returns the width and height of the texture after rendering.
+    pub fn render_text_line(
+        &mut self,
+        c: &mut Canvas<Window>,
+        font_name: String,
+        font_size: u16,
+        font_style: FontStyle,
+        font_string: String,
+        font_color: Color,
+    ) -> (Texture, u32, u32) {
+        let ttf_context = self.get_ttf_context();
+        let texture_creator = c.texture_creator();
+        let mut font = ttf_context
+            .load_font(Path::new(&font_name), font_size)
+            .unwrap();
+
+        font.set_style(font_style);
+
+        let surface = font
+            .render(&font_string)
+            .blended(font_color)
+            .map_err(|e| e.to_string())
+            .unwrap();
+        let font_texture = texture_creator
+            .create_texture_from_surface(&surface)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        let TextureQuery { width, height, .. } = font_texture.query();
+
+        (font_texture, width, height)
+    }
+
+    /// Measures a single line of text, given the font name, size, style, and string.  Returns a `Vec`
+    /// containing the X offset of each character boundary in the string (starting with `0` for the
+    /// position before the first character, ending with the full width of the string), along with
+    /// the height of a line of text in the font.  This is used by `Widget`s that need to position
+    /// a cursor, or map a mouse position to a character in the text.  Each offset is the width of
+    /// the text up to that boundary, so kerning between characters is included, and the offsets
+    /// match the text drawn by `render_text_line`.
+    pub fn measure_text(
+        &mut self,
+        font_name: String,
+        font_size: u16,
+        font_style: FontStyle,
+        font_string: String,
+    ) -> (Vec<u32>, u32) {
+        let ttf_context = self.get_ttf_context();
+        let mut font = ttf_context
+            .load_font(Path::new(&font_name), font_size)
+            .unwrap();
+        let mut offsets = vec![0];
+
+        font.set_style(font_style);
+
+        for (index, character) in font_string.char_indices() {
+            let (width, _) = font
+                .size_of(&font_string[..index + character.len_utf8()])
+                .map_err(|e| e.to_string())
+                .unwrap();
+
+            offsets.push(width);
+        }
+
+        (offsets, font.height() as u32)
+    }
 }
 
 impl Default for TextureCache {
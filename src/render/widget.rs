@@ -25,6 +25,7 @@ use crate::render::widget_cache::WidgetContainer;
 use crate::render::widget_config::*;
 use crate::render::{Points, Size};
 use sdl2::event::Event;
+use sdl2::keyboard::{Keycode, Mod};
 use sdl2::pixels::Color;
 use std::any::Any;
 use std::collections::HashMap;
@@ -135,6 +136,57 @@ pub trait Widget {
         self.tick_callback(_widgets, _layouts);
     }
 
+    /// When a key is pressed while this `Widget` has focus, this function is called.  It contains
+    /// the `Keycode` of the key that was pressed, and the `Mod` modifier keys that were held at the
+    /// time.  Key repeats are sent as additional key presses.  This function implementation is
+    /// **optional**.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// When a key is released while this `Widget` has focus, this function is called.  It contains
+    /// the `Keycode` of the key that was released, and the `Mod` modifier keys that were held at the
+    /// time.  This function implementation is **optional**.
+    fn key_released(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        self.key_released_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// When text is entered while this `Widget` has focus, this function is called.  The text is
+    /// already translated by SDL2 according to the keyboard layout, so it should be used for text
+    /// entry rather than `key_pressed`.  This function implementation is **optional**.
+    fn text_input(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _text: String,
+    ) {
+        self.text_input_callback(_widgets, _layouts, _text);
+    }
+
+    /// When this `Widget` gains or loses keyboard focus, this function is called.  `focused` is
+    /// set to `true` when focus is gained, `false` when it is lost.  Only `Widget`s that have the
+    /// `CONFIG_FOCUSABLE` toggle set will receive focus.  This function implementation is
+    /// **optional**.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+    }
+
     /// When an `Event` is sent to the application that is not handled by the `Engine::run` loop, this
     /// method is called, sending the unhandled `Event` to the currently active `Widget`.  **This behavior
     /// is subject to change** as the `Engine::run` loop is modified to handle more `Event`s.
@@ -207,6 +259,41 @@ pub trait Widget {
     ) {
     }
 
+    /// This calls the `on_key_pressed` callback.  This is implemented by the `default_widget_callbacks!` macro,
+    /// so you do not need to implement it.  However, you need to call this function if you wish
+    /// to honor an `on_key_pressed` callback.
+    fn key_pressed_callback(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+    }
+
+    /// This calls the `on_key_released` callback.  This is implemented by the `default_widget_callbacks!` macro,
+    /// so you do not need to implement it.  However, you need to call this function if you wish
+    /// to honor an `on_key_released` callback.
+    fn key_released_callback(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+    }
+
+    /// This calls the `on_text_input` callback.  This is implemented by the `default_widget_callbacks!` macro,
+    /// so you do not need to implement it.  However, you need to call this function if you wish
+    /// to honor an `on_text_input` callback.
+    fn text_input_callback(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _text: String,
+    ) {
+    }
+
     /// This callback is called when a setter is used to configure a value.  It is _not_ called when a
     /// call to `get_config()` using the setter is called, so it is best to use the top-level setters
     /// and getters for the configuration values - at least, until the `get_config()` call can be made
@@ -319,6 +406,68 @@ pub trait Widget {
         )
     }
 
+    /// Returns the area inside of this `Widget` that its children are drawn in.  Children are
+    /// clipped to this area, and can only receive mouse events inside of it.  Defaults to the
+    /// drawing area of the `Widget`.
+    fn get_client_area(&mut self) -> Rect {
+        self.get_drawing_area()
+    }
+
+    /// Returns the offset by which the children of this `Widget` are scrolled, as X and Y points.
+    /// Children are positioned relative to the origin of this `Widget`, minus this offset.  Defaults
+    /// to `0, 0`.
+    fn get_content_offset(&mut self) -> Points {
+        vec![0, 0]
+    }
+
+    /// Returns the area of the `Window` covered by this `Widget`'s overlay, if it has one open.  An
+    /// overlay is a floating area, such as a popup list, that is drawn above all other `Widget`s
+    /// and is not clipped to the bounds of this `Widget` or its parents.  Mouse events inside of
+    /// the overlay are sent to this `Widget`.  Defaults to `None`.
+    fn get_overlay_area(&mut self) -> Option<Rect> {
+        None
+    }
+
+    /// Draws the overlay of this `Widget`, returning a `Texture` that is copied over the area
+    /// returned by `get_overlay_area`.  This is called during every frame while the overlay is
+    /// open, after all of the `Widget`s have been drawn.  Defaults to `None`.
+    fn draw_overlay(&mut self, _c: &mut Canvas<Window>, _t: &mut TextureCache) -> Option<&Texture> {
+        None
+    }
+
+    /// Closes the overlay of this `Widget`.  This is called when a mouse button is pressed outside
+    /// of this `Widget` and its overlay while the overlay is open.
+    fn dismiss_overlay(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {}
+
+    /// Opens this `Widget` as a context menu at the point in the `Window` specified by `_points`,
+    /// for the `Widget` specified by `_widget_id`.  This is called by the `Engine` when the right
+    /// mouse button is pressed over a `Widget` that has this `Widget` set as its
+    /// `CONFIG_CONTEXT_MENU`.  Defaults to doing nothing.
+    fn open_context_menu(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _widget_id: i32,
+        _points: Points,
+    ) {
+    }
+
+    /// When a mouse button is pressed or released over one of this `Widget`'s children, or over one
+    /// of their children, this function is called after the child has handled the click.  It
+    /// contains the ID of the `Widget` that was clicked, the button, the number of clicks, and the
+    /// click state.  This allows a `Widget` to react to clicks on the `Widget`s that it contains.
+    /// Defaults to doing nothing.
+    fn child_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _widget_id: i32,
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+    }
+
     /// Returns whether or not a `Widget` is invalidated state.
     fn is_invalidated(&mut self) -> bool {
         self.get_config().invalidated()
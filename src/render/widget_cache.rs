@@ -16,24 +16,46 @@
 use std::cell::RefCell;
 
 use crate::render::layout_cache::LayoutContainer;
+use crate::render::make_points;
 use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
 use crate::render::widget::Widget;
-use crate::render::widget_config::{CONFIG_ORIGIN, CONFIG_SIZE};
+use crate::render::widget_config::{
+    CONFIG_CONTEXT_MENU, CONFIG_FOCUSABLE, CONFIG_MODAL, CONFIG_MOUSE_CAPTURE, CONFIG_ORIGIN,
+    CONFIG_SCROLLABLE, CONFIG_SIZE, CONFIG_TOOLTIP,
+};
 use sdl2::event::Event;
+use sdl2::keyboard::{Keycode, Mod};
 use sdl2::pixels::Color;
 use sdl2::rect::Rect;
-use sdl2::render::Canvas;
+use sdl2::render::{BlendMode, Canvas, Texture};
+use sdl2::ttf::FontStyle;
 use sdl2::video::Window;
 
+/// This is the font used to draw tooltips.
+const TOOLTIP_FONT_NAME: &str = "assets/OpenSans-Regular.ttf";
+
+/// This is the size of the font used to draw tooltips.
+const TOOLTIP_FONT_SIZE: u16 = 14;
+
+/// This is the width at which the text of a tooltip is wrapped, in pixels.
+const TOOLTIP_MAX_WIDTH: u32 = 300;
+
+/// This is the space between the text of a tooltip and the edge of its bubble, in pixels.
+const TOOLTIP_PADDING: u32 = 4;
+
 /// This is a container that stores information about a `Widget` that will be drawn on the screen.
-/// It stores the `Widget` object, the actual point of origin inside the `Window` (as a `Vec<i32>`
-/// of X and Y points), the parent ID of this `Widget`, if it is being added as a child.
+/// It stores the `Widget` object, the point of origin relative to its parent (as a `Vec<i32>`
+/// of X and Y points), the parent ID of this `Widget`, if it is being added as a child.  For
+/// top-level `Widget`s, the point of origin is the physical location inside the `Window`.
 pub struct WidgetContainer {
     pub widget: RefCell<Box<dyn Widget>>,
     widget_name: String,
     pub origin: Vec<i32>,
     widget_id: i32,
     parent_id: i32,
+    parent_origin: Vec<i32>,
+    last_drawn: Option<Rect>,
 }
 
 /// This is the `WidgetContainer` object that stores a `Widget` and its accompanying information:
@@ -53,6 +75,8 @@ impl WidgetContainer {
             origin,
             widget_id,
             parent_id,
+            parent_origin: vec![0, 0],
+            last_drawn: None,
         }
     }
 
@@ -81,6 +105,9 @@ impl WidgetContainer {
 pub struct WidgetCache {
     cache: Vec<WidgetContainer>,
     texture_cache: TextureCache,
+    back_buffer: TextureStore,
+    back_buffer_size: Vec<u32>,
+    tooltip: Option<(String, Texture, u32, u32)>,
 }
 
 /// This is the `WidgetCache` implementation.  This cache object manages the `Widget` list for use by the
@@ -90,6 +117,9 @@ impl WidgetCache {
         Self {
             cache: Vec::new(),
             texture_cache: TextureCache::new(),
+            back_buffer: TextureStore::default(),
+            back_buffer_size: vec![0, 0],
+            tooltip: None,
         }
     }
 
@@ -113,44 +143,141 @@ impl WidgetCache {
         (self.cache.len() - 1) as i32
     }
 
+    /// This adds a `Widget` to the render list as a child of the `Widget` specified by `parent_id`.
+    /// The point of origin of the `Widget` is relative to the point of origin of its parent, and is
+    /// converted to a physical location inside the `Window` when added.  Whenever the parent is
+    /// moved, its children are moved along with it.  Children are clipped to the bounds of their
+    /// parent when drawn, and are hidden or disabled whenever their parent is hidden or disabled.
+    /// The parent ID must already exist, otherwise, this function will panic.  Returns the ID of
+    /// the `Widget` that was added.
+    pub fn add_widget_to_parent(
+        &mut self,
+        parent_id: i32,
+        mut widget: Box<dyn Widget>,
+        widget_name: String,
+    ) -> i32 {
+        if parent_id < 0 || parent_id as usize >= self.cache.len() {
+            panic!(
+                "Parent ID {} does not exist in the Widget cache.",
+                parent_id
+            );
+        }
+
+        let origin = widget.get_config().get_point(CONFIG_ORIGIN);
+        let parent_origin = self.get_child_origin(parent_id);
+        let widget_id = self.cache.len() as i32;
+
+        widget.set_origin(vec![
+            origin[0] + parent_origin[0],
+            origin[1] + parent_origin[1],
+        ]);
+
+        let mut container = WidgetContainer::new(widget, widget_name, origin, widget_id, parent_id);
+
+        container.parent_origin = parent_origin;
+        self.cache.push(container);
+
+        widget_id
+    }
+
     /// This locates the ID of a `Widget` at a given `x` and `y` coordinate.  If a `Widget` could not
     /// be found, the top-level `Widget` (id 0) is returned.  This function returns the top-most
-    /// visible `Widget` id.
+    /// visible `Widget` id.  Children are only searched if the point is within the bounds of their
+    /// parent, and are considered to be on top of their parent.  Open overlays are on top of all
+    /// `Widget`s, so if the point is inside of an overlay, the `Widget` that owns it is returned.
+    /// While a modal `Widget` is shown, only it and its children can be found: `0` is returned for
+    /// any point outside of it.
     pub fn find_widget(&mut self, x: i32, y: i32) -> i32 {
-        let mut found_widget_id: i32 = 0;
+        let modal_id = self.get_modal_id();
 
-        for i in 0..self.cache.len() {
-            if !self.is_hidden(i as i32) {
-                let start_x: i32 = self.cache[i]
-                    .widget
-                    .borrow_mut()
-                    .get_config()
-                    .get_point(CONFIG_ORIGIN)[0];
-                let start_y: i32 = self.cache[i]
+        for i in (1..self.cache.len()).rev() {
+            if self.is_hidden(i as i32)
+                || (modal_id != 0 && !self.is_descendant(i as i32, modal_id))
+            {
+                continue;
+            }
+
+            let overlay_area = self.cache[i].widget.borrow_mut().get_overlay_area();
+
+            if overlay_area.is_some_and(|area| area.contains_point((x, y))) {
+                return i as i32;
+            }
+        }
+
+        if modal_id == 0 {
+            self.find_widget_in(0, x, y)
+        } else if self.cache[modal_id as usize]
+            .widget
+            .borrow_mut()
+            .get_drawing_area()
+            .contains_point((x, y))
+        {
+            self.find_widget_in(modal_id, x, y)
+        } else {
+            0
+        }
+    }
+
+    /// Returns the ID of the modal `Widget` that is currently shown: the last visible `Widget`
+    /// added with its `CONFIG_MODAL` toggle set.  Returns `0` if no modal `Widget` is shown.
+    pub fn get_modal_id(&self) -> i32 {
+        for i in (1..self.cache.len()).rev() {
+            if !self.is_hidden(i as i32)
+                && self.cache[i]
                     .widget
                     .borrow_mut()
                     .get_config()
-                    .get_point(CONFIG_ORIGIN)[1];
-                let end_x: i32 = start_x
-                    + (self.cache[i]
-                        .widget
-                        .borrow_mut()
-                        .get_config()
-                        .get_size(CONFIG_SIZE)[0] as i32);
-                let end_y: i32 = start_y
-                    + (self.cache[i]
-                        .widget
-                        .borrow_mut()
-                        .get_config()
-                        .get_size(CONFIG_SIZE)[1] as i32);
-
-                if x >= start_x && x <= end_x && y >= start_y && y <= end_y {
-                    found_widget_id = i as i32;
-                }
+                    .get_toggle(CONFIG_MODAL)
+            {
+                return i as i32;
             }
         }
 
-        found_widget_id
+        0
+    }
+
+    /// Indicates whether or not the `Widget` specified by `widget_id` can receive input.  While a
+    /// modal `Widget` is shown, only it and its children can receive input; otherwise, all
+    /// `Widget`s can.
+    pub fn is_input_allowed(&self, widget_id: i32) -> bool {
+        let modal_id = self.get_modal_id();
+
+        modal_id == 0 || self.is_descendant(widget_id, modal_id)
+    }
+
+    /// Indicates whether or not the `Widget` specified by `widget_id` captures the mouse while a
+    /// button is held down over it.  See `CONFIG_MOUSE_CAPTURE`.
+    pub fn captures_mouse(&self, widget_id: i32) -> bool {
+        self.cache[widget_id as usize]
+            .widget
+            .borrow_mut()
+            .get_config()
+            .get_toggle(CONFIG_MOUSE_CAPTURE)
+    }
+
+    /// This moves child `Widget`s along with their parents.  If the point of origin of a parent has
+    /// changed since the last time this function was called, its children (and their children) are
+    /// moved by the same amount.  The same applies when the content offset of a parent changes,
+    /// such as when it is scrolled.  This is called by the `Engine` before each draw loop, after
+    /// the `Layout`s have been applied.
+    pub fn update_children(&mut self) {
+        for i in 1..self.cache.len() {
+            let parent_origin = self.get_child_origin(self.cache[i].parent_id);
+            let last_origin = self.cache[i].parent_origin.clone();
+
+            if parent_origin[0] != last_origin[0] || parent_origin[1] != last_origin[1] {
+                let mut widget = self.cache[i].widget.borrow_mut();
+                let origin = widget.get_config().get_point(CONFIG_ORIGIN);
+
+                widget.set_origin(vec![
+                    origin[0] + parent_origin[0] - last_origin[0],
+                    origin[1] + parent_origin[1] - last_origin[1],
+                ]);
+                drop(widget);
+
+                self.cache[i].parent_origin = parent_origin;
+            }
+        }
     }
 
     /// Returns a `WidgetContainer` object by its ID.  This is the same `Widget` ID that is returned
@@ -179,8 +306,8 @@ impl WidgetCache {
     /// This function calls the `button_clicked` callback for the `Widget` specified by `widget_id`.
     /// When state is set to `true`, this indicates that a mouse button down was detected.  When set
     /// to `false`, it indicates that the mouse button was released.  When setting the button state
-    /// to `widget_id == -1`, the button click message will be sent to _all_ `Widget`s, so use
-    /// `widget_id == -1` with care.
+    /// to `widget_id == -1`, the button click message will be sent to _all_ `Widget`s that can
+    /// receive input, so use `widget_id == -1` with care.
     pub fn button_clicked(
         &mut self,
         widget_id: i32,
@@ -190,8 +317,13 @@ impl WidgetCache {
         cache: &[LayoutContainer],
     ) {
         if widget_id == -1 {
+            let modal_id = self.get_modal_id();
+
             for i in 0..self.cache.len() {
-                if !self.is_hidden(i as i32) && self.is_enabled(i as i32) {
+                if !self.is_hidden(i as i32)
+                    && self.is_enabled(i as i32)
+                    && (modal_id == 0 || self.is_descendant(i as i32, modal_id))
+                {
                     self.cache[i as usize].widget.borrow_mut().button_clicked(
                         &self.cache,
                         cache,
@@ -209,6 +341,33 @@ impl WidgetCache {
         }
     }
 
+    /// This function calls the `child_clicked` function of each of the parents of the `Widget`
+    /// specified by `widget_id`, from its closest parent up to the top-level `Widget`.  This is
+    /// called by the `Engine` after a mouse button is pressed or released over the `Widget`.
+    pub fn child_clicked(
+        &mut self,
+        widget_id: i32,
+        button: u8,
+        clicks: u8,
+        state: bool,
+        cache: &[LayoutContainer],
+    ) {
+        if self.is_hidden(widget_id) || !self.is_enabled(widget_id) {
+            return;
+        }
+
+        let mut current_id = widget_id;
+
+        while self.cache[current_id as usize].parent_id != current_id {
+            current_id = self.cache[current_id as usize].parent_id;
+
+            self.cache[current_id as usize]
+                .widget
+                .borrow_mut()
+                .child_clicked(&self.cache, cache, widget_id, button, clicks, state);
+        }
+    }
+
     /// This function calls the `mouse_moved` callback for the `Widget` specified by `widget_id`.
     pub fn mouse_moved(&mut self, widget_id: i32, points: Vec<i32>, cache: &[LayoutContainer]) {
         if !self.is_hidden(widget_id) && self.is_enabled(widget_id) {
@@ -220,7 +379,30 @@ impl WidgetCache {
     }
 
     /// This function calls the `mouse_scrolled` callback for the `Widget` specified by `widget_id`.
+    /// If that `Widget` is not scrollable (see `CONFIG_SCROLLABLE`), the event is sent to its
+    /// closest scrollable parent instead, if there is one.
     pub fn mouse_scrolled(&mut self, widget_id: i32, points: Vec<i32>, cache: &[LayoutContainer]) {
+        let mut scroll_id = widget_id;
+
+        while !self.cache[scroll_id as usize]
+            .widget
+            .borrow_mut()
+            .get_toggle(CONFIG_SCROLLABLE)
+            && self.cache[scroll_id as usize].parent_id != scroll_id
+        {
+            scroll_id = self.cache[scroll_id as usize].parent_id;
+        }
+
+        let widget_id = if self.cache[scroll_id as usize]
+            .widget
+            .borrow_mut()
+            .get_toggle(CONFIG_SCROLLABLE)
+        {
+            scroll_id
+        } else {
+            widget_id
+        };
+
         if !self.is_hidden(widget_id) && self.is_enabled(widget_id) {
             self.cache[widget_id as usize]
                 .widget
@@ -262,6 +444,97 @@ impl WidgetCache {
         }
     }
 
+    /// This function calls the `key_pressed` callback for the `Widget` specified by `widget_id`.
+    pub fn key_pressed(
+        &mut self,
+        widget_id: i32,
+        keycode: Keycode,
+        keymod: Mod,
+        cache: &[LayoutContainer],
+    ) {
+        if !self.is_hidden(widget_id) && self.is_enabled(widget_id) {
+            self.cache[widget_id as usize]
+                .widget
+                .borrow_mut()
+                .key_pressed(&self.cache, cache, keycode, keymod);
+        }
+    }
+
+    /// This function calls the `key_released` callback for the `Widget` specified by `widget_id`.
+    pub fn key_released(
+        &mut self,
+        widget_id: i32,
+        keycode: Keycode,
+        keymod: Mod,
+        cache: &[LayoutContainer],
+    ) {
+        if !self.is_hidden(widget_id) && self.is_enabled(widget_id) {
+            self.cache[widget_id as usize]
+                .widget
+                .borrow_mut()
+                .key_released(&self.cache, cache, keycode, keymod);
+        }
+    }
+
+    /// This function calls the `text_input` callback for the `Widget` specified by `widget_id`.
+    pub fn text_input(&mut self, widget_id: i32, text: String, cache: &[LayoutContainer]) {
+        if !self.is_hidden(widget_id) && self.is_enabled(widget_id) {
+            self.cache[widget_id as usize]
+                .widget
+                .borrow_mut()
+                .text_input(&self.cache, cache, text);
+        }
+    }
+
+    /// This function sets or clears the focus state of the `Widget` specified by `widget_id`, and
+    /// calls its `focus_changed` callback.  Focus is tracked by the `Engine`, so this function does
+    /// not remove focus from any other `Widget`.
+    pub fn set_focused(&mut self, widget_id: i32, focused: bool, cache: &[LayoutContainer]) {
+        let mut widget = self.cache[widget_id as usize].widget.borrow_mut();
+
+        widget.get_config().set_focused(focused);
+        widget.focus_changed(&self.cache, cache, focused);
+    }
+
+    /// Indicates whether or not the `Widget` specified by `widget_id` can currently receive focus.
+    /// The `Widget` must have its `CONFIG_FOCUSABLE` toggle set, and must be visible and enabled.
+    pub fn is_focusable(&self, widget_id: i32) -> bool {
+        widget_id > 0
+            && (widget_id as usize) < self.cache.len()
+            && !self.is_hidden(widget_id)
+            && self.is_enabled(widget_id)
+            && self.cache[widget_id as usize]
+                .widget
+                .borrow_mut()
+                .get_config()
+                .get_toggle(CONFIG_FOCUSABLE)
+    }
+
+    /// Finds the next focusable `Widget` after `widget_id` in the order in which `Widget`s were
+    /// added to the cache, wrapping around at the end of the list.  If `reverse` is `true`, the
+    /// previous focusable `Widget` is found instead.  While a modal `Widget` is shown, only it and its
+    /// children are considered.  Returns `0` if no `Widget` can receive focus.
+    pub fn find_next_focusable(&self, widget_id: i32, reverse: bool) -> i32 {
+        let cache_size = self.cache.len() as i32;
+        let modal_id = self.get_modal_id();
+
+        for i in 1..=cache_size {
+            let candidate_id = if reverse {
+                (widget_id - i).rem_euclid(cache_size)
+            } else {
+                (widget_id + i).rem_euclid(cache_size)
+            };
+
+            if self.is_focusable(candidate_id)
+                && (modal_id == 0 || self.is_descendant(candidate_id, modal_id))
+            {
+                return candidate_id;
+            }
+        }
+
+        0
+    }
+
     /// This function sends all other un-handled events from SDL2 to the currently highlighted
     /// `Widget`.
     pub fn other_event(&mut self, widget_id: i32, event: Event, cache: &[LayoutContainer]) {
@@ -273,23 +546,355 @@ impl WidgetCache {
         }
     }
 
-    /// This function performs the draw loop for all of the `Widget`s stored in the `cache`.  Each
-    /// `Widget` receives a mutable reference to the `Canvas` so that the `Widget` can be drawn on
-    /// the screen during the draw loop of the `Engine`.  This `draw_loop` function automatically
-    /// clips the screen area so that the `Widget` cannot draw outside of its bounds.  Returns `true`
-    /// if the display loop needs to refresh the top-level canvas, `false` otherwise.
+    /// This function performs the draw loop for all of the `Widget`s stored in the `cache`.  The
+    /// `Widget`s are drawn into a back buffer, which is kept between frames.  Each invalidated,
+    /// moved, shown or hidden `Widget` marks the area it covers (and the area it previously
+    /// covered) as damaged, and only the `Widget`s that intersect a damaged area are redrawn,
+    /// clipped to that area.  Child `Widget`s are clipped to the bounds of their parents.  Returns
+    /// `true` if any area of the back buffer was redrawn, `false` otherwise.
     pub fn draw_loop(&mut self, c: &mut Canvas<Window>) -> bool {
-        let cache_size = self.cache.len();
+        let (width, height) = c.output_size().unwrap();
+        let mut damage: Vec<Rect> = Vec::new();
 
-        for i in 0..cache_size {
-            if self.cache[i].widget.borrow_mut().is_invalidated() {
-                self.draw(0, c);
+        if self.back_buffer_size[0] != width || self.back_buffer_size[1] != height {
+            self.back_buffer.create_or_resize_texture(c, width, height);
+            self.back_buffer_size = vec![width, height];
+            damage.push(Rect::new(0, 0, width, height));
+        }
 
-                return true;
+        let mut paint_list = Vec::new();
+        let mut visible_areas: Vec<Option<Rect>> = vec![None; self.cache.len()];
+
+        self.build_paint_list(0, None, true, &mut paint_list);
+
+        for (paint_id, _, visible_area) in &paint_list {
+            visible_areas[*paint_id as usize] = *visible_area;
+        }
+
+        for (i, visible_area) in visible_areas.iter().enumerate() {
+            let last_drawn = self.cache[i].last_drawn;
+            let invalidated = self.cache[i].widget.borrow_mut().is_invalidated();
+
+            if *visible_area != last_drawn || (invalidated && visible_area.is_some()) {
+                damage.extend(last_drawn);
+                damage.extend(*visible_area);
+            }
+        }
+
+        if damage.is_empty() {
+            return false;
+        }
+
+        let damage = merge_rects(damage);
+
+        for (paint_id, widget_area, visible_area) in &paint_list {
+            let damaged_areas: Vec<Rect> = match visible_area {
+                Some(area) => damage
+                    .iter()
+                    .filter_map(|x| x.intersection(*area))
+                    .collect(),
+                None => continue,
+            };
+
+            if damaged_areas.is_empty() {
+                continue;
+            }
+
+            let mut paint_widget = self.cache[*paint_id as usize].widget.borrow_mut();
+            let is_enabled = paint_widget.get_config().is_enabled();
+
+            match paint_widget.draw(c, &mut self.texture_cache) {
+                Some(texture) => {
+                    c.with_texture_canvas(self.back_buffer.get_mut_ref(), |buffer| {
+                        for damaged_area in &damaged_areas {
+                            buffer.set_clip_rect(*damaged_area);
+                            buffer.copy(texture, None, *widget_area).unwrap();
+
+                            if !is_enabled {
+                                buffer.set_draw_color(Color::RGBA(0, 0, 0, 128));
+                                buffer.draw_rect(*widget_area).unwrap();
+                            }
+                        }
+
+                        buffer.set_clip_rect(None);
+                    })
+                    .unwrap();
+                }
+                None => eprintln!("No texture presented: ID={}", paint_id),
+            };
+
+            paint_widget.set_invalidated(false);
+        }
+
+        for (i, visible_area) in visible_areas.iter().enumerate() {
+            self.cache[i].last_drawn = *visible_area;
+        }
+
+        true
+    }
+
+    /// Copies the back buffer that the `Widget`s are drawn into onto the `Canvas`.  This is called
+    /// by the `Engine` after each draw loop.
+    pub fn copy_back_buffer(&mut self, c: &mut Canvas<Window>) {
+        if self.back_buffer_size[0] > 0 && self.back_buffer_size[1] > 0 {
+            c.copy(self.back_buffer.get_mut_ref(), None, None).unwrap();
+        }
+    }
+
+    /// Draws the open overlays of all visible `Widget`s directly onto the `Canvas`, in the order
+    /// in which the `Widget`s were added.  Overlays are not drawn into the back buffer, so they are
+    /// never clipped by, or hidden under, other `Widget`s.  While a modal `Widget` is shown, only the
+    /// overlays of it and its children are drawn.  This is called by the `Engine` after the modal
+    /// `Widget` has been drawn.
+    pub fn draw_overlays(&mut self, c: &mut Canvas<Window>) {
+        let modal_id = self.get_modal_id();
+
+        for i in 1..self.cache.len() {
+            if self.is_hidden(i as i32)
+                || (modal_id != 0 && !self.is_descendant(i as i32, modal_id))
+            {
+                continue;
+            }
+
+            let mut widget = self.cache[i].widget.borrow_mut();
+
+            if let Some(overlay_area) = widget.get_overlay_area() {
+                if let Some(texture) = widget.draw_overlay(c, &mut self.texture_cache) {
+                    c.copy(texture, None, overlay_area).unwrap();
+                }
+            }
+        }
+    }
+
+    /// Returns the tooltip text of the `Widget` specified by `widget_id`.  An empty `String` is
+    /// returned if the `Widget` has no tooltip, if it is hidden or cannot receive input, or if it
+    /// has an overlay open.
+    pub fn get_tooltip(&self, widget_id: i32) -> String {
+        if widget_id <= 0
+            || widget_id as usize >= self.cache.len()
+            || self.is_hidden(widget_id)
+            || !self.is_input_allowed(widget_id)
+        {
+            return String::new();
+        }
+
+        let mut widget = self.cache[widget_id as usize].widget.borrow_mut();
+
+        if widget.get_overlay_area().is_some() {
+            return String::new();
+        }
+
+        widget.get_config().get_text(CONFIG_TOOLTIP)
+    }
+
+    /// Draws a tooltip bubble containing `text` directly onto the `Canvas`, near the mouse point
+    /// `x, y`.  The bubble is drawn below and to the right of the mouse, and is moved so that it
+    /// stays inside of the `Window`.  Long text is wrapped.  This is called by the `Engine` after
+    /// the overlays have been drawn, so tooltips are drawn above everything else.  The text is only
+    /// rendered when it changes, and is kept until `clear_tooltip` is called.
+    pub fn draw_tooltip(&mut self, c: &mut Canvas<Window>, text: &str, x: i32, y: i32) {
+        if text.is_empty() {
+            self.clear_tooltip();
+            return;
+        }
+
+        if self
+            .tooltip
+            .as_ref()
+            .is_none_or(|(tooltip_text, _, _, _)| tooltip_text != text)
+        {
+            self.clear_tooltip();
+
+            let (font_texture, width, height) = self.texture_cache.render_text(
+                c,
+                String::from(TOOLTIP_FONT_NAME),
+                TOOLTIP_FONT_SIZE,
+                FontStyle::NORMAL,
+                String::from(text),
+                Color::RGB(0, 0, 0),
+                TOOLTIP_MAX_WIDTH,
+            );
+
+            self.tooltip = Some((String::from(text), font_texture, width, height));
+        }
+
+        let window_size = self.cache[0]
+            .widget
+            .borrow_mut()
+            .get_config()
+            .get_size(CONFIG_SIZE);
+        let (_, font_texture, width, height) = self.tooltip.as_ref().unwrap();
+        let (width, height) = (*width, *height);
+        let bubble_width = width + TOOLTIP_PADDING * 2;
+        let bubble_height = height + TOOLTIP_PADDING * 2;
+        let bubble_x = (x + 12).min(window_size[0] as i32 - bubble_width as i32);
+        let bubble_y = if y + 20 + bubble_height as i32 > window_size[1] as i32 {
+            y - 4 - bubble_height as i32
+        } else {
+            y + 20
+        };
+        let bubble = Rect::new(
+            bubble_x.max(0),
+            bubble_y.max(0),
+            bubble_width,
+            bubble_height,
+        );
+
+        c.set_draw_color(Color::RGB(255, 255, 225));
+        c.fill_rect(bubble).unwrap();
+        c.set_draw_color(Color::RGB(0, 0, 0));
+        c.draw_rect(bubble).unwrap();
+        c.copy(
+            font_texture,
+            None,
+            Rect::new(
+                bubble.x() + TOOLTIP_PADDING as i32,
+                bubble.y() + TOOLTIP_PADDING as i32,
+                width,
+                height,
+            ),
+        )
+        .unwrap();
+    }
+
+    /// Destroys the rendered text of the tooltip, if any.  This is called by the `Engine` while it
+    /// draws a frame in which the tooltip is hidden, and must only be called while the `Canvas`
+    /// that the tooltip was drawn on still exists.
+    pub fn clear_tooltip(&mut self) {
+        if let Some((_, font_texture, _, _)) = self.tooltip.take() {
+            // The Canvas that created the Texture still exists, as described above.
+            unsafe { font_texture.destroy() };
+        }
+    }
+
+    /// Draws the modal `Widget` that is currently shown, if any, along with its children, directly
+    /// onto the `Canvas`.  The rest of the `Canvas` is dimmed first, so that the modal `Widget`
+    /// stands out.  This is called by the `Engine` after the back buffer has been copied to the
+    /// `Canvas`.
+    pub fn draw_modal(&mut self, c: &mut Canvas<Window>) {
+        let modal_id = self.get_modal_id();
+
+        if modal_id == 0 {
+            return;
+        }
+
+        let blend_mode = c.blend_mode();
+        let modal_area = self.cache[modal_id as usize]
+            .widget
+            .borrow_mut()
+            .get_drawing_area();
+        let client_area = self.cache[modal_id as usize]
+            .widget
+            .borrow_mut()
+            .get_client_area();
+        let mut paint_list = vec![(modal_id, modal_area, Some(modal_area))];
+
+        c.set_blend_mode(BlendMode::Blend);
+        c.set_draw_color(Color::RGBA(0, 0, 0, 128));
+        c.fill_rect(None).unwrap();
+        c.set_blend_mode(blend_mode);
+
+        self.build_paint_list(
+            modal_id,
+            modal_area.intersection(client_area),
+            true,
+            &mut paint_list,
+        );
+
+        for (paint_id, widget_area, visible_area) in &paint_list {
+            if let Some(visible_area) = visible_area {
+                let mut paint_widget = self.cache[*paint_id as usize].widget.borrow_mut();
+
+                if let Some(texture) = paint_widget.draw(c, &mut self.texture_cache) {
+                    c.set_clip_rect(*visible_area);
+                    c.copy(texture, None, *widget_area).unwrap();
+                }
+
+                paint_widget.set_invalidated(false);
             }
         }
 
-        false
+        c.set_clip_rect(None);
+    }
+
+    /// Closes the open overlays of all `Widget`s other than the `Widget` specified by `widget_id`.
+    /// This is called by the `Engine` when a mouse button is pressed, so that clicking outside of
+    /// an overlay closes it.
+    pub fn dismiss_overlays(&mut self, widget_id: i32, cache: &[LayoutContainer]) {
+        for i in 1..self.cache.len() {
+            if i as i32 == widget_id {
+                continue;
+            }
+
+            let mut widget = self.cache[i].widget.borrow_mut();
+
+            if widget.get_overlay_area().is_some() {
+                widget.dismiss_overlay(&self.cache, cache);
+            }
+        }
+    }
+
+    /// Opens the context menu of the `Widget` specified by `widget_id` at the point `x, y` in the
+    /// `Window`.  The context menu is the `CONFIG_CONTEXT_MENU` of the `Widget`, or of its closest
+    /// parent that has one set.  Nothing is opened if the `Widget` has an overlay open, such as
+    /// when it is a context menu itself.  Returns the ID of the context menu that was opened, or
+    /// `0` if there was none.  This is called by the `Engine` when the right mouse button is
+    /// pressed.
+    pub fn open_context_menu(
+        &mut self,
+        widget_id: i32,
+        x: i32,
+        y: i32,
+        cache: &[LayoutContainer],
+    ) -> i32 {
+        if widget_id < 0
+            || widget_id as usize >= self.cache.len()
+            || self.cache[widget_id as usize]
+                .widget
+                .borrow_mut()
+                .get_overlay_area()
+                .is_some()
+        {
+            return 0;
+        }
+
+        let mut current_id = widget_id;
+
+        loop {
+            let menu_id = self.cache[current_id as usize]
+                .widget
+                .borrow_mut()
+                .get_config()
+                .get_numeric(CONFIG_CONTEXT_MENU);
+
+            if menu_id > 0 && (menu_id as usize) < self.cache.len() {
+                if menu_id == widget_id || !self.is_input_allowed(menu_id) {
+                    return 0;
+                }
+
+                self.dismiss_overlays(menu_id, cache);
+                self.cache[menu_id as usize]
+                    .widget
+                    .borrow_mut()
+                    .open_context_menu(&self.cache, cache, widget_id, make_points(x, y));
+
+                return menu_id;
+            }
+
+            if current_id == 0 {
+                return 0;
+            }
+
+            current_id = self.cache[current_id as usize].parent_id;
+        }
+    }
+
+    /// Invalidates all of the `Widget`s in the cache, forcing the entire screen to be redrawn on
+    /// the next draw loop.
+    pub fn invalidate_all(&mut self) {
+        for x in &self.cache {
+            x.widget.borrow_mut().set_invalidated(true);
+        }
     }
 
     /// Returns a borrowed slice of the `WidgetContainer` `Vec` object, which can be passed on to
@@ -300,7 +905,7 @@ impl WidgetCache {
 
     // Private functions
 
-    fn get_children_of(&mut self, widget_id: i32) -> Vec<i32> {
+    fn get_children_of(&self, widget_id: i32) -> Vec<i32> {
         self.cache
             .iter()
             .filter(|x| x.parent_id == widget_id)
@@ -308,79 +913,159 @@ impl WidgetCache {
             .collect()
     }
 
-    fn draw(&mut self, widget_id: i32, c: &mut Canvas<Window>) {
-        let parents_of_widget = self.get_children_of(widget_id);
+    fn find_widget_in(&mut self, widget_id: i32, x: i32, y: i32) -> i32 {
+        let mut found_widget_id: i32 = widget_id;
+        let client_area = self.cache[widget_id as usize]
+            .widget
+            .borrow_mut()
+            .get_client_area();
 
-        if parents_of_widget.is_empty() {
-            return;
+        // Children can only be found inside of the client area of their parent.
+        if widget_id != 0 && !client_area.contains_point((x, y)) {
+            return widget_id;
         }
 
-        for paint_id in &parents_of_widget {
-            let paint_widget = &mut self.cache[*paint_id as usize];
-            let is_hidden = paint_widget.widget.borrow_mut().get_config().is_hidden();
-            let is_enabled = paint_widget.widget.borrow_mut().get_config().is_enabled();
-            let widget_x = paint_widget.widget.borrow_mut().get_config().to_x(0);
-            let widget_y = paint_widget.widget.borrow_mut().get_config().to_y(0);
-            let widget_w = paint_widget
-                .widget
-                .borrow_mut()
-                .get_config()
-                .get_size(CONFIG_SIZE)[0];
-            let widget_h = paint_widget
+        for child_id in self.get_children_of(widget_id) {
+            if child_id == widget_id
+                || self.cache[child_id as usize]
+                    .widget
+                    .borrow_mut()
+                    .get_config()
+                    .is_hidden()
+            {
+                continue;
+            }
+
+            let area = self.cache[child_id as usize]
                 .widget
                 .borrow_mut()
-                .get_config()
-                .get_size(CONFIG_SIZE)[1];
+                .get_drawing_area();
 
-            if !is_hidden {
-                match paint_widget
-                    .widget
-                    .borrow_mut()
-                    .draw(c, &mut self.texture_cache)
-                {
-                    Some(texture) => {
-                        c.copy(
-                            texture,
-                            None,
-                            Rect::new(widget_x, widget_y, widget_w, widget_h),
-                        )
-                        .unwrap();
-                    }
-                    None => eprintln!("No texture presented: ID={}", paint_id),
-                };
-
-                paint_widget.widget.borrow_mut().set_invalidated(false);
+            if x >= area.x() && x <= area.right() && y >= area.y() && y <= area.bottom() {
+                found_widget_id = child_id;
             }
+        }
 
-            if *paint_id != widget_id {
-                self.draw(*paint_id, c);
+        if found_widget_id != widget_id {
+            self.find_widget_in(found_widget_id, x, y)
+        } else {
+            widget_id
+        }
+    }
+
+    fn build_paint_list(
+        &self,
+        widget_id: i32,
+        clip: Option<Rect>,
+        visible: bool,
+        paint_list: &mut Vec<(i32, Rect, Option<Rect>)>,
+    ) {
+        for paint_id in self.get_children_of(widget_id) {
+            let paint_widget = &self.cache[paint_id as usize];
+            let is_hidden = paint_widget.widget.borrow_mut().get_config().is_hidden();
+            let widget_area = paint_widget.widget.borrow_mut().get_drawing_area();
+            let client_area = paint_widget.widget.borrow_mut().get_client_area();
+
+            // Hidden Widgets hide all of their children as well.
+            if is_hidden {
+                continue;
             }
 
-            if !is_enabled {
-                c.set_draw_color(Color::RGBA(0, 0, 0, 128));
-                c.draw_rect(Rect::new(widget_x, widget_y, widget_w, widget_h))
-                    .unwrap();
+            // Children are clipped to the bounds of all of their parents.
+            let visible_area = match clip {
+                Some(clip_area) => clip_area.intersection(widget_area),
+                None => Some(widget_area),
             }
+            .filter(|_| visible);
+
+            paint_list.push((paint_id, widget_area, visible_area));
+
+            // The children of this Widget are clipped to its client area.
+            if paint_id != widget_id {
+                let child_area = visible_area.and_then(|x| x.intersection(client_area));
+
+                self.build_paint_list(
+                    paint_id,
+                    Some(child_area.unwrap_or(client_area)),
+                    child_area.is_some(),
+                    paint_list,
+                );
+            }
+        }
+    }
+
+    /// Returns the point that the children of the `Widget` specified by `widget_id` are positioned
+    /// relative to: the origin of the `Widget`, minus its content offset.
+    fn get_child_origin(&self, widget_id: i32) -> Vec<i32> {
+        let mut widget = self.cache[widget_id as usize].widget.borrow_mut();
+        let origin = widget.get_config().get_point(CONFIG_ORIGIN);
+        let offset = widget.get_content_offset();
+
+        vec![origin[0] - offset[0], origin[1] - offset[1]]
+    }
+
+    /// Indicates whether or not the `Widget` specified by `widget_id` is `ancestor_id`, or one of its
+    /// children.
+    fn is_descendant(&self, widget_id: i32, ancestor_id: i32) -> bool {
+        let mut current_id = widget_id;
+
+        loop {
+            if current_id == ancestor_id {
+                return true;
+            }
+
+            let parent_id = self.cache[current_id as usize].parent_id;
+
+            if parent_id == current_id {
+                return false;
+            }
+
+            current_id = parent_id;
         }
     }
 
+    /// Indicates whether or not the `Widget` specified by `widget_id` is hidden, either directly, or
+    /// because one of its parents is hidden.
     fn is_hidden(&self, widget_id: i32) -> bool {
-        self.cache[widget_id as usize]
-            .widget
-            .borrow_mut()
-            .get_config()
-            .is_hidden()
+        let container = &self.cache[widget_id as usize];
+
+        container.widget.borrow_mut().get_config().is_hidden()
+            || (container.parent_id != widget_id && self.is_hidden(container.parent_id))
     }
 
+    /// Indicates whether or not the `Widget` specified by `widget_id` is enabled.  A `Widget` is
+    /// disabled if any of its parents are disabled.
     fn is_enabled(&self, widget_id: i32) -> bool {
-        self.cache[widget_id as usize]
-            .widget
-            .borrow_mut()
-            .get_config()
-            .is_enabled()
+        let container = &self.cache[widget_id as usize];
+
+        container.widget.borrow_mut().get_config().is_enabled()
+            && (container.parent_id == widget_id || self.is_enabled(container.parent_id))
     }
 }
 
+/// Merges any overlapping rectangles in `rects` into their union, so that no area is drawn more
+/// than once in a single draw loop.
+fn merge_rects(mut rects: Vec<Rect>) -> Vec<Rect> {
+    let mut merged: Vec<Rect> = Vec::new();
+
+    while let Some(mut rect) = rects.pop() {
+        let mut i = 0;
+
+        while i < merged.len() {
+            if merged[i].has_intersection(rect) {
+                rect = rect.union(merged.remove(i));
+                i = 0;
+            } else {
+                i += 1;
+            }
+        }
+
+        merged.push(rect);
+    }
+
+    merged
+}
+
 impl Default for WidgetCache {
     fn default() -> Self {
         Self::new()
@@ -70,6 +70,54 @@ pub const CONFIG_FONT_SIZE: u8 = 12;
 /// `PushButtonWidget` selected state.  This is stored as a `Config::Toggle` value.
 pub const CONFIG_SELECTED_STATE: u8 = 13;
 
+/// `Widget` focusable flag.  When set to `true`, the `Widget` can receive keyboard focus, either by
+/// being clicked, or by using `Tab` and `Shift-Tab` to cycle through focusable `Widget`s.  This is
+/// stored as a `Config::Toggle` value.
+pub const CONFIG_FOCUSABLE: u8 = 14;
+
+/// `Widget` minimum size hint, used by `Layout` managers.  A `Layout` will not shrink a `Widget`
+/// below this size unless there is not enough room.  This is stored as a `Config::Size` value.
+pub const CONFIG_MIN_SIZE: u8 = 15;
+
+/// `Widget` maximum size hint, used by `Layout` managers.  A `Layout` will not grow a `Widget`
+/// beyond this size.  This is stored as a `Config::Size` value.
+pub const CONFIG_MAX_SIZE: u8 = 16;
+
+/// `Widget` preferred size hint, used by `Layout` managers.  This is the size a `Widget` is given
+/// before any remaining space is handed out.  This is stored as a `Config::Size` value.
+pub const CONFIG_PREFERRED_SIZE: u8 = 17;
+
+/// `Widget` stretch factor, used by `Layout` managers.  Remaining space in a `Layout` is divided
+/// between `Widget`s in proportion to their stretch factors.  A stretch of `0` keeps the `Widget`
+/// at its preferred size.  Defaults to `1`.  This is stored as a `Config::Numeric` value.
+pub const CONFIG_STRETCH: u8 = 18;
+
+/// `Widget` scrollable flag.  Mouse wheel events are sent to the closest `Widget` under the mouse
+/// with this flag set: either the `Widget` itself, or one of its parents.  This is stored as a
+/// `Config::Toggle` value.
+pub const CONFIG_SCROLLABLE: u8 = 19;
+
+/// `Widget` modal flag.  While a `Widget` with this flag set is visible, it is drawn above all other
+/// `Widget`s over a dimmed background, and only it and its children receive input.  If more than
+/// one modal `Widget` is visible, the one added last is used.  This is stored as a
+/// `Config::Toggle` value.
+pub const CONFIG_MODAL: u8 = 20;
+
+/// `Widget` context menu.  This is the ID of the `Widget` that is opened as a popup menu when the
+/// right mouse button is pressed over this `Widget` or any of its children, or `0` for none.  This
+/// is stored as a `Config::Numeric` value.
+pub const CONFIG_CONTEXT_MENU: u8 = 21;
+
+/// `Widget` tooltip.  This is the help text that is shown in a bubble near the mouse when it rests
+/// over the `Widget`.  An empty tooltip is not shown.  This is stored as a `Config::Text` value.
+pub const CONFIG_TOOLTIP: u8 = 22;
+
+/// `Widget` mouse capture flag.  When a mouse button is pressed over a `Widget` with this flag set,
+/// all mouse movement is sent to that `Widget` until the button is released, even when the mouse
+/// leaves its bounds.  This is used by `Widget`s that can be dragged.  This is stored as a
+/// `Config::Toggle` value.
+pub const CONFIG_MOUSE_CAPTURE: u8 = 23;
+
 /// This enum is used by the `ImageWidget`, which controls the positioning of the image being
 /// rendered within the bounds of the `Widget`.
 #[derive(Clone, Debug, Copy)]
@@ -170,6 +218,9 @@ pub struct WidgetConfig {
 
     /// `Widget`'s redraw flag.  Set `true` if the object needs to be redrawn, `false` otherwise.
     invalidated: bool,
+
+    /// `Widget`'s focus flag - set by the `Engine` when this `Widget` receives keyboard input.
+    focused: bool,
 }
 
 /// This is the implementation of the `WidgetConfig`.
@@ -190,6 +241,7 @@ impl WidgetConfig {
             hidden: false,
             enabled: true,
             invalidated: true,
+            focused: false,
         }
     }
 
@@ -258,6 +310,19 @@ impl WidgetConfig {
         self.hidden
     }
 
+    /// Sets the keyboard focus state of the `Widget`.  This is controlled by the `Engine`, and
+    /// should not normally need to be called directly.
+    pub fn set_focused(&mut self, flag: bool) {
+        self.focused = flag;
+        self.invalidated = true;
+    }
+
+    /// Indicates whether or not this `Widget` currently has keyboard focus - `true` if it has focus,
+    /// `false` otherwise.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
     /// Sets a point for a configuration key.
     pub fn set_point(&mut self, config: u8, x: i32, y: i32) {
         self.config.insert(config, Config::Points(vec![x, y]));
@@ -354,6 +419,39 @@ impl WidgetConfig {
         }
     }
 
+    /// Retrieves the minimum size hint for the `Widget`.  Returns `0x0` if not set.
+    pub fn get_min_size(&self) -> Size {
+        match self.config.get(&CONFIG_MIN_SIZE) {
+            Some(Config::Size(size)) => size.clone(),
+            _ => vec![0, 0],
+        }
+    }
+
+    /// Retrieves the maximum size hint for the `Widget`.  Returns `u32::MAX` for each dimension if
+    /// not set.
+    pub fn get_max_size(&self) -> Size {
+        match self.config.get(&CONFIG_MAX_SIZE) {
+            Some(Config::Size(size)) => size.clone(),
+            _ => vec![u32::MAX, u32::MAX],
+        }
+    }
+
+    /// Retrieves the preferred size hint for the `Widget`.  Returns `0x0` if not set.
+    pub fn get_preferred_size(&self) -> Size {
+        match self.config.get(&CONFIG_PREFERRED_SIZE) {
+            Some(Config::Size(size)) => size.clone(),
+            _ => vec![0, 0],
+        }
+    }
+
+    /// Retrieves the stretch factor for the `Widget`.  Returns `1` if not set.
+    pub fn get_stretch(&self) -> i32 {
+        match self.config.get(&CONFIG_STRETCH) {
+            Some(Config::Numeric(stretch)) => *stretch,
+            _ => 1,
+        }
+    }
+
     /// Retrieves a `PaddingConstraint` toggle for a configuration key.  Returns empty `PaddingConstraint` if not set.
     pub fn get_padding(&self, k: u8) -> PaddingConstraint {
         match self.config.get(&k) {
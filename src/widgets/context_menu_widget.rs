@@ -0,0 +1,336 @@
+// Pushrod Widget Library
+// Context Menu Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{BlendMode, Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{
+    make_points, make_points_origin, make_size, Points, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH,
+};
+use crate::widgets::popup_menu::*;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the callback type that is used when an `on_selected` callback is triggered from this
+/// `Widget`.  It receives the ID of the `MenuItem` that was selected.
+pub type OnContextMenuSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut ContextMenuWidget, &[WidgetContainer], &[LayoutContainer], i32)>>;
+
+/// This is the storage object for the `ContextMenuWidget`.  It stores the config, properties,
+/// callback registry, the items of the menu, and the state of the menu while it is open.
+pub struct ContextMenuWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    overlay_store: TextureStore,
+    items: Vec<MenuItem>,
+    popup: PopupMenu,
+    popup_invalidated: bool,
+    source_widget_id: i32,
+    mouse_point: Points,
+    on_selected: OnContextMenuSelectedCallbackType,
+}
+
+/// This is the implementation of the `ContextMenuWidget`, a popup menu that is opened at the mouse
+/// position when the right mouse button is pressed over a `Widget`.  The `ContextMenuWidget` has
+/// no area of its own: it only draws its menu as an overlay while it is open.  It is added to the
+/// `Engine` like any other `Widget`, and then assigned to the `Widget`s that use it with
+/// `Engine::set_context_menu`.  The same `ContextMenuWidget` can be shared by many `Widget`s: the
+/// `Widget` it was opened for is available from `get_source_widget_id`.
+///
+/// While the menu is open, it has keyboard focus, and handles the keys the same way as a
+/// `PopupMenu`.  When it is closed, focus returns to the `Widget` that had it before.
+impl ContextMenuWidget {
+    /// Creates a new `ContextMenuWidget` containing `items`.  Items are drawn using
+    /// `assets/OpenSans-Regular.ttf` at size 14.
+    pub fn new(items: Vec<MenuItem>) -> Self {
+        let mut config = WidgetConfig::new(make_points_origin(), make_size(0, 0));
+
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            overlay_store: TextureStore::default(),
+            items,
+            popup: PopupMenu::new(),
+            popup_invalidated: false,
+            source_widget_id: 0,
+            mouse_point: make_points(0, 0),
+            on_selected: None,
+        }
+    }
+
+    /// Replaces the items of the menu with `items`, closing the menu if it is open.
+    pub fn set_items(&mut self, items: Vec<MenuItem>) {
+        self.close();
+        self.items = items;
+    }
+
+    /// Returns the items of the menu.
+    pub fn get_items(&self) -> &[MenuItem] {
+        &self.items
+    }
+
+    /// Returns the `MenuItem` with the specified `id`, or `None` if there is no such item.
+    pub fn get_item(&self, id: i32) -> Option<&MenuItem> {
+        find_menu_item(&self.items, id)
+    }
+
+    /// Indicates whether or not the `MenuItem` with the specified `id` is checked.
+    pub fn is_checked(&self, id: i32) -> bool {
+        self.get_item(id).is_some_and(|item| item.checked)
+    }
+
+    /// Sets the check mark of the `MenuItem` with the specified `id`.
+    pub fn set_checked(&mut self, id: i32, checked: bool) {
+        if let Some(item) = find_menu_item_mut(&mut self.items, id) {
+            item.checked = checked;
+            self.popup_invalidated = true;
+        }
+    }
+
+    /// Enables or disables the `MenuItem` with the specified `id`.
+    pub fn set_item_enabled(&mut self, id: i32, enabled: bool) {
+        if let Some(item) = find_menu_item_mut(&mut self.items, id) {
+            item.enabled = enabled;
+            self.popup_invalidated = true;
+        }
+    }
+
+    /// Sets the width of the menu, in pixels.
+    pub fn set_menu_width(&mut self, menu_width: u32) {
+        self.popup.set_menu_width(menu_width);
+    }
+
+    /// Sets the font used to draw the items, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.popup.set_font(font_name, font_size);
+        self.popup_invalidated = true;
+    }
+
+    /// Indicates whether or not the menu is open.
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    /// Returns the ID of the `Widget` that the menu was last opened for.
+    pub fn get_source_widget_id(&self) -> i32 {
+        self.source_widget_id
+    }
+
+    /// Opens the menu at the point `x, y` in the `Window`, for the `Widget` specified by
+    /// `widget_id`.  The menu is moved so that it fits inside of the `Window`, the size of which
+    /// is taken from the top-level `Widget`.  This is called by the `Engine` when the right mouse
+    /// button is pressed, but can also be used to open the menu from a callback.
+    pub fn open(&mut self, widgets: &[WidgetContainer], widget_id: i32, x: i32, y: i32) {
+        let window_size = widgets[0]
+            .widget
+            .borrow_mut()
+            .get_config()
+            .get_size(CONFIG_SIZE);
+
+        self.popup.open(
+            &self.items,
+            Rect::new(x, y, 1, 1),
+            Rect::new(0, 0, window_size[SIZE_WIDTH], window_size[SIZE_HEIGHT]),
+        );
+        self.source_widget_id = widget_id;
+        self.popup_invalidated = true;
+
+        let is_open = self.popup.is_open();
+
+        self.get_config().set_toggle(CONFIG_FOCUSABLE, is_open);
+    }
+
+    /// Closes the menu, if it is open.
+    pub fn close(&mut self) {
+        self.popup.close();
+        self.get_config().set_toggle(CONFIG_FOCUSABLE, false);
+    }
+
+    /// Assigns the callback closure that will be used when a `MenuItem` is selected.
+    pub fn on_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut ContextMenuWidget, &[WidgetContainer], &[LayoutContainer], i32) + 'static,
+    {
+        self.on_selected = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_selected` callback.
+    fn call_selected_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        id: i32,
+    ) {
+        if let Some(mut cb) = self.on_selected.take() {
+            cb(self, widgets, layouts, id);
+            self.on_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that acts on a response from the menu.
+    fn handle_response(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        response: MenuResponse,
+    ) {
+        match response {
+            MenuResponse::Changed => self.popup_invalidated = true,
+            MenuResponse::Activated(id) => {
+                self.close();
+                self.call_selected_callback(widgets, layouts, id);
+            }
+            MenuResponse::Closed => self.close(),
+            _ => (),
+        }
+    }
+}
+
+/// This is the `Widget` implementation of the `ContextMenuWidget`.
+impl Widget for ContextMenuWidget {
+    /// Returns the area covered by the menu and its submenus, if the menu is open.
+    fn get_overlay_area(&mut self) -> Option<Rect> {
+        self.popup.get_area()
+    }
+
+    /// Draws the menu and its submenus.
+    fn draw_overlay(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        let area = self.popup.get_area()?;
+
+        if self.popup_invalidated {
+            self.overlay_store
+                .create_or_resize_texture(c, area.width(), area.height());
+            self.overlay_store
+                .get_mut_ref()
+                .set_blend_mode(BlendMode::Blend);
+
+            let popup = &self.popup;
+            let items = &self.items;
+            let config = &self.config;
+
+            c.with_texture_canvas(self.overlay_store.get_mut_ref(), |texture| {
+                popup.draw(texture, t, items, config);
+            })
+            .unwrap();
+
+            self.popup_invalidated = false;
+        }
+
+        self.overlay_store.get_optional_ref()
+    }
+
+    /// Closes the menu when the mouse is clicked outside of it.
+    fn dismiss_overlay(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.close();
+    }
+
+    /// Opens the menu at the mouse position.
+    fn open_context_menu(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _widget_id: i32,
+        _points: Points,
+    ) {
+        self.open(_widgets, _widget_id, _points[POINT_X], _points[POINT_Y]);
+    }
+
+    /// Highlights the item under the mouse, opening its submenu if it has one.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        self.mouse_point = _points.clone();
+
+        let response = self
+            .popup
+            .mouse_moved(&self.items, _points[POINT_X], _points[POINT_Y]);
+
+        self.handle_response(_widgets, _layouts, response);
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Selects the item under the mouse.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 && _state && self.is_open() {
+            let mouse_point = self.mouse_point.clone();
+            let response =
+                self.popup
+                    .button_down(&mut self.items, mouse_point[POINT_X], mouse_point[POINT_Y]);
+
+            self.handle_response(_widgets, _layouts, response);
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles keyboard navigation of the menu.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        let response = self.popup.key_pressed(&mut self.items, _keycode);
+
+        self.handle_response(_widgets, _layouts, response);
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Closes the menu when it loses focus.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        if !_focused {
+            self.close();
+        }
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
@@ -0,0 +1,684 @@
+// Pushrod Widget Library
+// Dialog Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{
+    make_points, make_size, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH,
+};
+use crate::widgets::dialog_widget::DialogResult::{DialogCancel, DialogInput, DialogOk};
+use crate::widgets::text_field_widget::TextFieldWidget;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::ttf::FontStyle;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the height of the title bar of a `DialogWidget`, in pixels.
+const TITLE_HEIGHT: u32 = 30;
+
+/// This is the width of the buttons of a `DialogWidget`, in pixels.
+const BUTTON_WIDTH: u32 = 80;
+
+/// This is the height of the buttons, and the text entry box, of a `DialogWidget`, in pixels.
+const BUTTON_HEIGHT: u32 = 28;
+
+/// This is the callback type that is used when an `on_result` callback is triggered from this
+/// `Widget`.
+pub type OnDialogResultCallbackType =
+    Option<Box<dyn FnMut(&mut DialogWidget, &[WidgetContainer], &[LayoutContainer], DialogResult)>>;
+
+/// These are the results that a `DialogWidget` reports when it is closed.
+#[derive(PartialEq, Clone, Debug)]
+pub enum DialogResult {
+    /// The `OK` button was clicked, or `Return` was pressed.
+    DialogOk,
+
+    /// The `Cancel` button was clicked, or `Escape` was pressed.
+    DialogCancel,
+
+    /// A prompt dialog was accepted, with the text that was entered.
+    DialogInput(String),
+}
+
+/// These are the kinds of dialog that a `DialogWidget` can be.
+#[derive(PartialEq, Clone, Copy, Debug)]
+enum DialogKind {
+    Custom,
+    Message,
+    Confirm,
+    Prompt,
+}
+
+/// This is the storage object for the `DialogWidget`.  It stores the config, properties, callback
+/// registry, the title, message and buttons of the dialog, and the `TextFieldWidget` that edits
+/// the text of a prompt.
+pub struct DialogWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    kind: DialogKind,
+    title: String,
+    message: String,
+    input_field: TextFieldWidget,
+    button_labels: Vec<String>,
+    font_name: String,
+    font_size: i32,
+    hovered_button: i32,
+    pressed_button: i32,
+    mouse_point: Points,
+    on_result: OnDialogResultCallbackType,
+}
+
+/// This is the implementation of the `DialogWidget`, a modal window with a title bar.  While it is
+/// shown, the rest of the `Window` is dimmed, and only the dialog and its children receive input.
+/// Dialogs are created hidden, and are shown with `open`.
+///
+/// The ready-made dialogs (`message_box`, `confirm` and `prompt`) draw their own message and
+/// buttons, close themselves when a button is clicked, and report the result through the
+/// `on_result` callback.  A prompt dialog hosts a `TextFieldWidget`, which receives the keys and
+/// text typed into the dialog.  `Return` accepts the dialog, and `Escape` cancels it.  A dialog created
+/// with `new` has no buttons: add child `Widget`s to it, and close it with `close`.
+impl DialogWidget {
+    /// Creates a new, empty `DialogWidget` given the `x, y, w, h` coordinates and the `title` to
+    /// show in its title bar.  Child `Widget`s are placed below the title bar.
+    pub fn new(points: Points, size: Size, title: String) -> Self {
+        Self::with_kind(
+            points,
+            size,
+            title,
+            String::new(),
+            DialogKind::Custom,
+            vec![],
+        )
+    }
+
+    /// Creates a message box, which shows a `message` with an `OK` button.  It reports `DialogOk`
+    /// when it is closed.
+    pub fn message_box(points: Points, size: Size, title: String, message: String) -> Self {
+        Self::with_kind(
+            points,
+            size,
+            title,
+            message,
+            DialogKind::Message,
+            vec![String::from("OK")],
+        )
+    }
+
+    /// Creates a confirmation dialog, which shows a `message` with `OK` and `Cancel` buttons.  It
+    /// reports `DialogOk` or `DialogCancel` when it is closed.
+    pub fn confirm(points: Points, size: Size, title: String, message: String) -> Self {
+        Self::with_kind(
+            points,
+            size,
+            title,
+            message,
+            DialogKind::Confirm,
+            vec![String::from("OK"), String::from("Cancel")],
+        )
+    }
+
+    /// Creates a prompt dialog, which shows a `message` and a text entry box containing `text`,
+    /// with `OK` and `Cancel` buttons.  It reports `DialogInput` with the entered text, or
+    /// `DialogCancel`, when it is closed.
+    pub fn prompt(
+        points: Points,
+        size: Size,
+        title: String,
+        message: String,
+        text: String,
+    ) -> Self {
+        let mut dialog = Self::with_kind(
+            points,
+            size,
+            title,
+            message,
+            DialogKind::Prompt,
+            vec![String::from("OK"), String::from("Cancel")],
+        );
+
+        dialog.input_field.set_text(text);
+        dialog.place_input_field();
+        dialog
+    }
+
+    /// Shows the dialog.
+    pub fn open(&mut self) {
+        self.hovered_button = -1;
+        self.pressed_button = -1;
+        self.place_input_field();
+        self.get_config().show();
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Hides the dialog without reporting a result.
+    pub fn close(&mut self) {
+        self.get_config().hide();
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Changes the message shown in the dialog.
+    pub fn set_message(&mut self, message: String) {
+        self.message = message;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the message shown in the dialog.
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+
+    /// Changes the text in the text entry box of a prompt dialog.
+    pub fn set_input(&mut self, text: String) {
+        self.input_field.set_text(text);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the text in the text entry box of a prompt dialog.
+    pub fn get_input(&self) -> String {
+        self.input_field.get_text()
+    }
+
+    /// Changes the labels of the buttons, in order from the accepting button to the cancelling
+    /// button.  Labels past the number of buttons in the dialog are ignored.
+    pub fn set_button_labels(&mut self, labels: Vec<String>) {
+        for (button_label, label) in self.button_labels.iter_mut().zip(labels) {
+            *button_label = label;
+        }
+
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the font used to draw the dialog, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.font_name = font_name;
+        self.font_size = font_size;
+        self.input_field.set_numeric(CONFIG_FONT_SIZE, font_size);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Assigns the callback closure that will be used when the dialog is closed with a result.
+    pub fn on_result<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut DialogWidget, &[WidgetContainer], &[LayoutContainer], DialogResult) + 'static,
+    {
+        self.on_result = Some(Box::new(callback));
+    }
+
+    /// Internal function that creates a hidden, modal `DialogWidget`.
+    fn with_kind(
+        points: Points,
+        size: Size,
+        title: String,
+        message: String,
+        kind: DialogKind,
+        button_labels: Vec<String>,
+    ) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_FOCUSABLE, kind != DialogKind::Custom);
+        config.set_toggle(CONFIG_MODAL, true);
+        config.set_color(CONFIG_COLOR_BASE, Color::RGB(240, 240, 240));
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(64, 64, 64));
+        config.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        config.hide();
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            kind,
+            title,
+            message,
+            input_field: TextFieldWidget::new(
+                make_points(0, 0),
+                make_size(1, BUTTON_HEIGHT),
+                String::new(),
+                16,
+            ),
+            button_labels,
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size: 16,
+            hovered_button: -1,
+            pressed_button: -1,
+            mouse_point: make_points(0, 0),
+            on_result: None,
+        }
+    }
+
+    /// Internal function that closes the dialog in response to a button or key, and triggers the
+    /// `on_result` callback.  Button `0` accepts the dialog, and any other button cancels it.
+    fn finish(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer], button: usize) {
+        let result = match (self.kind, button) {
+            (DialogKind::Message, _) | (DialogKind::Confirm, 0) => DialogOk,
+            (DialogKind::Prompt, 0) => DialogInput(self.input_field.get_text()),
+            _ => DialogCancel,
+        };
+
+        self.close();
+
+        if let Some(mut cb) = self.on_result.take() {
+            cb(self, widgets, layouts, result);
+            self.on_result = Some(cb);
+        }
+    }
+
+    /// Internal function that returns the area of `button`, relative to the dialog.  The first
+    /// button is placed in the lower right-hand corner, and the rest to its left.
+    fn get_button_area(&self, button: usize) -> Rect {
+        let bounds = self.config.get_size(CONFIG_SIZE);
+
+        Rect::new(
+            bounds[SIZE_WIDTH] as i32 - ((BUTTON_WIDTH + 10) * (button as u32 + 1)) as i32,
+            bounds[SIZE_HEIGHT] as i32 - BUTTON_HEIGHT as i32 - 12,
+            BUTTON_WIDTH,
+            BUTTON_HEIGHT,
+        )
+    }
+
+    /// Internal function that returns the area of the text entry box of a prompt dialog, relative
+    /// to the dialog.
+    fn get_input_area(&self) -> Rect {
+        let bounds = self.config.get_size(CONFIG_SIZE);
+
+        Rect::new(
+            12,
+            bounds[SIZE_HEIGHT] as i32 - BUTTON_HEIGHT as i32 * 2 - 24,
+            bounds[SIZE_WIDTH].saturating_sub(24).max(1),
+            BUTTON_HEIGHT,
+        )
+    }
+
+    /// Internal function that moves the text entry box of a prompt dialog to its area in the
+    /// dialog, so that clicks in it are placed on the right characters.
+    fn place_input_field(&mut self) {
+        let origin = self.config.get_point(CONFIG_ORIGIN);
+        let input_area = self.get_input_area();
+
+        self.input_field.get_config().set_point(
+            CONFIG_ORIGIN,
+            origin[POINT_X] + input_area.x(),
+            origin[POINT_Y] + input_area.y(),
+        );
+        self.input_field.get_config().set_size(
+            CONFIG_SIZE,
+            input_area.width(),
+            input_area.height(),
+        );
+        self.input_field.get_config().set_invalidated(true);
+    }
+
+    /// Internal function that redraws the dialog when an event changed its text entry box.
+    fn refresh_input_field(&mut self) {
+        if self.input_field.is_invalidated() {
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Internal function that returns the button under the local coordinates `x, y`, or `-1` if
+    /// there is no button there.
+    fn get_button_at(&self, x: i32, y: i32) -> i32 {
+        (0..self.button_labels.len())
+            .find(|button| self.get_button_area(*button).contains_point((x, y)))
+            .map_or(-1, |button| button as i32)
+    }
+}
+
+/// Draws `text` into `area` of the `Canvas`, centered if `centered` is `true`, or left justified
+/// otherwise.  Returns the width of the text that was drawn.
+fn draw_text(
+    c: &mut Canvas<Window>,
+    t: &mut TextureCache,
+    font: (&str, i32, FontStyle),
+    text: &str,
+    color: Color,
+    area: Rect,
+    centered: bool,
+) -> u32 {
+    if text.is_empty() {
+        return 0;
+    }
+
+    let (font_texture, width, height) = t.render_text(
+        c,
+        String::from(font.0),
+        font.1 as u16,
+        font.2,
+        String::from(text),
+        color,
+        area.width(),
+    );
+    let width = width.min(area.width());
+    let height = height.min(area.height());
+    let text_x = if centered {
+        (area.width() - width) as i32 / 2
+    } else {
+        0
+    };
+
+    c.copy(
+        &font_texture,
+        Rect::new(0, 0, width, height),
+        Rect::new(
+            area.x() + text_x,
+            if centered {
+                area.y() + (area.height() - height) as i32 / 2
+            } else {
+                area.y()
+            },
+            width,
+            height,
+        ),
+    )
+    .unwrap();
+
+    width
+}
+
+/// This is the `Widget` implementation of the `DialogWidget`.
+impl Widget for DialogWidget {
+    /// Draws the title bar, message, text entry box and buttons of the dialog.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            let base_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let title_color = self.get_config().get_color(CONFIG_COLOR_SECONDARY);
+            let text_color = self.get_config().get_color(CONFIG_COLOR_TEXT);
+            let font_name = self.font_name.clone();
+            let font_size = self.font_size;
+            let kind = self.kind;
+            let title = &self.title;
+            let message = &self.message;
+            let input_area = self.get_input_area();
+            let message_bottom = if kind == DialogKind::Prompt {
+                input_area.y() - 8
+            } else {
+                self.get_button_area(0).y() - 8
+            };
+            let buttons: Vec<(Rect, &String, bool)> = self
+                .button_labels
+                .iter()
+                .enumerate()
+                .map(|(i, label)| {
+                    (
+                        self.get_button_area(i),
+                        label,
+                        self.pressed_button == i as i32 && self.hovered_button == i as i32,
+                    )
+                })
+                .collect();
+            let input_texture = if kind == DialogKind::Prompt {
+                self.input_field.draw(c, t)
+            } else {
+                None
+            };
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                texture.set_draw_color(title_color);
+                texture
+                    .fill_rect(Rect::new(0, 0, bounds[0], TITLE_HEIGHT))
+                    .unwrap();
+
+                draw_text(
+                    texture,
+                    t,
+                    (&font_name, font_size, FontStyle::BOLD),
+                    title,
+                    Color::RGB(255, 255, 255),
+                    Rect::new(12, 0, bounds[0].saturating_sub(24).max(1), TITLE_HEIGHT),
+                    true,
+                );
+
+                if kind != DialogKind::Custom {
+                    draw_text(
+                        texture,
+                        t,
+                        (&font_name, font_size, FontStyle::NORMAL),
+                        message,
+                        text_color,
+                        Rect::new(
+                            12,
+                            TITLE_HEIGHT as i32 + 12,
+                            bounds[0].saturating_sub(24).max(1),
+                            (message_bottom - TITLE_HEIGHT as i32 - 12).max(1) as u32,
+                        ),
+                        false,
+                    );
+                }
+
+                if let Some(input_texture) = input_texture {
+                    texture.copy(input_texture, None, input_area).unwrap();
+                }
+
+                for (button_area, label, pressed) in &buttons {
+                    let (fill_color, label_color) = if *pressed {
+                        (Color::RGB(0, 0, 0), Color::RGB(255, 255, 255))
+                    } else {
+                        (Color::RGB(255, 255, 255), Color::RGB(0, 0, 0))
+                    };
+
+                    texture.set_draw_color(fill_color);
+                    texture.fill_rect(*button_area).unwrap();
+                    texture.set_draw_color(border_color);
+                    texture.draw_rect(*button_area).unwrap();
+                    texture
+                        .draw_rect(Rect::new(
+                            button_area.x() + 1,
+                            button_area.y() + 1,
+                            button_area.width() - 2,
+                            button_area.height() - 2,
+                        ))
+                        .unwrap();
+
+                    draw_text(
+                        texture,
+                        t,
+                        (&font_name, font_size, FontStyle::NORMAL),
+                        label,
+                        label_color,
+                        *button_area,
+                        true,
+                    );
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, bounds[0], bounds[1]))
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Returns the area below the title bar, in which child `Widget`s are drawn.
+    fn get_client_area(&mut self) -> Rect {
+        let area = self.get_drawing_area();
+
+        Rect::new(
+            area.x(),
+            area.y() + TITLE_HEIGHT as i32,
+            area.width(),
+            area.height().saturating_sub(TITLE_HEIGHT).max(1),
+        )
+    }
+
+    /// Tracks the button under the mouse.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = _points[POINT_X] - origin[POINT_X];
+        let y = _points[POINT_Y] - origin[POINT_Y];
+        let hovered_button = self.get_button_at(x, y);
+
+        self.mouse_point = make_points(x, y);
+
+        if self.kind == DialogKind::Prompt {
+            self.input_field
+                .mouse_moved(_widgets, _layouts, _points.clone());
+            self.refresh_input_field();
+        }
+
+        if hovered_button != self.hovered_button {
+            self.hovered_button = hovered_button;
+
+            if self.pressed_button >= 0 {
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// When a mouse exits the bounds of the `Widget`, this function is triggered.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.hovered_button = -1;
+        self.get_config().set_invalidated(true);
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Presses a button when the mouse is pressed over it, and closes the dialog when the mouse is
+    /// released over the same button.  Clicks in the text entry box of a prompt dialog position
+    /// its cursor.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if self.kind == DialogKind::Prompt
+            && (!_state
+                || self
+                    .get_input_area()
+                    .contains_point((self.mouse_point[POINT_X], self.mouse_point[POINT_Y])))
+        {
+            self.input_field
+                .button_clicked(_widgets, _layouts, _button, _clicks, _state);
+            self.refresh_input_field();
+        }
+
+        if _button == 1 {
+            if _state {
+                self.pressed_button =
+                    self.get_button_at(self.mouse_point[POINT_X], self.mouse_point[POINT_Y]);
+                self.get_config().set_invalidated(true);
+            } else if self.pressed_button >= 0 {
+                let pressed_button = self.pressed_button;
+
+                self.pressed_button = -1;
+                self.get_config().set_invalidated(true);
+
+                if pressed_button == self.hovered_button {
+                    self.finish(_widgets, _layouts, pressed_button as usize);
+                }
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Accepts the dialog with `Return`, and cancels it with `Escape`.  Other keys edit the text of
+    /// a prompt dialog.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        match (self.kind, _keycode) {
+            (DialogKind::Custom, _) => (),
+            (_, Keycode::Return) | (_, Keycode::KpEnter) => self.finish(_widgets, _layouts, 0),
+            (_, Keycode::Escape) => self.finish(_widgets, _layouts, self.button_labels.len() - 1),
+            (DialogKind::Prompt, _) => {
+                self.input_field
+                    .key_pressed(_widgets, _layouts, _keycode, _keymod);
+                self.refresh_input_field();
+            }
+            _ => (),
+        }
+
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Adds typed text to the text entry box of a prompt dialog.
+    fn text_input(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _text: String,
+    ) {
+        if self.kind == DialogKind::Prompt {
+            self.input_field
+                .text_input(_widgets, _layouts, _text.clone());
+            self.refresh_input_field();
+        }
+
+        self.text_input_callback(_widgets, _layouts, _text);
+    }
+
+    /// Shows the cursor of the text entry box of a prompt dialog while the dialog has focus.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        self.input_field.get_config().set_focused(_focused);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Moves the text entry box of a prompt dialog along with the dialog.
+    fn on_config_changed(&mut self, _k: u8, _v: Config) {
+        if let CONFIG_ORIGIN | CONFIG_SIZE = _k {
+            self.place_input_field();
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
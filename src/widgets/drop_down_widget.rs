@@ -0,0 +1,737 @@
+// Pushrod Widget Library
+// Drop Down Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+use crate::widgets::scroll_bar::ScrollBarOrientation::ScrollBarVertical;
+use crate::widgets::scroll_bar::{ScrollBar, SCROLL_BAR_THICKNESS};
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::ttf::FontStyle;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the callback type that is used when an `on_selected` callback is triggered from this
+/// `Widget`.  It receives the index of the selected item.
+pub type OnDropDownSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut DropDownWidget, &[WidgetContainer], &[LayoutContainer], i32)>>;
+
+/// This is the storage object for the `DropDownWidget`.  It stores the config, properties, callback
+/// registry, the items, the selected item, and the state of the popup list.
+pub struct DropDownWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    overlay_store: TextureStore,
+    items: Vec<String>,
+    selected_item: i32,
+    highlighted_item: i32,
+    font_name: String,
+    font_size: i32,
+    row_height: u32,
+    max_visible_items: usize,
+    popup_area: Option<Rect>,
+    popup_invalidated: bool,
+    scroll_bar: ScrollBar,
+    mouse_point: Points,
+    in_bounds: bool,
+    on_selected: OnDropDownSelectedCallbackType,
+}
+
+/// This is the implementation of the `DropDownWidget`, a control that shows the currently selected
+/// item from a list of values.  Clicking on it opens a popup list as an overlay, which is drawn
+/// above all other `Widget`s.  Clicking on an item in the popup selects it and closes the popup;
+/// clicking outside of the popup, or pressing `Escape`, closes it without changing the selection.
+///
+/// When it has focus, `Up` and `Down` change the selected item while the popup is closed, and
+/// `Space`, `Return` or `Alt-Down` open it.  While the popup is open, `Up`, `Down`, `Home`, `End`,
+/// `PageUp` and `PageDown` move the highlight, and `Return` selects the highlighted item.
+impl DropDownWidget {
+    /// Creates a new `DropDownWidget` given the `x, y, w, h` coordinates.  Items are drawn using
+    /// `assets/OpenSans-Regular.ttf` at size 14, and the popup shows up to 8 items at a time.
+    pub fn new(points: Points, size: Size) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            overlay_store: TextureStore::default(),
+            items: vec![],
+            selected_item: -1,
+            highlighted_item: -1,
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size: 14,
+            row_height: 24,
+            max_visible_items: 8,
+            popup_area: None,
+            popup_invalidated: false,
+            scroll_bar: ScrollBar::new(ScrollBarVertical),
+            mouse_point: make_points(0, 0),
+            in_bounds: false,
+            on_selected: None,
+        }
+    }
+
+    /// Adds an item to the end of the list.  Returns the index of the item.
+    pub fn add_item(&mut self, item: String) -> usize {
+        self.items.push(item);
+        self.popup_invalidated = true;
+
+        self.items.len() - 1
+    }
+
+    /// Replaces all of the items in the list with `items`, clearing the selection.
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.close_popup();
+        self.items = items;
+        self.selected_item = -1;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the items in the list.
+    pub fn get_items(&self) -> &[String] {
+        &self.items
+    }
+
+    /// Returns the index of the selected item, or `-1` if no item is selected.
+    pub fn get_selected(&self) -> i32 {
+        self.selected_item
+    }
+
+    /// Returns the text of the selected item, or `None` if no item is selected.
+    pub fn get_selected_item(&self) -> Option<&String> {
+        if self.selected_item >= 0 {
+            self.items.get(self.selected_item as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Selects the item at `index`, or clears the selection if `index` is `-1` or out of range.
+    /// This does not trigger the `on_selected` callback.
+    pub fn set_selected(&mut self, index: i32) {
+        self.selected_item = if index >= 0 && (index as usize) < self.items.len() {
+            index
+        } else {
+            -1
+        };
+        self.popup_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the maximum number of items shown in the popup at a time.  If there are more items, the
+    /// popup can be scrolled.
+    pub fn set_max_visible_items(&mut self, max_visible_items: usize) {
+        self.max_visible_items = max_visible_items.max(1);
+    }
+
+    /// Sets the height of each item in the popup, in pixels.
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.row_height = row_height.max(1);
+        self.popup_invalidated = true;
+    }
+
+    /// Sets the font used to draw the items, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.font_name = font_name;
+        self.font_size = font_size;
+        self.popup_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Indicates whether or not the popup list is open.
+    pub fn is_open(&self) -> bool {
+        self.popup_area.is_some()
+    }
+
+    /// Assigns the callback closure that will be used when an item is selected from the popup, or
+    /// with the keyboard.
+    pub fn on_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut DropDownWidget, &[WidgetContainer], &[LayoutContainer], i32) + 'static,
+    {
+        self.on_selected = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_selected` callback.
+    fn call_selected_callback(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+        let selected_item = self.selected_item;
+
+        if let Some(mut cb) = self.on_selected.take() {
+            cb(self, widgets, layouts, selected_item);
+            self.on_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that opens the popup list below the `Widget`, or above it if there is not
+    /// enough room below it in the `Window`.  The size of the `Window` is taken from the top-level
+    /// `Widget`.
+    fn open_popup(&mut self, widgets: &[WidgetContainer]) {
+        if self.items.is_empty() {
+            return;
+        }
+
+        let area = self.get_drawing_area();
+        let window_height = widgets[0]
+            .widget
+            .borrow_mut()
+            .get_config()
+            .get_size(CONFIG_SIZE)[SIZE_HEIGHT] as i32;
+        let visible_items = self.items.len().min(self.max_visible_items) as u32;
+        let popup_height = visible_items * self.row_height + 2;
+        let popup_y = if area.bottom() + popup_height as i32 > window_height
+            && area.y() - popup_height as i32 >= 0
+        {
+            area.y() - popup_height as i32
+        } else {
+            area.bottom()
+        };
+        let popup_area = Rect::new(area.x(), popup_y, area.width(), popup_height);
+
+        self.scroll_bar
+            .set_lengths(self.items.len() as u32 * self.row_height, popup_height - 2);
+        self.popup_area = Some(popup_area);
+        self.highlighted_item = self.selected_item;
+        self.popup_invalidated = true;
+        self.scroll_to_item(self.selected_item);
+        self.get_config().set_toggle(CONFIG_SCROLLABLE, true);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Internal function that closes the popup list.
+    fn close_popup(&mut self) {
+        if self.popup_area.is_some() {
+            self.popup_area = None;
+            self.highlighted_item = -1;
+            self.scroll_bar.button_up();
+            self.get_config().set_toggle(CONFIG_SCROLLABLE, false);
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Internal function that selects `item`, closes the popup, and triggers the `on_selected`
+    /// callback.
+    fn select_item(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer], item: i32) {
+        self.close_popup();
+
+        if item != self.selected_item {
+            self.selected_item = item;
+            self.get_config().set_invalidated(true);
+            self.call_selected_callback(widgets, layouts);
+        }
+    }
+
+    /// Internal function that scrolls the popup list so that `item` is fully visible.
+    fn scroll_to_item(&mut self, item: i32) {
+        if item < 0 {
+            return;
+        }
+
+        let item_top = item * self.row_height as i32;
+        let item_bottom = item_top + self.row_height as i32;
+        let viewport_height = self.scroll_bar.get_viewport_length() as i32;
+        let position = self.scroll_bar.get_position() as i32;
+
+        if item_top < position {
+            self.scroll_bar.set_position(item_top);
+        } else if item_bottom > position + viewport_height {
+            self.scroll_bar.set_position(item_bottom - viewport_height);
+        }
+
+        self.popup_invalidated = true;
+    }
+
+    /// Internal function that returns the track area of the popup's scroll bar, relative to the
+    /// popup, if it is shown.
+    fn get_track_area(&self) -> Option<Rect> {
+        match self.popup_area {
+            Some(popup_area) if self.scroll_bar.is_needed() => Some(Rect::new(
+                popup_area.width().saturating_sub(SCROLL_BAR_THICKNESS + 1) as i32,
+                1,
+                SCROLL_BAR_THICKNESS,
+                popup_area.height().saturating_sub(2).max(1),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Internal function that returns the index of the item in the popup under the window
+    /// coordinates `x, y`, or `-1` if there is no item there.
+    fn get_item_at(&self, x: i32, y: i32) -> i32 {
+        match self.popup_area {
+            Some(popup_area) => {
+                let local_y = y - popup_area.y() - 1;
+                let in_track = self
+                    .get_track_area()
+                    .is_some_and(|track| x - popup_area.x() >= track.x());
+
+                if !popup_area.contains_point((x, y)) || in_track || local_y < 0 {
+                    return -1;
+                }
+
+                let item =
+                    (local_y + self.scroll_bar.get_position() as i32) / self.row_height as i32;
+
+                if item < self.items.len() as i32 {
+                    item
+                } else {
+                    -1
+                }
+            }
+            None => -1,
+        }
+    }
+
+    /// Internal function that handles a press of the left mouse button: the popup is opened when
+    /// the `Widget` is clicked, closed when the mouse is pressed outside of it, and items or the
+    /// scroll bar are clicked when the mouse is pressed inside of it.
+    fn left_button_pressed(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+        match self.popup_area {
+            Some(popup_area) => {
+                let mouse_point = self.mouse_point.clone();
+
+                if !popup_area.contains_point((mouse_point[POINT_X], mouse_point[POINT_Y])) {
+                    self.close_popup();
+                    return;
+                }
+
+                if let Some(track) = self.get_track_area() {
+                    if self.scroll_bar.button_down(
+                        track,
+                        mouse_point[POINT_X] - popup_area.x(),
+                        mouse_point[POINT_Y] - popup_area.y(),
+                    ) {
+                        self.popup_invalidated = true;
+                        return;
+                    }
+                }
+
+                let item = self.get_item_at(mouse_point[POINT_X], mouse_point[POINT_Y]);
+
+                if item >= 0 {
+                    self.select_item(widgets, layouts, item);
+                }
+            }
+            None => {
+                if self.in_bounds {
+                    self.open_popup(widgets);
+                }
+            }
+        }
+    }
+
+    /// Internal function that handles keyboard selection, and navigation of the popup list.
+    fn navigate(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        keycode: Keycode,
+        keymod: Mod,
+    ) {
+        let item_count = self.items.len() as i32;
+
+        if item_count == 0 {
+            return;
+        }
+
+        if self.is_open() {
+            let page_size = self.max_visible_items as i32;
+            let highlighted_item = self.highlighted_item;
+            let target = match keycode {
+                Keycode::Escape => {
+                    self.close_popup();
+                    return;
+                }
+                Keycode::Return | Keycode::KpEnter | Keycode::Space => {
+                    if highlighted_item >= 0 {
+                        self.select_item(widgets, layouts, highlighted_item);
+                    } else {
+                        self.close_popup();
+                    }
+
+                    return;
+                }
+                Keycode::Up => highlighted_item - 1,
+                Keycode::Down => highlighted_item + 1,
+                Keycode::Home => 0,
+                Keycode::End => item_count - 1,
+                Keycode::PageUp => highlighted_item - page_size,
+                Keycode::PageDown => highlighted_item + page_size,
+                _ => return,
+            }
+            .max(0)
+            .min(item_count - 1);
+
+            self.highlighted_item = target;
+            self.scroll_to_item(target);
+        } else {
+            let alt = keymod.intersects(Mod::LALTMOD | Mod::RALTMOD);
+
+            match keycode {
+                Keycode::Down if alt => self.open_popup(widgets),
+                Keycode::Space | Keycode::Return | Keycode::KpEnter => self.open_popup(widgets),
+                Keycode::Up if self.selected_item > 0 => {
+                    self.select_item(widgets, layouts, self.selected_item - 1)
+                }
+                Keycode::Down if self.selected_item < item_count - 1 => {
+                    self.select_item(widgets, layouts, self.selected_item + 1)
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Draws `text` into `area` of the `Canvas`, left justified and vertically centered.
+fn draw_item_text(
+    c: &mut Canvas<Window>,
+    t: &mut TextureCache,
+    font: (&str, i32),
+    text: &str,
+    color: Color,
+    area: Rect,
+) {
+    let text_width = area.width().saturating_sub(12);
+
+    if text.is_empty() || text_width == 0 {
+        return;
+    }
+
+    let (font_texture, width, height) = t.render_text(
+        c,
+        String::from(font.0),
+        font.1 as u16,
+        FontStyle::NORMAL,
+        String::from(text),
+        color,
+        text_width,
+    );
+    let width = width.min(text_width);
+    let height = height.min(area.height());
+
+    c.copy(
+        &font_texture,
+        Rect::new(0, 0, width, height),
+        Rect::new(
+            area.x() + 6,
+            area.y() + (area.height() as i32 - height as i32) / 2,
+            width,
+            height,
+        ),
+    )
+    .unwrap();
+}
+
+/// This is the `Widget` implementation of the `DropDownWidget`.
+impl Widget for DropDownWidget {
+    /// Draws the selected item, and the arrow that opens the popup.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            let base_color = if self.in_bounds || self.is_open() {
+                self.get_config().get_color(CONFIG_COLOR_HOVER)
+            } else {
+                self.get_config().get_color(CONFIG_COLOR_BASE)
+            };
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let text_color = self.get_config().get_color(CONFIG_COLOR_TEXT);
+            let focused = self.get_config().is_focused();
+            let arrow_x = bounds[SIZE_WIDTH] as i32 - 14;
+            let arrow_y = bounds[SIZE_HEIGHT] as i32 / 2;
+            let text = self.get_selected_item().cloned().unwrap_or_default();
+            let font_name = self.font_name.clone();
+            let font_size = self.font_size;
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                draw_item_text(
+                    texture,
+                    t,
+                    (&font_name, font_size),
+                    &text,
+                    text_color,
+                    Rect::new(0, 0, (arrow_x - 8).max(1) as u32, bounds[SIZE_HEIGHT]),
+                );
+
+                texture.set_draw_color(text_color);
+
+                for k in 0..5 {
+                    texture
+                        .draw_line(
+                            Point::new(arrow_x - 4 + k, arrow_y - 2 + k),
+                            Point::new(arrow_x + 4 - k, arrow_y - 2 + k),
+                        )
+                        .unwrap();
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, bounds[0], bounds[1]))
+                    .unwrap();
+
+                if focused {
+                    texture
+                        .draw_rect(Rect::new(
+                            2,
+                            2,
+                            bounds[0].saturating_sub(4).max(1),
+                            bounds[1].saturating_sub(4).max(1),
+                        ))
+                        .unwrap();
+                }
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Returns the area of the popup list, if it is open.
+    fn get_overlay_area(&mut self) -> Option<Rect> {
+        self.popup_area
+    }
+
+    /// Draws the visible items of the popup list.
+    fn draw_overlay(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        let popup_area = self.popup_area?;
+
+        if self.popup_invalidated {
+            self.overlay_store
+                .create_or_resize_texture(c, popup_area.width(), popup_area.height());
+
+            let base_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let hover_color = self.get_config().get_color(CONFIG_COLOR_HOVER);
+            let selected_color = self.get_config().get_color(CONFIG_COLOR_SELECTED);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let thumb_color = self.get_config().get_color(CONFIG_COLOR_SECONDARY);
+            let track = self.get_track_area();
+            let row_height = self.row_height;
+            let row_width = match track {
+                Some(track) => track.x() as u32 - 1,
+                None => popup_area.width().saturating_sub(2),
+            }
+            .max(1);
+            let viewport_height = popup_area.height().saturating_sub(2).max(1);
+            let position = self.scroll_bar.get_position();
+            let first_item = (position / row_height) as usize;
+            let last_item =
+                (((position + viewport_height) / row_height) as usize + 1).min(self.items.len());
+            let font_name = self.font_name.clone();
+            let font_size = self.font_size;
+            let items = &self.items;
+            let selected_item = self.selected_item;
+            let highlighted_item = self.highlighted_item;
+            let scroll_bar = &self.scroll_bar;
+
+            c.with_texture_canvas(self.overlay_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+                texture.set_clip_rect(Rect::new(1, 1, row_width, viewport_height));
+
+                for (i, item) in items.iter().enumerate().take(last_item).skip(first_item) {
+                    let row_area = Rect::new(
+                        1,
+                        1 + (i as u32 * row_height) as i32 - position as i32,
+                        row_width,
+                        row_height,
+                    );
+                    let (color, text_color) = if highlighted_item == i as i32 {
+                        (hover_color, Color::RGB(0, 0, 0))
+                    } else if selected_item == i as i32 {
+                        (selected_color, Color::RGB(255, 255, 255))
+                    } else {
+                        (base_color, Color::RGB(0, 0, 0))
+                    };
+
+                    texture.set_draw_color(color);
+                    texture.fill_rect(row_area).unwrap();
+
+                    draw_item_text(
+                        texture,
+                        t,
+                        (&font_name, font_size),
+                        item,
+                        text_color,
+                        row_area,
+                    );
+                }
+
+                texture.set_clip_rect(None);
+
+                if let Some(track) = track {
+                    scroll_bar.draw(texture, track, base_color, thumb_color);
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, popup_area.width(), popup_area.height()))
+                    .unwrap();
+            })
+            .unwrap();
+
+            self.popup_invalidated = false;
+        }
+
+        self.overlay_store.get_optional_ref()
+    }
+
+    /// Closes the popup list when the mouse is clicked outside of it.
+    fn dismiss_overlay(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.close_popup();
+    }
+
+    /// When a mouse enters the bounds of the `Widget`, this function is triggered.
+    fn mouse_entered(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = true;
+        self.get_config().set_invalidated(true);
+        self.mouse_entered_callback(_widgets, _layouts);
+    }
+
+    /// When a mouse exits the bounds of the `Widget`, this function is triggered.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = false;
+
+        if self.highlighted_item != -1 && self.is_open() {
+            self.popup_invalidated = true;
+        }
+
+        self.get_config().set_invalidated(true);
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Highlights the item in the popup under the mouse, or drags the popup's scroll bar thumb.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        self.mouse_point = _points.clone();
+
+        if let (Some(popup_area), Some(track)) = (self.popup_area, self.get_track_area()) {
+            if self.scroll_bar.mouse_moved(
+                track,
+                _points[POINT_X] - popup_area.x(),
+                _points[POINT_Y] - popup_area.y(),
+            ) {
+                self.popup_invalidated = true;
+            }
+        }
+
+        if self.is_open() {
+            let item = self.get_item_at(_points[POINT_X], _points[POINT_Y]);
+
+            if item >= 0 && item != self.highlighted_item {
+                self.highlighted_item = item;
+                self.popup_invalidated = true;
+            }
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Scrolls the popup list, if it is open.
+    fn mouse_scrolled(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        if self.is_open()
+            && self
+                .scroll_bar
+                .scroll_by(-_points[POINT_Y] * self.row_height as i32)
+        {
+            self.popup_invalidated = true;
+        }
+
+        self.mouse_scrolled_callback(_widgets, _layouts, _points);
+    }
+
+    /// Opens or closes the popup when the `Widget` is clicked, and selects items from the popup.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 && !_state {
+            self.scroll_bar.button_up();
+        } else if _button == 1 {
+            self.left_button_pressed(_widgets, _layouts);
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles keyboard selection, and navigation of the popup list.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        self.navigate(_widgets, _layouts, _keycode, _keymod);
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Closes the popup when the `Widget` loses focus, and redraws the focus indicator.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        if !_focused {
+            self.close_popup();
+        }
+
+        self.get_config().set_invalidated(true);
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
@@ -18,51 +18,307 @@ use crate::render::widget::*;
 use crate::render::widget_cache::WidgetContainer;
 use crate::render::widget_config::*;
 
-use sdl2::render::{Canvas, Texture, TextureQuery};
+use sdl2::render::{Canvas, Texture};
 use sdl2::video::Window;
 
 use crate::render::canvas_helper::CanvasHelper;
 use crate::render::layout_cache::LayoutContainer;
+use crate::render::modifiers::mod_state;
 use crate::render::texture_cache::TextureCache;
 use crate::render::texture_store::TextureStore;
-use crate::render::{Points, Size, POINT_Y, SIZE_WIDTH};
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+use crate::widgets::list_widget::ListSelectionMode::{MultipleSelection, SingleSelection};
+use crate::widgets::scroll_bar::ScrollBarOrientation::ScrollBarVertical;
+use crate::widgets::scroll_bar::{ScrollBar, SCROLL_BAR_THICKNESS};
+use sdl2::keyboard::{Keycode, Mod};
 use sdl2::pixels::Color;
 use sdl2::rect::Rect;
 use std::any::Any;
 use std::collections::HashMap;
-use std::path::Path;
 
 /// This is the callback type that is used when an `on_selected` callback is triggered from this
 /// `Widget`.
 pub type OnSelectedCallbackType =
     Option<Box<dyn FnMut(&mut ListWidget, &[WidgetContainer], &[LayoutContainer], i32)>>;
 
-/// This is the storage object for the `ListWidget`.  It stores the config, properties, callback registry.
+/// These are the selection modes that a `ListWidget` supports.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ListSelectionMode {
+    /// Only one item can be selected at a time.
+    SingleSelection,
+
+    /// Any number of items can be selected.  `Ctrl`-click toggles the selection of a single item,
+    /// and `Shift`-click selects a range of items.
+    MultipleSelection,
+}
+
+/// This is the storage object for a `ListSelection`, which tracks the selected items, the cursor,
+/// and the anchor of a range selection for a list of items.  It is shared by the list `Widget`s, so
+/// that they select and navigate items in the same way.  A cursor or anchor of `-1` indicates that
+/// it is not set.
+#[derive(Clone, Debug)]
+pub struct ListSelection {
+    selection_mode: ListSelectionMode,
+    selected_items: Vec<usize>,
+    cursor_item: i32,
+    anchor_item: i32,
+}
+
+/// This is the implementation of the `ListSelection`.
+impl ListSelection {
+    /// Creates a new, empty `ListSelection` using the specified `selection_mode`.
+    pub fn new(selection_mode: ListSelectionMode) -> Self {
+        Self {
+            selection_mode,
+            selected_items: vec![],
+            cursor_item: -1,
+            anchor_item: -1,
+        }
+    }
+
+    /// Returns the selection mode.
+    pub fn get_selection_mode(&self) -> ListSelectionMode {
+        self.selection_mode
+    }
+
+    /// Sets the selection mode.  Switching to `SingleSelection` mode keeps only the item at the
+    /// cursor selected.
+    pub fn set_selection_mode(&mut self, selection_mode: ListSelectionMode) {
+        self.selection_mode = selection_mode;
+
+        if selection_mode == SingleSelection && self.selected_items.len() > 1 {
+            let cursor_item = self.cursor_item;
+
+            self.selected_items.retain(|x| *x as i32 == cursor_item);
+        }
+    }
+
+    /// Returns the indexes of the selected items, in ascending order.
+    pub fn get_selected(&self) -> Vec<usize> {
+        self.selected_items.clone()
+    }
+
+    /// Indicates whether or not the item at `item` is selected.
+    pub fn is_selected(&self, item: usize) -> bool {
+        self.selected_items.binary_search(&item).is_ok()
+    }
+
+    /// Sets the selected items by their indexes.  Indexes that are not less than `item_count` are
+    /// ignored.  In `SingleSelection` mode, only the last index is selected.  The cursor is moved to
+    /// the last index.
+    pub fn set_selected(&mut self, items: Vec<usize>, item_count: usize) {
+        let mut selected: Vec<usize> = items.into_iter().filter(|x| *x < item_count).collect();
+
+        if self.selection_mode == SingleSelection && selected.len() > 1 {
+            selected = selected.split_off(selected.len() - 1);
+        }
+
+        if let Some(last) = selected.last() {
+            self.cursor_item = *last as i32;
+            self.anchor_item = *last as i32;
+        }
+
+        selected.sort();
+        selected.dedup();
+
+        self.selected_items = selected;
+    }
+
+    /// Returns the index of the item at the cursor, or `-1` if the cursor is not set.
+    pub fn get_cursor(&self) -> i32 {
+        self.cursor_item
+    }
+
+    /// Deselects all items, leaving the cursor where it is.
+    pub fn deselect_all(&mut self) {
+        self.selected_items.clear();
+    }
+
+    /// Deselects all items, and clears the cursor and anchor.
+    pub fn clear(&mut self) {
+        self.selected_items.clear();
+        self.cursor_item = -1;
+        self.anchor_item = -1;
+    }
+
+    /// Selects `item` in response to a mouse click, given the state of the modifier keys.  In
+    /// `MultipleSelection` mode, `Shift` selects the range of items from the anchor to `item`,
+    /// and `Ctrl` toggles the selection of `item`.
+    pub fn click_item(&mut self, item: usize, keymod: Mod) {
+        let extend = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+        let toggle = keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD);
+
+        self.select_item(item as i32, extend, toggle);
+    }
+
+    /// Handles a navigation key for a list of `item_count` items, of which `page_size` items are
+    /// visible at a time.  `Up`, `Down`, `Home`, `End`, `PageUp` and `PageDown` move the cursor,
+    /// selecting the item it moves to.  In `MultipleSelection` mode, `Shift` extends the selection
+    /// from the anchor, `Ctrl` moves the cursor without changing the selection, and `Space`
+    /// toggles the item at the cursor.  Returns the item at the cursor, and whether or not the
+    /// selection changed, if the key was handled.  Returns `None` otherwise.
+    pub fn key_pressed(
+        &mut self,
+        keycode: Keycode,
+        keymod: Mod,
+        item_count: usize,
+        page_size: usize,
+    ) -> Option<(usize, bool)> {
+        if item_count == 0 {
+            return None;
+        }
+
+        let multiple = self.selection_mode == MultipleSelection;
+        let extend = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+        let keep = keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD) && multiple;
+        let cursor = self.cursor_item;
+        let page_size = page_size.max(1) as i32;
+        let target = match keycode {
+            Keycode::Up => (cursor - 1).max(0),
+            Keycode::Down => cursor + 1,
+            Keycode::Home => 0,
+            Keycode::End => item_count as i32 - 1,
+            Keycode::PageUp => (cursor - page_size).max(0),
+            Keycode::PageDown => cursor + page_size,
+            Keycode::Space if cursor >= 0 && multiple => {
+                self.select_item(cursor, false, true);
+                return Some((cursor as usize, true));
+            }
+            _ => return None,
+        }
+        .min(item_count as i32 - 1);
+
+        if keep {
+            self.cursor_item = target;
+            Some((target as usize, false))
+        } else {
+            self.select_item(target, extend, false);
+            Some((target as usize, true))
+        }
+    }
+
+    /// Adjusts the selection after `count` items have been inserted at `index`.
+    pub fn items_inserted(&mut self, index: usize, count: usize) {
+        self.shift_items(index, count as i32);
+    }
+
+    /// Adjusts the selection after `count` items have been removed, starting at `index`.  Removed
+    /// items are removed from the selection.
+    pub fn items_removed(&mut self, index: usize, count: usize) {
+        self.selected_items
+            .retain(|x| *x < index || *x >= index + count);
+
+        if self.cursor_item >= index as i32 && self.cursor_item < (index + count) as i32 {
+            self.cursor_item = index as i32 - 1;
+        }
+
+        if self.anchor_item >= index as i32 && self.anchor_item < (index + count) as i32 {
+            self.anchor_item = self.cursor_item;
+        }
+
+        self.shift_items(index + count, -(count as i32));
+    }
+
+    /// Removes any items that are not less than `item_count` from the selection, used when the
+    /// number of items shrinks.
+    pub fn truncate(&mut self, item_count: usize) {
+        self.selected_items.retain(|x| *x < item_count);
+        self.cursor_item = self.cursor_item.min(item_count as i32 - 1);
+        self.anchor_item = self.anchor_item.min(item_count as i32 - 1);
+    }
+
+    // Private functions
+
+    fn shift_items(&mut self, index: usize, amount: i32) {
+        for item in self.selected_items.iter_mut() {
+            if *item >= index {
+                *item = (*item as i32 + amount) as usize;
+            }
+        }
+
+        if self.cursor_item >= index as i32 {
+            self.cursor_item += amount;
+        }
+
+        if self.anchor_item >= index as i32 {
+            self.anchor_item += amount;
+        }
+    }
+
+    fn select_item(&mut self, item: i32, extend: bool, toggle: bool) {
+        let multiple = self.selection_mode == MultipleSelection;
+
+        if multiple && extend && self.anchor_item >= 0 {
+            let start = self.anchor_item.min(item) as usize;
+            let end = self.anchor_item.max(item) as usize;
+
+            self.selected_items = (start..=end).collect();
+        } else if multiple && toggle {
+            match self.selected_items.binary_search(&(item as usize)) {
+                Ok(position) => {
+                    self.selected_items.remove(position);
+                }
+                Err(position) => self.selected_items.insert(position, item as usize),
+            }
+
+            self.anchor_item = item;
+        } else {
+            self.selected_items = vec![item as usize];
+            self.anchor_item = item;
+        }
+
+        self.cursor_item = item;
+    }
+}
+
+/// This is the storage object for the `ListWidget`.  It stores the config, properties, callback registry,
+/// the list items, the selection, and the vertical `ScrollBar`.
 pub struct ListWidget {
     config: WidgetConfig,
     system_properties: HashMap<i32, String>,
     callback_registry: CallbackRegistry,
     texture_store: TextureStore,
     list_items: Vec<String>,
+    font_name: String,
+    font_size: i32,
+    row_height: u32,
     highlighted_item: i32,
-    selected_item: i32,
+    selection: ListSelection,
+    scroll_bar: ScrollBar,
+    mouse_point: Points,
     in_bounds: bool,
     on_selected: OnSelectedCallbackType,
 }
 
 /// This is the implementation of the `ListWidget`, a control that displays a list of items that can be
-/// selected.
+/// selected.  The list can be scrolled with the mouse wheel or the scroll bar, and navigated with
+/// the `Up`, `Down`, `Home`, `End`, `PageUp` and `PageDown` keys when it has focus.  In
+/// `MultipleSelection` mode, holding `Shift` while navigating extends the selection, holding `Ctrl`
+/// moves the cursor without changing the selection, and `Space` toggles the item at the cursor.
 impl ListWidget {
-    /// Creates a new `ListWidget` given the `x, y, w, h` coordinates.
+    /// Creates a new `ListWidget` given the `x, y, w, h` coordinates.  Rows are 30 pixels high, and
+    /// drawn using `assets/OpenSans-Regular.ttf` at size 16, in `SingleSelection` mode.
     pub fn new(points: Points, size: Size) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+        config.set_toggle(CONFIG_SCROLLABLE, true);
+        config.set_toggle(CONFIG_MOUSE_CAPTURE, true);
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
         Self {
-            config: WidgetConfig::new(points, size),
+            config,
             system_properties: HashMap::new(),
             callback_registry: CallbackRegistry::new(),
             texture_store: TextureStore::default(),
             list_items: vec![],
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size: 16,
+            row_height: 30,
             highlighted_item: -1,
-            selected_item: -1,
+            selection: ListSelection::new(SingleSelection),
+            scroll_bar: ScrollBar::new(ScrollBarVertical),
+            mouse_point: make_points(0, 0),
             in_bounds: false,
             on_selected: None,
         }
@@ -73,12 +329,121 @@ impl ListWidget {
         let item_size = self.list_items.len() + 1;
 
         self.list_items.push(item);
+        self.get_config().set_invalidated(true);
 
         item_size
     }
 
+    /// Inserts a text item into the `ListWidget` at `index`, moving all items after it down by one.
+    /// If `index` is past the end of the list, the item is added to the end.
+    pub fn insert_item(&mut self, index: usize, item: String) {
+        let index = index.min(self.list_items.len());
+
+        self.list_items.insert(index, item);
+        self.selection.items_inserted(index, 1);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Removes the item at `index` from the `ListWidget`, removing it from the selection if it was
+    /// selected.  Returns the removed item, or `None` if `index` is out of range.
+    pub fn remove_item(&mut self, index: usize) -> Option<String> {
+        if index >= self.list_items.len() {
+            return None;
+        }
+
+        let item = self.list_items.remove(index);
+
+        self.selection.items_removed(index, 1);
+        self.highlighted_item = -1;
+        self.get_config().set_invalidated(true);
+
+        Some(item)
+    }
+
+    /// Removes all of the items from the `ListWidget`, clearing the selection.
+    pub fn clear(&mut self) {
+        self.set_items(vec![]);
+    }
+
+    /// Replaces all of the items in the `ListWidget` with `items`, clearing the selection, and
+    /// scrolling back to the top of the list.
+    pub fn set_items(&mut self, items: Vec<String>) {
+        self.list_items = items;
+        self.selection.clear();
+        self.highlighted_item = -1;
+        self.scroll_bar.set_position(0);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the items in the `ListWidget`.
+    pub fn get_items(&self) -> &[String] {
+        &self.list_items
+    }
+
+    /// Returns the indexes of the selected items, in ascending order.
+    pub fn get_selected(&self) -> Vec<usize> {
+        self.selection.get_selected()
+    }
+
+    /// Sets the selected items by their indexes.  Indexes that are out of range are ignored.  In
+    /// `SingleSelection` mode, only the last index is selected.  This does not trigger the
+    /// `on_selected` callback.
+    pub fn set_selected(&mut self, items: Vec<usize>) {
+        self.selection.set_selected(items, self.list_items.len());
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the selection mode of the `ListWidget`.  Switching to `SingleSelection` mode keeps only
+    /// the item at the cursor selected.
+    pub fn set_selection_mode(&mut self, selection_mode: ListSelectionMode) {
+        self.selection.set_selection_mode(selection_mode);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the selection mode of the `ListWidget`.
+    pub fn get_selection_mode(&self) -> ListSelectionMode {
+        self.selection.get_selection_mode()
+    }
+
+    /// Sets the height of each row in the list, in pixels.
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.row_height = row_height.max(1);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the font used to draw the list items, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.font_name = font_name;
+        self.font_size = font_size;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Scrolls the list so that the item at `index` is fully visible.
+    pub fn scroll_to_item(&mut self, index: usize) {
+        self.update_scroll_bar();
+
+        let item_top = index as i32 * self.row_height as i32;
+        let item_bottom = item_top + self.row_height as i32;
+        let viewport_height = self.scroll_bar.get_viewport_length() as i32;
+        let position = self.scroll_bar.get_position() as i32;
+
+        let changed = if item_top < position {
+            self.scroll_bar.set_position(item_top)
+        } else if item_bottom > position + viewport_height {
+            self.scroll_bar.set_position(item_bottom - viewport_height)
+        } else {
+            false
+        };
+
+        if changed {
+            self.get_config().set_invalidated(true);
+        }
+    }
+
     /// Assigns the callback closure that will be used when the `Widget` changes value, based on a selected
-    /// item.
+    /// item.  In `MultipleSelection` mode, the item is the one that was last clicked or navigated
+    /// to: use `get_selected` to retrieve the full selection.
     pub fn on_selected<F>(&mut self, callback: F)
     where
         F: FnMut(&mut ListWidget, &[WidgetContainer], &[LayoutContainer], i32) + 'static,
@@ -89,92 +454,153 @@ impl ListWidget {
     /// Internal function that triggers the `on_selected` callback.  The selected item ID indicates the value
     /// in the `ListWidget` that has been selected.  If the value is set to `-1`, it means the list items
     /// have been de-selected.
-    fn call_selected_callback(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+    fn call_selected_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        selected_item: i32,
+    ) {
         if let Some(mut cb) = self.on_selected.take() {
-            cb(self, widgets, layouts, self.selected_item);
+            cb(self, widgets, layouts, selected_item);
             self.on_selected = Some(cb);
         }
     }
+
+    /// Internal function that updates the `ScrollBar` to match the current size of the `Widget` and
+    /// the number of items.  Returns the track area of the scroll bar relative to the `Widget`, if
+    /// it is shown.
+    fn update_scroll_bar(&mut self) -> Option<Rect> {
+        let bounds = self.get_config().get_size(CONFIG_SIZE);
+        let viewport_height = bounds[SIZE_HEIGHT].saturating_sub(2);
+
+        self.scroll_bar.set_lengths(
+            self.list_items.len() as u32 * self.row_height,
+            viewport_height,
+        );
+
+        if self.scroll_bar.is_needed() {
+            Some(Rect::new(
+                bounds[SIZE_WIDTH].saturating_sub(SCROLL_BAR_THICKNESS + 1) as i32,
+                1,
+                SCROLL_BAR_THICKNESS,
+                viewport_height.max(1),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Internal function that returns the number of rows that fit in the visible area of the list.
+    fn get_page_size(&self) -> usize {
+        (self.scroll_bar.get_viewport_length() / self.row_height).max(1) as usize
+    }
 }
 
 impl CanvasHelper for ListWidget {}
 
 /// This is the `Widget` implementation of the `ListWidget`.
 impl Widget for ListWidget {
-    /// Draws the `ListWidget` contents.
+    /// Draws the `ListWidget` contents.  Only the rows that are visible are drawn.
     fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
         if self.get_config().invalidated() {
             let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let track = self.update_scroll_bar();
 
             self.texture_store
-                .create_or_resize_texture(c, bounds[0] as u32, bounds[1] as u32);
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
 
             let base_color = self.get_color(CONFIG_COLOR_BASE);
             let hover_color = self.get_color(CONFIG_COLOR_HOVER);
             let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
-            let list_size = self.list_items.len();
-            let highlighted_item = self.highlighted_item;
-            let selected_item = self.selected_item;
-            let list_items = self.list_items.clone();
-
-            let ttf_context = t.get_ttf_context();
-            let texture_creator = c.texture_creator();
-            let mut font = ttf_context
-                .load_font(Path::new(&String::from("assets/OpenSans-Regular.ttf")), 16)
-                .unwrap();
+            let track_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let thumb_color = self.get_config().get_color(CONFIG_COLOR_SECONDARY);
+            let focused = self.get_config().is_focused();
+            let row_height = self.row_height;
+            let row_width = match track {
+                Some(track) => track.x() as u32 - 1,
+                None => bounds[SIZE_WIDTH].saturating_sub(2),
+            };
+            let position = self.scroll_bar.get_position();
+            let first_row = (position / row_height) as usize;
+            let last_row =
+                (((position + self.scroll_bar.get_viewport_length()) / row_height) as usize + 1)
+                    .min(self.list_items.len());
+            let mut rows = Vec::new();
+
+            for i in first_row..last_row {
+                let selected = self.selection.is_selected(i);
+                let color = if selected {
+                    Color::RGB(0, 0, 0)
+                } else if self.highlighted_item == i as i32 {
+                    hover_color
+                } else {
+                    Color::RGB(255, 255, 255)
+                };
+                let text_color = if selected {
+                    Color::RGB(255, 255, 255)
+                } else {
+                    Color::RGB(0, 0, 0)
+                };
+                let (font_texture, width, height) = t.render_text(
+                    c,
+                    self.font_name.clone(),
+                    self.font_size as u16,
+                    sdl2::ttf::FontStyle::NORMAL,
+                    self.list_items[i].clone(),
+                    text_color,
+                    row_width.max(1),
+                );
+                let row_y = 1 + (i as u32 * row_height) as i32 - position as i32;
+
+                rows.push((i, row_y, color, font_texture, width, height));
+            }
 
-            font.set_style(sdl2::ttf::FontStyle::NORMAL);
+            let cursor_item = self.selection.get_cursor();
+            let scroll_bar = &self.scroll_bar;
 
             c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
                 texture.set_draw_color(base_color);
                 texture.clear();
 
-                let list_height: u32 = 30;
-
-                for i in 0..list_size {
-                    let mut text_color = Color::RGB(0, 0, 0);
-                    let mut color = if highlighted_item == i as i32 {
-                        hover_color
-                    } else {
-                        Color::RGB(255, 255, 255)
-                    };
+                // Rows are clipped so that partially visible rows do not draw over the border.
+                texture.set_clip_rect(Rect::new(
+                    1,
+                    1,
+                    row_width.max(1),
+                    bounds[SIZE_HEIGHT].saturating_sub(2).max(1),
+                ));
 
-                    if selected_item == i as i32 {
-                        color = Color::RGB(0, 0, 0);
-                        text_color = Color::RGB(255, 255, 255);
-                    }
-
-                    texture.set_draw_color(color);
+                for (i, row_y, color, font_texture, width, height) in &rows {
+                    texture.set_draw_color(*color);
                     texture
-                        .fill_rect(Rect::new(
-                            0,
-                            (list_height * i as u32) as i32,
-                            bounds[SIZE_WIDTH],
-                            30,
-                        ))
+                        .fill_rect(Rect::new(1, *row_y, row_width.max(1), row_height))
                         .unwrap();
 
-                    let surface = font
-                        .render(&list_items[i].clone())
-                        .blended_wrapped(text_color, bounds[SIZE_WIDTH])
-                        .map_err(|e| e.to_string())
-                        .unwrap();
-                    let font_texture = texture_creator
-                        .create_texture_from_surface(&surface)
-                        .map_err(|e| e.to_string())
-                        .unwrap();
-
-                    let TextureQuery { width, height, .. } = font_texture.query();
-                    let texture_y = (list_height * i as u32) as i32 + 3;
-                    let texture_x = 10;
-
                     texture
                         .copy(
-                            &font_texture,
+                            font_texture,
                             None,
-                            Rect::new(texture_x, texture_y, width, height),
+                            Rect::new(
+                                10,
+                                *row_y + (row_height as i32 - *height as i32) / 2,
+                                *width,
+                                *height,
+                            ),
                         )
                         .unwrap();
+
+                    if focused && *i as i32 == cursor_item {
+                        texture.set_draw_color(hover_color);
+                        texture
+                            .draw_rect(Rect::new(1, *row_y, row_width.max(1), row_height))
+                            .unwrap();
+                    }
+                }
+
+                texture.set_clip_rect(None);
+
+                if let Some(track) = track {
+                    scroll_bar.draw(texture, track, track_color, thumb_color);
                 }
 
                 texture.set_draw_color(border_color);
@@ -191,6 +617,7 @@ impl Widget for ListWidget {
     /// When a mouse enters the bounds of the `Widget`, this function is triggered.
     fn mouse_entered(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
         self.in_bounds = true;
+        self.mouse_entered_callback(_widgets, _layouts);
     }
 
     /// When a mouse exits the bounds of the `Widget`, this function is triggered.
@@ -198,23 +625,38 @@ impl Widget for ListWidget {
         self.in_bounds = false;
         self.highlighted_item = -1;
         self.get_config().set_invalidated(true);
+        self.mouse_exited_callback(_widgets, _layouts);
     }
 
-    /// When a mouse is moved in the bounds of this `Widget`, this function is triggered.
+    /// When a mouse is moved in the bounds of this `Widget`, this function is triggered.  Highlights
+    /// the item under the mouse, or drags the scroll bar thumb.
     fn mouse_moved(
         &mut self,
         _widgets: &[WidgetContainer],
         _layouts: &[LayoutContainer],
         points: Points,
     ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = points[POINT_X] - origin[POINT_X];
+        let y = points[POINT_Y] - origin[POINT_Y];
+        let track = self.update_scroll_bar();
+
+        self.mouse_point = make_points(x, y);
+
+        if let Some(track) = track {
+            if self.scroll_bar.mouse_moved(track, x, y) {
+                self.get_config().set_invalidated(true);
+            }
+        }
+
         if self.in_bounds {
-            let position_y =
-                points[POINT_Y] - self.get_config().get_point(CONFIG_ORIGIN)[POINT_Y] as i32;
             let previous_highlighted_item = self.highlighted_item;
+            let in_track = track.is_some_and(|track| x >= track.x());
 
-            self.highlighted_item = position_y / 30;
+            self.highlighted_item =
+                (y - 1 + self.scroll_bar.get_position() as i32) / self.row_height as i32;
 
-            if self.highlighted_item >= self.list_items.len() as i32 {
+            if in_track || y < 1 || self.highlighted_item >= self.list_items.len() as i32 {
                 self.highlighted_item = -1;
             }
 
@@ -222,9 +664,32 @@ impl Widget for ListWidget {
                 self.get_config().set_invalidated(true);
             }
         }
+
+        self.mouse_moved_callback(_widgets, _layouts, points);
+    }
+
+    /// Scrolls the list by one row for each step of the mouse wheel.
+    fn mouse_scrolled(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        points: Points,
+    ) {
+        self.update_scroll_bar();
+
+        if self
+            .scroll_bar
+            .scroll_by(-points[POINT_Y] * self.row_height as i32)
+        {
+            self.highlighted_item = -1;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_scrolled_callback(_widgets, _layouts, points);
     }
 
-    /// Overrides the `button_clicked` callback to handle toggling.
+    /// Overrides the `button_clicked` callback to handle selection, and the scroll bar.  In
+    /// `MultipleSelection` mode, `Ctrl`-click toggles an item, and `Shift`-click selects a range.
     fn button_clicked(
         &mut self,
         _widgets: &[WidgetContainer],
@@ -233,15 +698,136 @@ impl Widget for ListWidget {
         _clicks: u8,
         state: bool,
     ) {
-        if button == 1 && state {
-            self.selected_item = self.highlighted_item;
+        if button == 1 && !state {
+            self.scroll_bar.button_up();
+        } else if button == 1 && self.in_bounds {
+            let track = self.update_scroll_bar();
+            let x = self.mouse_point[POINT_X];
+            let y = self.mouse_point[POINT_Y];
+
+            if track.is_some_and(|track| self.scroll_bar.button_down(track, x, y)) {
+                self.get_config().set_invalidated(true);
+            } else {
+                if self.highlighted_item >= 0 {
+                    self.selection
+                        .click_item(self.highlighted_item as usize, mod_state());
+                } else {
+                    self.selection.deselect_all();
+                }
+
+                self.get_config().set_invalidated(true);
+                self.call_selected_callback(_widgets, _layouts, self.highlighted_item);
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, button, _clicks, state);
+    }
+
+    /// Handles keyboard navigation of the list.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        self.update_scroll_bar();
+
+        let page_size = self.get_page_size();
+
+        if let Some((item, changed)) =
+            self.selection
+                .key_pressed(_keycode, _keymod, self.list_items.len(), page_size)
+        {
             self.get_config().set_invalidated(true);
 
-            self.call_selected_callback(_widgets, _layouts);
+            if changed {
+                self.call_selected_callback(_widgets, _layouts, item as i32);
+            }
+
+            self.scroll_to_item(item);
         }
+
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Redraws the cursor when focus changes.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        self.get_config().set_invalidated(true);
     }
 
     default_widget_functions!();
     default_widget_properties!();
     default_widget_callbacks!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selection_after_insert() {
+        let mut selection = ListSelection::new(MultipleSelection);
+
+        selection.set_selected(vec![1, 3, 5], 10);
+        selection.items_inserted(3, 2);
+
+        assert_eq!(selection.get_selected(), vec![1, 5, 7]);
+        assert_eq!(selection.get_cursor(), 7);
+        assert!(!selection.is_selected(3));
+    }
+
+    #[test]
+    fn test_range_selection_after_insert() {
+        let mut selection = ListSelection::new(MultipleSelection);
+
+        selection.set_selected(vec![2], 10);
+        selection.items_inserted(0, 3);
+        selection.click_item(7, Mod::LSHIFTMOD);
+
+        assert_eq!(selection.get_selected(), vec![5, 6, 7]);
+        assert_eq!(selection.get_cursor(), 7);
+    }
+
+    #[test]
+    fn test_selection_after_remove() {
+        let mut selection = ListSelection::new(MultipleSelection);
+
+        selection.set_selected(vec![1, 3, 5, 7], 10);
+        selection.items_removed(3, 2);
+
+        assert_eq!(selection.get_selected(), vec![1, 3, 5]);
+        assert_eq!(selection.get_cursor(), 5);
+    }
+
+    #[test]
+    fn test_range_selection_after_removing_cursor() {
+        let mut selection = ListSelection::new(MultipleSelection);
+
+        selection.set_selected(vec![2, 4], 10);
+        selection.items_removed(3, 3);
+
+        assert_eq!(selection.get_selected(), vec![2]);
+        assert_eq!(selection.get_cursor(), 2);
+
+        selection.click_item(4, Mod::LSHIFTMOD);
+
+        assert_eq!(selection.get_selected(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_selection_after_truncate() {
+        let mut selection = ListSelection::new(MultipleSelection);
+
+        selection.set_selected(vec![1, 8], 10);
+        selection.truncate(5);
+
+        assert_eq!(selection.get_selected(), vec![1]);
+        assert_eq!(selection.get_cursor(), 4);
+    }
+}
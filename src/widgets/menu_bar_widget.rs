@@ -0,0 +1,540 @@
+// Pushrod Widget Library
+// Menu Bar Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{BlendMode, Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+use crate::widgets::popup_menu::*;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::ttf::FontStyle;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the space to the left and right of each menu title, in pixels.
+const TITLE_PADDING: u32 = 10;
+
+/// This is the callback type that is used when an `on_selected` callback is triggered from this
+/// `Widget`.  It receives the ID of the `MenuItem` that was selected.
+pub type OnMenuSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut MenuBarWidget, &[WidgetContainer], &[LayoutContainer], i32)>>;
+
+/// This is the storage object for the `MenuBarWidget`.  It stores the config, properties, callback
+/// registry, the menus, and the state of the open menu.
+pub struct MenuBarWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    overlay_store: TextureStore,
+    menus: Vec<MenuItem>,
+    title_areas: Vec<Rect>,
+    popup: PopupMenu,
+    popup_invalidated: bool,
+    open_menu: i32,
+    highlighted_menu: i32,
+    font_name: String,
+    font_size: i32,
+    mouse_point: Points,
+    on_selected: OnMenuSelectedCallbackType,
+}
+
+/// This is the implementation of the `MenuBarWidget`, which shows a row of menu titles, normally
+/// across the top of the `Window`.  Clicking on a title opens its menu as an overlay below it,
+/// and moving the mouse to another title while a menu is open switches to that menu.  Menus are
+/// made of `MenuItem`s, which can be commands, checkable commands, disabled items, separators, or
+/// submenus that open to the side when the mouse is over them.  Selecting a command closes the
+/// menu, and triggers the `on_selected` callback with the ID of the item.
+///
+/// When it has focus, `Left` and `Right` move between titles, and `Down`, `Return` or `Space`
+/// open the highlighted menu.  While a menu is open, the keys are handled as they are by a
+/// `PopupMenu`, with `Left` and `Right` moving to the neighboring menus.
+impl MenuBarWidget {
+    /// Creates a new `MenuBarWidget` given the `x, y, w, h` coordinates.  Titles and items are drawn
+    /// using `assets/OpenSans-Regular.ttf` at size 14.
+    pub fn new(points: Points, size: Size) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+        config.set_color(CONFIG_COLOR_BASE, Color::RGB(240, 240, 240));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            overlay_store: TextureStore::default(),
+            menus: vec![],
+            title_areas: vec![],
+            popup: PopupMenu::new(),
+            popup_invalidated: false,
+            open_menu: -1,
+            highlighted_menu: -1,
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size: 14,
+            mouse_point: make_points(0, 0),
+            on_selected: None,
+        }
+    }
+
+    /// Adds a menu to the end of the bar, with a `title`, containing `items`.  Returns the index
+    /// of the menu.
+    pub fn add_menu(&mut self, title: String, items: Vec<MenuItem>) -> usize {
+        self.close_menu();
+        self.menus.push(MenuItem::submenu(title, items));
+        self.get_config().set_invalidated(true);
+
+        self.menus.len() - 1
+    }
+
+    /// Returns the menus in the bar.  Each menu is a `MenuItem` with the title of the menu as its
+    /// label, and the items of the menu as its submenu.
+    pub fn get_menus(&self) -> &[MenuItem] {
+        &self.menus
+    }
+
+    /// Returns the `MenuItem` with the specified `id` from any of the menus, or `None` if there is
+    /// no such item.
+    pub fn get_item(&self, id: i32) -> Option<&MenuItem> {
+        find_menu_item(&self.menus, id)
+    }
+
+    /// Indicates whether or not the `MenuItem` with the specified `id` is checked.
+    pub fn is_checked(&self, id: i32) -> bool {
+        self.get_item(id).is_some_and(|item| item.checked)
+    }
+
+    /// Sets the check mark of the `MenuItem` with the specified `id`.
+    pub fn set_checked(&mut self, id: i32, checked: bool) {
+        if let Some(item) = find_menu_item_mut(&mut self.menus, id) {
+            item.checked = checked;
+            self.popup_invalidated = true;
+        }
+    }
+
+    /// Enables or disables the `MenuItem` with the specified `id`.
+    pub fn set_item_enabled(&mut self, id: i32, enabled: bool) {
+        if let Some(item) = find_menu_item_mut(&mut self.menus, id) {
+            item.enabled = enabled;
+            self.popup_invalidated = true;
+        }
+    }
+
+    /// Sets the width of the menus, in pixels.
+    pub fn set_menu_width(&mut self, menu_width: u32) {
+        self.popup.set_menu_width(menu_width);
+    }
+
+    /// Sets the font used to draw the titles and items, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.popup.set_font(font_name.clone(), font_size);
+        self.font_name = font_name;
+        self.font_size = font_size;
+        self.popup_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Indicates whether or not a menu is open.
+    pub fn is_open(&self) -> bool {
+        self.open_menu >= 0
+    }
+
+    /// Assigns the callback closure that will be used when a `MenuItem` is selected.
+    pub fn on_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut MenuBarWidget, &[WidgetContainer], &[LayoutContainer], i32) + 'static,
+    {
+        self.on_selected = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_selected` callback.
+    fn call_selected_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        id: i32,
+    ) {
+        if let Some(mut cb) = self.on_selected.take() {
+            cb(self, widgets, layouts, id);
+            self.on_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that opens the menu at `index` below its title.  If the menu is opened
+    /// with the keyboard, its first item is highlighted.  The size of the `Window` is taken from
+    /// the top-level `Widget`.
+    fn open_menu(&mut self, widgets: &[WidgetContainer], index: i32, keyboard: bool) {
+        let title_area = match self.title_areas.get(index as usize) {
+            Some(title_area) if index >= 0 => *title_area,
+            _ => return,
+        };
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let window_size = widgets[0]
+            .widget
+            .borrow_mut()
+            .get_config()
+            .get_size(CONFIG_SIZE);
+        let anchor = Rect::new(
+            origin[POINT_X] + title_area.x(),
+            origin[POINT_Y] + title_area.y(),
+            title_area.width(),
+            title_area.height(),
+        );
+        let items = &self.menus[index as usize].submenu;
+
+        self.popup.open(
+            items,
+            anchor,
+            Rect::new(0, 0, window_size[SIZE_WIDTH], window_size[SIZE_HEIGHT]),
+        );
+
+        if keyboard {
+            self.popup.highlight_first(items);
+        }
+
+        self.open_menu = index;
+        self.highlighted_menu = index;
+        self.popup_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Internal function that closes the open menu.
+    fn close_menu(&mut self) {
+        if self.open_menu >= 0 {
+            self.popup.close();
+            self.open_menu = -1;
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Internal function that acts on a response from the open menu.
+    fn handle_response(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        response: MenuResponse,
+    ) {
+        let menu_count = self.menus.len() as i32;
+
+        match response {
+            MenuResponse::Ignored => (),
+            MenuResponse::Changed => self.popup_invalidated = true,
+            MenuResponse::Activated(id) => {
+                self.close_menu();
+                self.call_selected_callback(widgets, layouts, id);
+            }
+            MenuResponse::Closed => self.close_menu(),
+            MenuResponse::Previous => self.open_menu(
+                widgets,
+                (self.open_menu + menu_count - 1) % menu_count,
+                true,
+            ),
+            MenuResponse::Next => self.open_menu(widgets, (self.open_menu + 1) % menu_count, true),
+        }
+    }
+
+    /// Internal function that returns the index of the menu title under the window coordinates
+    /// `x, y`, or `-1` if there is no title there.
+    fn get_title_at(&mut self, x: i32, y: i32) -> i32 {
+        let local_x = self.get_config().to_x(0);
+        let local_y = self.get_config().to_y(0);
+
+        self.title_areas
+            .iter()
+            .position(|area| area.contains_point((x - local_x, y - local_y)))
+            .map_or(-1, |index| index as i32)
+    }
+}
+
+/// This is the `Widget` implementation of the `MenuBarWidget`.
+impl Widget for MenuBarWidget {
+    /// Draws the titles of the menus, highlighting the open or focused menu.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let mut title_x = 0;
+
+            self.title_areas.clear();
+
+            for menu in &self.menus {
+                let (offsets, _) = t.measure_text(
+                    self.font_name.clone(),
+                    self.font_size as u16,
+                    FontStyle::NORMAL,
+                    menu.label.clone(),
+                );
+                let width = offsets.last().cloned().unwrap_or(0) + TITLE_PADDING * 2;
+
+                self.title_areas
+                    .push(Rect::new(title_x, 0, width, bounds[SIZE_HEIGHT]));
+                title_x += width as i32;
+            }
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[SIZE_WIDTH], bounds[SIZE_HEIGHT]);
+
+            let base_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let hover_color = self.get_config().get_color(CONFIG_COLOR_HOVER);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let text_color = self.get_config().get_color(CONFIG_COLOR_TEXT);
+            let highlighted_menu = if self.is_open() || self.get_config().is_focused() {
+                self.highlighted_menu
+            } else {
+                -1
+            };
+            let font_name = self.font_name.clone();
+            let font_size = self.font_size;
+            let menus = &self.menus;
+            let title_areas = &self.title_areas;
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                for (i, (menu, title_area)) in menus.iter().zip(title_areas.iter()).enumerate() {
+                    if highlighted_menu == i as i32 {
+                        texture.set_draw_color(hover_color);
+                        texture.fill_rect(*title_area).unwrap();
+                    }
+
+                    if menu.label.is_empty() {
+                        continue;
+                    }
+
+                    let (font_texture, width, height) = t.render_text_line(
+                        texture,
+                        font_name.clone(),
+                        font_size as u16,
+                        FontStyle::NORMAL,
+                        menu.label.clone(),
+                        text_color,
+                    );
+                    let height = height.min(title_area.height());
+
+                    texture
+                        .copy(
+                            &font_texture,
+                            Rect::new(0, 0, width, height),
+                            Rect::new(
+                                title_area.x() + TITLE_PADDING as i32,
+                                (title_area.height() as i32 - height as i32) / 2,
+                                width,
+                                height,
+                            ),
+                        )
+                        .unwrap();
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_line(
+                        Point::new(0, bounds[SIZE_HEIGHT] as i32 - 1),
+                        Point::new(bounds[SIZE_WIDTH] as i32, bounds[SIZE_HEIGHT] as i32 - 1),
+                    )
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Returns the area covered by the open menu and its submenus, if a menu is open.
+    fn get_overlay_area(&mut self) -> Option<Rect> {
+        self.popup.get_area()
+    }
+
+    /// Draws the open menu and its submenus.
+    fn draw_overlay(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        let area = self.popup.get_area()?;
+
+        if self.popup_invalidated {
+            self.overlay_store
+                .create_or_resize_texture(c, area.width(), area.height());
+            self.overlay_store
+                .get_mut_ref()
+                .set_blend_mode(BlendMode::Blend);
+
+            let popup = &self.popup;
+            let items = &self.menus[self.open_menu as usize].submenu;
+            let config = &self.config;
+
+            c.with_texture_canvas(self.overlay_store.get_mut_ref(), |texture| {
+                popup.draw(texture, t, items, config);
+            })
+            .unwrap();
+
+            self.popup_invalidated = false;
+        }
+
+        self.overlay_store.get_optional_ref()
+    }
+
+    /// Closes the open menu when the mouse is clicked outside of it.
+    fn dismiss_overlay(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.close_menu();
+    }
+
+    /// Clears the highlighted title when the mouse leaves the `Widget`, unless a menu is open.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if !self.is_open() && !self.get_config().is_focused() {
+            self.highlighted_menu = -1;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Highlights the item under the mouse in the open menu, and switches to another menu when its
+    /// title is under the mouse.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        self.mouse_point = _points.clone();
+
+        let title = self.get_title_at(_points[POINT_X], _points[POINT_Y]);
+
+        if self.is_open() {
+            if title >= 0 && title != self.open_menu {
+                self.open_menu(_widgets, title, false);
+            } else {
+                let response = self.popup.mouse_moved(
+                    &self.menus[self.open_menu as usize].submenu,
+                    _points[POINT_X],
+                    _points[POINT_Y],
+                );
+
+                self.handle_response(_widgets, _layouts, response);
+            }
+        } else if title != self.highlighted_menu {
+            self.highlighted_menu = title;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Opens and closes menus when their titles are clicked, and selects items from the open menu.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button != 1 || !_state {
+            return;
+        }
+
+        let mouse_point = self.mouse_point.clone();
+        let title = self.get_title_at(mouse_point[POINT_X], mouse_point[POINT_Y]);
+
+        if title >= 0 {
+            if title == self.open_menu {
+                self.close_menu();
+            } else {
+                self.open_menu(_widgets, title, false);
+            }
+        } else if self.is_open() {
+            let response = self.popup.button_down(
+                &mut self.menus[self.open_menu as usize].submenu,
+                mouse_point[POINT_X],
+                mouse_point[POINT_Y],
+            );
+
+            self.handle_response(_widgets, _layouts, response);
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles keyboard navigation of the titles, and of the open menu.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        let menu_count = self.menus.len() as i32;
+        let highlighted_menu = self.highlighted_menu.max(0);
+
+        if self.is_open() {
+            let response = self
+                .popup
+                .key_pressed(&mut self.menus[self.open_menu as usize].submenu, _keycode);
+
+            self.handle_response(_widgets, _layouts, response);
+        } else if menu_count > 0 {
+            match _keycode {
+                Keycode::Left => {
+                    self.highlighted_menu = (highlighted_menu + menu_count - 1) % menu_count;
+                    self.get_config().set_invalidated(true);
+                }
+                Keycode::Right => {
+                    self.highlighted_menu = (highlighted_menu + 1) % menu_count;
+                    self.get_config().set_invalidated(true);
+                }
+                Keycode::Down | Keycode::Return | Keycode::KpEnter | Keycode::Space => {
+                    self.open_menu(_widgets, highlighted_menu, true)
+                }
+                _ => (),
+            }
+        }
+
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Closes the open menu when the `Widget` loses focus, and highlights the first title when it
+    /// gains focus.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        if _focused {
+            self.highlighted_menu = self.highlighted_menu.max(0);
+        } else {
+            self.close_menu();
+            self.highlighted_menu = -1;
+        }
+
+        self.get_config().set_invalidated(true);
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
@@ -62,3 +62,67 @@ pub mod list_widget;
 pub mod tile_widget;
 
 pub mod tab_bar_widget;
+
+/// This is a `TextFieldWidget`, which is a single line text entry box with cursor movement,
+/// selection, and `on_text_changed`/`on_submit` callbacks.
+pub mod text_field_widget;
+
+/// This is a `RadioButtonWidget`, which is a selectable button that belongs to a `RadioGroup`.  Only
+/// one member of a `RadioGroup` can be selected at a time.
+pub mod radio_button_widget;
+
+/// This is a `ScrollBar`, which is a helper used by `Widget`s to track a scroll position, and to draw
+/// and handle the mouse for a scroll bar inside of their bounds.
+pub mod scroll_bar;
+
+/// This is a `ScrollViewWidget`, which hosts child `Widget`s in a content area that can be larger
+/// than its bounds, with draggable scroll bars and mouse wheel scrolling.
+pub mod scroll_view_widget;
+
+/// This is a `VirtualListWidget`, which displays rows from a `ListModel`, drawing only the visible
+/// rows with a `ListItemRenderer`.
+pub mod virtual_list_widget;
+
+/// This is a `TableWidget`, which displays rows of text in columns under a header row, with
+/// sortable and resizable columns.
+pub mod table_widget;
+
+/// This is a `TreeViewWidget`, which displays a hierarchy of nodes that can be expanded and
+/// collapsed, with children that can be loaded when they are first shown.
+pub mod tree_view_widget;
+
+/// This is a `DropDownWidget`, which shows the selected value from a list of values, and opens a
+/// popup list above all other `Widget`s to change it.
+pub mod drop_down_widget;
+
+/// This is a `DialogWidget`, which is a modal window that can show a message box, a confirmation,
+/// or a text prompt, and reports the result through a callback.
+pub mod dialog_widget;
+
+/// This is a `PopupMenu`, which is a helper used by `Widget`s to show a menu of `MenuItem`s with
+/// cascading submenus in an overlay, and to handle the mouse and keyboard for it.
+pub mod popup_menu;
+
+/// This is a `MenuBarWidget`, which shows a row of menu titles that open drop-down menus with
+/// submenus, separators, checkable items, disabled items and shortcut labels.
+pub mod menu_bar_widget;
+
+/// This is a `ContextMenuWidget`, which is a popup menu that the `Engine` opens at the mouse
+/// position when the right mouse button is pressed over a `Widget` it is assigned to.
+pub mod context_menu_widget;
+
+/// This is a `TextAreaWidget`, which is a multi-line text editor with word wrap, selection across
+/// lines, and a vertical scroll bar.
+pub mod text_area_widget;
+
+/// This is a `TabbedPanelWidget`, which shows a row of tabs, and the page `Widget` of the active
+/// tab below them.
+pub mod tabbed_panel_widget;
+
+/// This is a `ToolbarWidget`, which lays out `TileWidget`s and `ImageButtonWidget`s in a
+/// horizontal or vertical strip, with separators, toggle groups, and an overflow menu.
+pub mod toolbar_widget;
+
+/// This is a `SplitPaneWidget`, which divides its area into two panes, side by side or one above
+/// the other, separated by a divider that can be dragged, and double clicked to collapse a pane.
+pub mod split_pane_widget;
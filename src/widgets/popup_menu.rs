@@ -0,0 +1,781 @@
+// Pushrod Widget Library
+// Popup Menu
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::texture_cache::TextureCache;
+use crate::render::widget_config::*;
+
+use sdl2::keyboard::Keycode;
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::render::Canvas;
+use sdl2::ttf::FontStyle;
+use sdl2::video::Window;
+
+/// This is the default width of a menu panel, in pixels.
+pub const MENU_WIDTH: u32 = 200;
+
+/// This is the height of a separator in a menu panel, in pixels.
+const SEPARATOR_HEIGHT: u32 = 9;
+
+/// This is the width of the area to the left of each item's label, where check marks are drawn.
+const CHECK_WIDTH: i32 = 22;
+
+/// This is the width of the area to the right of each item's shortcut label, where the submenu
+/// arrow is drawn.
+const ARROW_WIDTH: i32 = 18;
+
+/// This is an item in a menu.  An item can be a command, a checkable command, a separator, or a
+/// submenu containing more items.
+#[derive(Clone, Debug)]
+pub struct MenuItem {
+    /// The ID that is reported when the item is selected.
+    pub id: i32,
+
+    /// The text shown for the item.
+    pub label: String,
+
+    /// The keyboard shortcut shown to the right of the label, such as `Ctrl+S`.  This is only a
+    /// label: the shortcut itself is handled by the application.
+    pub shortcut: String,
+
+    /// Indicates whether or not the item toggles a check mark when it is selected.
+    pub checkable: bool,
+
+    /// Indicates whether or not the check mark of a checkable item is shown.
+    pub checked: bool,
+
+    /// Indicates whether or not the item can be selected.
+    pub enabled: bool,
+
+    /// Indicates whether or not the item is a separator line.
+    pub separator: bool,
+
+    /// The items of the submenu opened by this item, if any.
+    pub submenu: Vec<MenuItem>,
+}
+
+/// This is the implementation of the `MenuItem`.
+impl MenuItem {
+    /// Creates a new enabled command `MenuItem` with an `id` and a `label`.
+    pub fn new(id: i32, label: String) -> Self {
+        Self {
+            id,
+            label,
+            shortcut: String::new(),
+            checkable: false,
+            checked: false,
+            enabled: true,
+            separator: false,
+            submenu: vec![],
+        }
+    }
+
+    /// Creates a new separator `MenuItem`, which is drawn as a line, and cannot be selected.
+    pub fn separator() -> Self {
+        let mut item = Self::new(-1, String::new());
+
+        item.separator = true;
+        item
+    }
+
+    /// Creates a new `MenuItem` with a `label`, that opens a submenu containing `items`.
+    pub fn submenu(label: String, items: Vec<MenuItem>) -> Self {
+        let mut item = Self::new(-1, label);
+
+        item.submenu = items;
+        item
+    }
+
+    /// Returns this `MenuItem` with its shortcut label set to `shortcut`.
+    pub fn with_shortcut(mut self, shortcut: String) -> Self {
+        self.shortcut = shortcut;
+        self
+    }
+
+    /// Returns this `MenuItem` as a checkable item, with its check mark set to `checked`.
+    pub fn with_check(mut self, checked: bool) -> Self {
+        self.checkable = true;
+        self.checked = checked;
+        self
+    }
+
+    /// Returns this `MenuItem` with its enabled state set to `enabled`.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Indicates whether or not this `MenuItem` can be highlighted and selected.
+    pub fn is_selectable(&self) -> bool {
+        self.enabled && !self.separator
+    }
+}
+
+/// Finds the `MenuItem` with the specified `id` in `items`, or in any of their submenus.
+pub fn find_menu_item(items: &[MenuItem], id: i32) -> Option<&MenuItem> {
+    for item in items.iter() {
+        if item.id == id && !item.separator {
+            return Some(item);
+        }
+
+        if let Some(found) = find_menu_item(&item.submenu, id) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Finds the `MenuItem` with the specified `id` in `items`, or in any of their submenus, so that it
+/// can be modified.
+pub fn find_menu_item_mut(items: &mut [MenuItem], id: i32) -> Option<&mut MenuItem> {
+    for item in items.iter_mut() {
+        if item.id == id && !item.separator {
+            return Some(item);
+        }
+
+        if let Some(found) = find_menu_item_mut(&mut item.submenu, id) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// These are the responses of a `PopupMenu` to mouse and keyboard input.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum MenuResponse {
+    /// The input was not used by the menu.
+    Ignored,
+
+    /// The highlighted item, or the open panels, changed.
+    Changed,
+
+    /// The item with this ID was selected, and the menu was closed.
+    Activated(i32),
+
+    /// The menu was closed without selecting an item.
+    Closed,
+
+    /// `Left` was pressed in the top panel of the menu.
+    Previous,
+
+    /// `Right` was pressed in the top panel of the menu, over an item without a submenu.
+    Next,
+}
+
+/// This is an open panel of a `PopupMenu`.
+#[derive(Clone, Debug)]
+struct MenuPanel {
+    path: Vec<usize>,
+    row_heights: Vec<u32>,
+    area: Rect,
+    highlighted: i32,
+}
+
+/// This is the storage object for a `PopupMenu`.  A `PopupMenu` is not a `Widget`: it is a helper
+/// that a `Widget` uses to show a menu of `MenuItem`s in an overlay.  The menu is made of a panel
+/// for the top level items, and a panel for each open submenu, cascading to the right.  All
+/// coordinates passed to a `PopupMenu` are in the `Window`.
+///
+/// The `PopupMenu` does not own the `MenuItem`s: they are passed in by the `Widget` that owns them,
+/// and must not be changed while the menu is open.
+#[derive(Clone, Debug)]
+pub struct PopupMenu {
+    panels: Vec<MenuPanel>,
+    bounds: Rect,
+    menu_width: u32,
+    row_height: u32,
+    font_name: String,
+    font_size: i32,
+}
+
+/// This is the implementation of the `PopupMenu`.
+impl PopupMenu {
+    /// Creates a new, closed `PopupMenu`.  Items are drawn using `assets/OpenSans-Regular.ttf` at
+    /// size 14, in panels that are `MENU_WIDTH` pixels wide.
+    pub fn new() -> Self {
+        Self {
+            panels: vec![],
+            bounds: Rect::new(0, 0, 1, 1),
+            menu_width: MENU_WIDTH,
+            row_height: 24,
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size: 14,
+        }
+    }
+
+    /// Sets the width of each panel of the menu, in pixels.
+    pub fn set_menu_width(&mut self, menu_width: u32) {
+        self.menu_width = menu_width.max(CHECK_WIDTH as u32 + ARROW_WIDTH as u32);
+    }
+
+    /// Sets the height of each item in the menu, in pixels.
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.row_height = row_height.max(1);
+    }
+
+    /// Sets the font used to draw the items, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.font_name = font_name;
+        self.font_size = font_size;
+    }
+
+    /// Indicates whether or not the menu is open.
+    pub fn is_open(&self) -> bool {
+        !self.panels.is_empty()
+    }
+
+    /// Opens the menu showing `items`, below the `anchor` area, or above it if there is not enough
+    /// room below it.  The menu is kept inside of `bounds`, which is normally the area of the
+    /// `Window`.  Any panels that were already open are closed first.
+    pub fn open(&mut self, items: &[MenuItem], anchor: Rect, bounds: Rect) {
+        let height = self.get_panel_height(items);
+
+        self.bounds = bounds;
+        self.panels.clear();
+
+        if items.is_empty() {
+            return;
+        }
+
+        let area = self.place_panel(
+            (anchor.x(), anchor.right() - self.menu_width as i32),
+            (anchor.bottom(), anchor.y() - height as i32),
+            height,
+        );
+
+        self.panels.push(MenuPanel {
+            path: vec![],
+            row_heights: self.get_row_heights(items),
+            area,
+            highlighted: -1,
+        });
+    }
+
+    /// Closes the menu, and all of its submenus.
+    pub fn close(&mut self) {
+        self.panels.clear();
+    }
+
+    /// Returns the area covered by all of the open panels of the menu, or `None` if it is closed.
+    pub fn get_area(&self) -> Option<Rect> {
+        self.panels
+            .iter()
+            .map(|panel| panel.area)
+            .fold(None, |area: Option<Rect>, panel_area| match area {
+                Some(area) => Some(area.union(panel_area)),
+                None => Some(panel_area),
+            })
+    }
+
+    /// Highlights the first selectable item in the deepest open panel.  This is used when a menu is
+    /// opened with the keyboard.
+    pub fn highlight_first(&mut self, items: &[MenuItem]) {
+        if let Some(panel_index) = self.panels.len().checked_sub(1) {
+            let panel_items = items_at(items, &self.panels[panel_index].path);
+
+            self.panels[panel_index].highlighted = next_selectable(panel_items, -1, 1);
+        }
+    }
+
+    /// Highlights the item under the mouse at `x, y`, opening its submenu if it has one, and
+    /// closing any submenus below it.  Returns `Changed` if the menu needs to be redrawn.
+    pub fn mouse_moved(&mut self, items: &[MenuItem], x: i32, y: i32) -> MenuResponse {
+        match self.get_item_at(x, y) {
+            Some((panel_index, item)) if item >= 0 => {
+                if self.panels[panel_index].highlighted == item {
+                    return MenuResponse::Ignored;
+                }
+
+                let selectable =
+                    items_at(items, &self.panels[panel_index].path)[item as usize].is_selectable();
+
+                if !selectable && self.panels[panel_index].highlighted == -1 {
+                    return MenuResponse::Ignored;
+                }
+
+                self.panels.truncate(panel_index + 1);
+                self.panels[panel_index].highlighted = if selectable { item } else { -1 };
+
+                if selectable {
+                    self.open_submenu(items, panel_index, item as usize);
+                }
+
+                MenuResponse::Changed
+            }
+            _ => MenuResponse::Ignored,
+        }
+    }
+
+    /// Handles a mouse button press at `x, y`.  Pressing on an item selects it, or opens its
+    /// submenu.  Pressing outside of the menu closes it.
+    pub fn button_down(&mut self, items: &mut [MenuItem], x: i32, y: i32) -> MenuResponse {
+        match self.get_item_at(x, y) {
+            Some((panel_index, item)) if item >= 0 => {
+                self.activate(items, panel_index, item as usize)
+            }
+            Some(_) => MenuResponse::Ignored,
+            None => {
+                self.close();
+                MenuResponse::Closed
+            }
+        }
+    }
+
+    /// Handles keyboard navigation of the menu.  `Up`, `Down`, `Home` and `End` move the highlight
+    /// in the deepest open panel, `Right` opens a submenu, `Left` and `Escape` close it, and
+    /// `Return` or `Space` select the highlighted item.
+    pub fn key_pressed(&mut self, items: &mut [MenuItem], keycode: Keycode) -> MenuResponse {
+        let panel_index = match self.panels.len().checked_sub(1) {
+            Some(panel_index) => panel_index,
+            None => return MenuResponse::Ignored,
+        };
+        let highlighted = self.panels[panel_index].highlighted;
+        let panel_items = items_at(items, &self.panels[panel_index].path);
+        let highlighted_item = if highlighted >= 0 {
+            panel_items.get(highlighted as usize)
+        } else {
+            None
+        };
+
+        match keycode {
+            Keycode::Up | Keycode::Down | Keycode::Home | Keycode::End => {
+                let target = match keycode {
+                    Keycode::Up => next_selectable(panel_items, highlighted, -1),
+                    Keycode::Down => next_selectable(panel_items, highlighted, 1),
+                    Keycode::Home => next_selectable(panel_items, -1, 1),
+                    _ => next_selectable(panel_items, panel_items.len() as i32, -1),
+                };
+
+                if target == highlighted {
+                    return MenuResponse::Ignored;
+                }
+
+                self.panels[panel_index].highlighted = target;
+                MenuResponse::Changed
+            }
+            Keycode::Right => {
+                if highlighted_item.is_some_and(|item| !item.submenu.is_empty()) {
+                    self.open_submenu(items, panel_index, highlighted as usize);
+                    self.highlight_first(items);
+                    MenuResponse::Changed
+                } else {
+                    MenuResponse::Next
+                }
+            }
+            Keycode::Left if panel_index == 0 => MenuResponse::Previous,
+            Keycode::Left | Keycode::Escape if panel_index > 0 => {
+                self.panels.truncate(panel_index);
+                MenuResponse::Changed
+            }
+            Keycode::Escape => {
+                self.close();
+                MenuResponse::Closed
+            }
+            Keycode::Return | Keycode::KpEnter | Keycode::Space if highlighted >= 0 => {
+                let response = self.activate(items, panel_index, highlighted as usize);
+
+                if response == MenuResponse::Changed {
+                    self.highlight_first(items);
+                }
+
+                response
+            }
+            _ => MenuResponse::Ignored,
+        }
+    }
+
+    /// Draws the open panels of the menu into the `Canvas`, which must cover the area returned by
+    /// `get_area`.  The parts of the area that are not covered by a panel are cleared to
+    /// transparent, so the `Texture` being drawn to should use `BlendMode::Blend`.  Colors are taken
+    /// from `config`: the base, hover, border and text colors are used for the panels, and the
+    /// secondary color is used for separators and disabled items.
+    pub fn draw(
+        &self,
+        c: &mut Canvas<Window>,
+        t: &mut TextureCache,
+        items: &[MenuItem],
+        config: &WidgetConfig,
+    ) {
+        let area = match self.get_area() {
+            Some(area) => area,
+            None => return,
+        };
+        let base_color = config.get_color(CONFIG_COLOR_BASE);
+        let hover_color = config.get_color(CONFIG_COLOR_HOVER);
+        let border_color = config.get_color(CONFIG_COLOR_BORDER);
+        let text_color = config.get_color(CONFIG_COLOR_TEXT);
+        let disabled_color = config.get_color(CONFIG_COLOR_SECONDARY);
+
+        c.set_draw_color(Color::RGBA(0, 0, 0, 0));
+        c.clear();
+
+        for panel in &self.panels {
+            let panel_area = Rect::new(
+                panel.area.x() - area.x(),
+                panel.area.y() - area.y(),
+                panel.area.width(),
+                panel.area.height(),
+            );
+            let row_width = panel_area.width().saturating_sub(2).max(1);
+            let mut row_y = panel_area.y() + 1;
+
+            c.set_draw_color(base_color);
+            c.fill_rect(panel_area).unwrap();
+
+            for (i, item) in items_at(items, &panel.path).iter().enumerate() {
+                let row_height = self.get_row_height(item);
+                let row_area = Rect::new(panel_area.x() + 1, row_y, row_width, row_height);
+                let color = if item.enabled {
+                    text_color
+                } else {
+                    disabled_color
+                };
+
+                row_y += row_height as i32;
+
+                if item.separator {
+                    let line_y = row_area.y() + row_height as i32 / 2;
+
+                    c.set_draw_color(disabled_color);
+                    c.draw_line(
+                        Point::new(row_area.x() + 4, line_y),
+                        Point::new(row_area.right() - 5, line_y),
+                    )
+                    .unwrap();
+
+                    continue;
+                }
+
+                if panel.highlighted == i as i32 {
+                    c.set_draw_color(hover_color);
+                    c.fill_rect(row_area).unwrap();
+                }
+
+                if item.checkable && item.checked {
+                    let check_x = row_area.x() + 6;
+                    let check_y = row_area.y() + row_height as i32 / 2;
+
+                    c.set_draw_color(color);
+
+                    for k in 0..2 {
+                        c.draw_line(
+                            Point::new(check_x, check_y + k - 1),
+                            Point::new(check_x + 3, check_y + k + 2),
+                        )
+                        .unwrap();
+                        c.draw_line(
+                            Point::new(check_x + 3, check_y + k + 2),
+                            Point::new(check_x + 10, check_y + k - 5),
+                        )
+                        .unwrap();
+                    }
+                }
+
+                if !item.submenu.is_empty() {
+                    let arrow_x = row_area.right() - ARROW_WIDTH / 2 - 2;
+                    let arrow_y = row_area.y() + row_height as i32 / 2;
+
+                    c.set_draw_color(color);
+
+                    for k in 0..4 {
+                        c.draw_line(
+                            Point::new(arrow_x - 2 + k, arrow_y - 3 + k),
+                            Point::new(arrow_x - 2 + k, arrow_y + 3 - k),
+                        )
+                        .unwrap();
+                    }
+                }
+
+                let shortcut_width = draw_menu_text(
+                    c,
+                    t,
+                    (&self.font_name, self.font_size),
+                    &item.shortcut,
+                    color,
+                    Rect::new(
+                        row_area.x(),
+                        row_area.y(),
+                        (row_area.width() as i32 - ARROW_WIDTH).max(1) as u32,
+                        row_height,
+                    ),
+                    true,
+                );
+
+                draw_menu_text(
+                    c,
+                    t,
+                    (&self.font_name, self.font_size),
+                    &item.label,
+                    color,
+                    Rect::new(
+                        row_area.x() + CHECK_WIDTH,
+                        row_area.y(),
+                        (row_area.width() as i32
+                            - CHECK_WIDTH
+                            - ARROW_WIDTH
+                            - shortcut_width as i32)
+                            .max(1) as u32,
+                        row_height,
+                    ),
+                    false,
+                );
+            }
+
+            c.set_draw_color(border_color);
+            c.draw_rect(panel_area).unwrap();
+        }
+    }
+
+    /// Internal function that selects the item at `index` in the panel at `panel_index`.  Items
+    /// with a submenu open it, and checkable items have their check mark toggled.
+    fn activate(
+        &mut self,
+        items: &mut [MenuItem],
+        panel_index: usize,
+        index: usize,
+    ) -> MenuResponse {
+        let path = self.panels[panel_index].path.clone();
+        let item = &mut items_at_mut(items, &path)[index];
+
+        if !item.is_selectable() {
+            return MenuResponse::Ignored;
+        }
+
+        if !item.submenu.is_empty() {
+            self.panels.truncate(panel_index + 1);
+            self.panels[panel_index].highlighted = index as i32;
+            self.open_submenu(items, panel_index, index);
+
+            return MenuResponse::Changed;
+        }
+
+        if item.checkable {
+            item.checked = !item.checked;
+        }
+
+        let id = item.id;
+
+        self.close();
+        MenuResponse::Activated(id)
+    }
+
+    /// Internal function that opens the submenu of the item at `index` in the panel at
+    /// `panel_index`, to the right of the panel, or to the left of it if there is not enough room.
+    /// Nothing is opened if the item has no submenu, or if it is already open.
+    fn open_submenu(&mut self, items: &[MenuItem], panel_index: usize, index: usize) {
+        let mut path = self.panels[panel_index].path.clone();
+        let panel_items = items_at(items, &path);
+        let submenu = &panel_items[index].submenu;
+
+        path.push(index);
+
+        if submenu.is_empty()
+            || self
+                .panels
+                .get(panel_index + 1)
+                .is_some_and(|panel| panel.path == path)
+        {
+            return;
+        }
+
+        let parent_area = self.panels[panel_index].area;
+        let row_y = parent_area.y()
+            + 1
+            + panel_items[..index]
+                .iter()
+                .map(|item| self.get_row_height(item) as i32)
+                .sum::<i32>();
+        let height = self.get_panel_height(submenu);
+        let area = self.place_panel(
+            (
+                parent_area.right() - 1,
+                parent_area.x() - self.menu_width as i32 + 1,
+            ),
+            (
+                row_y - 1,
+                row_y + self.row_height as i32 + 1 - height as i32,
+            ),
+            height,
+        );
+
+        self.panels.truncate(panel_index + 1);
+        self.panels.push(MenuPanel {
+            path,
+            row_heights: self.get_row_heights(submenu),
+            area,
+            highlighted: -1,
+        });
+    }
+
+    /// Internal function that places a panel of `height` pixels at the first of the `x` positions
+    /// and the first of the `y` positions, using the second positions instead if the panel does
+    /// not fit in the bounds of the menu.  The panel is then moved to fit inside of the bounds.
+    fn place_panel(&self, x: (i32, i32), y: (i32, i32), height: u32) -> Rect {
+        let bounds = self.bounds;
+        let width = self.menu_width;
+        let panel_x = if x.0 + width as i32 > bounds.right() {
+            x.1
+        } else {
+            x.0
+        };
+        let panel_y = if y.0 + height as i32 > bounds.bottom() {
+            y.1
+        } else {
+            y.0
+        };
+
+        Rect::new(
+            panel_x.min(bounds.right() - width as i32).max(bounds.x()),
+            panel_y.min(bounds.bottom() - height as i32).max(bounds.y()),
+            width,
+            height,
+        )
+    }
+
+    /// Internal function that returns the height of the row used by `item`.
+    fn get_row_height(&self, item: &MenuItem) -> u32 {
+        if item.separator {
+            SEPARATOR_HEIGHT
+        } else {
+            self.row_height
+        }
+    }
+
+    /// Internal function that returns the heights of the rows used by `items`.
+    fn get_row_heights(&self, items: &[MenuItem]) -> Vec<u32> {
+        items.iter().map(|item| self.get_row_height(item)).collect()
+    }
+
+    /// Internal function that returns the height of a panel containing `items`, including its
+    /// border.
+    fn get_panel_height(&self, items: &[MenuItem]) -> u32 {
+        self.get_row_heights(items).iter().sum::<u32>() + 2
+    }
+
+    /// Internal function that returns the index of the deepest open panel under the point `x, y`,
+    /// along with the index of the item under the point in that panel, or `-1` if the point is on
+    /// the border of the panel.  Returns `None` if the point is not inside of any panel.
+    fn get_item_at(&self, x: i32, y: i32) -> Option<(usize, i32)> {
+        let panel_index = self
+            .panels
+            .iter()
+            .rposition(|panel| panel.area.contains_point((x, y)))?;
+        let panel = &self.panels[panel_index];
+        let mut row_y = panel.area.y() + 1;
+
+        if x == panel.area.x() || x == panel.area.right() - 1 {
+            return Some((panel_index, -1));
+        }
+
+        for (i, row_height) in panel.row_heights.iter().enumerate() {
+            if y >= row_y && y < row_y + *row_height as i32 {
+                return Some((panel_index, i as i32));
+            }
+
+            row_y += *row_height as i32;
+        }
+
+        Some((panel_index, -1))
+    }
+}
+
+impl Default for PopupMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the items shown by the panel at `path`, which lists the index of the item opening
+/// each submenu, starting from `items`.
+fn items_at<'a>(items: &'a [MenuItem], path: &[usize]) -> &'a [MenuItem] {
+    path.iter()
+        .fold(items, |panel_items, index| &panel_items[*index].submenu)
+}
+
+/// Returns the items shown by the panel at `path`, so that they can be modified.
+fn items_at_mut<'a>(items: &'a mut [MenuItem], path: &[usize]) -> &'a mut [MenuItem] {
+    path.iter()
+        .fold(items, |panel_items, index| &mut panel_items[*index].submenu)
+}
+
+/// Returns the index of the next selectable item in `items` after `index`, moving by `step`, and
+/// wrapping around at either end.  Moving backwards from `-1` starts from the last item.  Returns
+/// `index` if there is no other selectable item.
+fn next_selectable(items: &[MenuItem], index: i32, step: i32) -> i32 {
+    let count = items.len() as i32;
+    let mut current = if index < 0 && step < 0 { count } else { index };
+
+    for _ in 0..count {
+        current = (current + step).rem_euclid(count);
+
+        if items[current as usize].is_selectable() {
+            return current;
+        }
+    }
+
+    index
+}
+
+/// Draws `text` into `area` of the `Canvas`, vertically centered, and either left justified, or
+/// right justified with a small margin.  Returns the width of the text that was drawn.
+fn draw_menu_text(
+    c: &mut Canvas<Window>,
+    t: &mut TextureCache,
+    font: (&str, i32),
+    text: &str,
+    color: Color,
+    area: Rect,
+    right_justify: bool,
+) -> u32 {
+    let text_width = area.width().saturating_sub(8);
+
+    if text.is_empty() || text_width == 0 {
+        return 0;
+    }
+
+    let (font_texture, width, height) = t.render_text(
+        c,
+        String::from(font.0),
+        font.1 as u16,
+        FontStyle::NORMAL,
+        String::from(text),
+        color,
+        text_width,
+    );
+    let width = width.min(text_width);
+    let height = height.min(area.height());
+    let text_x = if right_justify {
+        area.right() - 4 - width as i32
+    } else {
+        area.x() + 4
+    };
+
+    c.copy(
+        &font_texture,
+        Rect::new(0, 0, width, height),
+        Rect::new(
+            text_x,
+            area.y() + (area.height() as i32 - height as i32) / 2,
+            width,
+            height,
+        ),
+    )
+    .unwrap();
+
+    width + 8
+}
@@ -14,11 +14,12 @@
 // limitations under the License.
 
 use crate::render::callbacks::CallbackRegistry;
+use crate::render::clock::time_ms;
 use crate::render::layout_cache::LayoutContainer;
 use crate::render::widget::*;
 use crate::render::widget_cache::WidgetContainer;
 use crate::render::widget_config::*;
-use crate::render::{Points, Size};
+use crate::render::{Points, Size, SIZE_HEIGHT, SIZE_WIDTH};
 
 use sdl2::pixels::Color;
 use sdl2::rect::Rect;
@@ -30,54 +31,254 @@ use sdl2::render::{Canvas, Texture};
 use std::any::Any;
 use std::collections::HashMap;
 
+/// This is the time it takes the busy indicator of an indeterminate `ProgressWidget` to travel
+/// across the bar and back, in milliseconds.
+const BUSY_PERIOD: u64 = 1_500;
+
+/// This is the space between segments when a `ProgressWidget` is drawn in segments.
+const SEGMENT_GAP: u32 = 2;
+
+/// This is the formatter that is used to produce the text shown over a `ProgressWidget`.  It is
+/// given the current value, and the percentage of the range that it represents.
+pub type ProgressFormatterType = Option<Box<dyn Fn(f64, f64) -> String>>;
+
+/// These are the possible progress bar orientations.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ProgressOrientation {
+    /// Indicates a progress bar that fills from left to right.
+    ProgressHorizontal,
+
+    /// Indicates a progress bar that fills from bottom to top.
+    ProgressVertical,
+}
+
 /// This is the storage object for the `ProgressWidget`.  It stores the config, properties, callback registry,
-/// the base widget, and progress from 0 to 100.
+/// the base widget, the progress value and its range, and the drawing options.
 pub struct ProgressWidget {
     config: WidgetConfig,
     system_properties: HashMap<i32, String>,
     callback_registry: CallbackRegistry,
     texture_store: TextureStore,
-    progress: u8,
+    value: f64,
+    min: f64,
+    max: f64,
+    orientation: ProgressOrientation,
+    segments: u32,
+    indeterminate: bool,
+    busy_start: u64,
+    busy_phase: u64,
+    label_visible: bool,
+    label_formatter: ProgressFormatterType,
 }
 
 /// Creates a new `ProgressWidget`, which draws a progress bar inside a `BaseWidget`.
+///
+/// By default, progress runs from 0 to 100, and fills the bar from left to right.  Any range of
+/// `f64` values can be used instead with `set_range` and `set_value`.  The bar can be drawn
+/// vertically, or as a series of segments that fill one step at a time.  A label can be drawn in
+/// the center of the bar, showing the percentage, or text produced by a formatter.  When progress
+/// cannot be measured, the bar can be made indeterminate, where a busy indicator moves back and
+/// forth across the bar instead.
 impl ProgressWidget {
     /// Creates a new instance of the `ProgressWidget` object.  It draws a progress bar-style
     /// `Widget` given the `xywh` coordinates, and the `percentage` of fill from 0-100.  The
     /// base color and border colors are set to white and black, respectively.  Use the
     /// `COLOR_SECONDARY` setting to change the color of the fill for the progress bar.
     pub fn new(points: Points, size: Size, progress: u8) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        config.set_numeric(CONFIG_FONT_SIZE, 14);
+
         Self {
-            config: WidgetConfig::new(points, size),
+            config,
             system_properties: HashMap::new(),
             callback_registry: CallbackRegistry::new(),
             texture_store: TextureStore::default(),
-            progress,
+            value: f64::from(progress.min(100)),
+            min: 0.0,
+            max: 100.0,
+            orientation: ProgressOrientation::ProgressHorizontal,
+            segments: 0,
+            indeterminate: false,
+            busy_start: time_ms(),
+            busy_phase: 0,
+            label_visible: false,
+            label_formatter: None,
         }
     }
 
     /// Sets the progress for the widget.  Progress value is between 0 and 100.  Anything over
-    /// 100 will just set the progress to 100.
+    /// 100 will just set the progress to 100.  This is the percentage of the range of the
+    /// `Widget`.
     pub fn set_progress(&mut self, progress: u8) {
-        if progress > 100 {
-            self.progress = 100;
-        } else {
-            self.progress = progress;
+        let progress = f64::from(progress.min(100));
+
+        self.set_value(self.min + (self.max - self.min) * progress / 100.0);
+    }
+
+    /// Retrieves the current progress value as a `u8` value, which is the percentage of the range
+    /// of the `Widget`.
+    pub fn get_progress(&mut self) -> u8 {
+        self.get_percentage().round() as u8
+    }
+
+    /// Sets the range of values shown by the progress bar.  If `max` is less than `min`, the values
+    /// are swapped.  The current value is kept inside of the new range.
+    pub fn set_range(&mut self, min: f64, max: f64) {
+        self.min = min.min(max);
+        self.max = max.max(min);
+        self.set_value(self.value);
+    }
+
+    /// Retrieves the lowest value of the range of the progress bar.
+    pub fn get_min(&self) -> f64 {
+        self.min
+    }
+
+    /// Retrieves the highest value of the range of the progress bar.
+    pub fn get_max(&self) -> f64 {
+        self.max
+    }
+
+    /// Sets the progress value, which is kept inside of the range of the progress bar.
+    pub fn set_value(&mut self, value: f64) {
+        self.value = value.max(self.min).min(self.max);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Retrieves the current progress value.
+    pub fn get_value(&self) -> f64 {
+        self.value
+    }
+
+    /// Sets the direction in which the progress bar fills.
+    pub fn set_orientation(&mut self, orientation: ProgressOrientation) {
+        self.orientation = orientation;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Draws the progress bar as a number of equally sized `segments`, which fill one step at a
+    /// time.  A value of `0` draws a continuous bar, which is the default.
+    pub fn set_segments(&mut self, segments: u32) {
+        self.segments = segments;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Turns the indeterminate mode on or off.  An indeterminate progress bar does not show its
+    /// value: instead, a busy indicator moves back and forth across the bar, to show that work is
+    /// being done.
+    pub fn set_indeterminate(&mut self, indeterminate: bool) {
+        if indeterminate && !self.indeterminate {
+            self.busy_start = time_ms();
+            self.busy_phase = 0;
         }
 
+        self.indeterminate = indeterminate;
         self.get_config().set_invalidated(true);
     }
 
-    /// Retrieves the current progress value as a `u8` value.
-    pub fn get_progress(&mut self) -> u8 {
-        self.progress
+    /// Indicates whether or not the progress bar is indeterminate.
+    pub fn is_indeterminate(&self) -> bool {
+        self.indeterminate
+    }
+
+    /// Shows or hides the label in the center of the progress bar.  Unless a formatter is set with
+    /// `set_label_formatter`, the label shows the percentage, and is empty while the progress bar
+    /// is indeterminate.  The label is drawn using the `CONFIG_COLOR_TEXT` color and the
+    /// `CONFIG_FONT_SIZE` size.
+    pub fn set_label_visible(&mut self, visible: bool) {
+        self.label_visible = visible;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the formatter that produces the label text from the current value and percentage, and
+    /// shows the label.
+    pub fn set_label_formatter<F>(&mut self, formatter: F)
+    where
+        F: Fn(f64, f64) -> String + 'static,
+    {
+        self.label_formatter = Some(Box::new(formatter));
+        self.set_label_visible(true);
+    }
+
+    /// Returns the percentage of the range that the current value represents, from 0 to 100.
+    fn get_percentage(&self) -> f64 {
+        if self.max > self.min {
+            (self.value - self.min) * 100.0 / (self.max - self.min)
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the text of the label, or an empty `String` if no label is to be shown.
+    fn get_label(&self) -> String {
+        if !self.label_visible {
+            return String::new();
+        }
+
+        match &self.label_formatter {
+            Some(formatter) => formatter(self.value, self.get_percentage()),
+            None if self.indeterminate => String::new(),
+            None => format!("{}%", self.get_percentage().round()),
+        }
+    }
+
+    /// Returns the areas to fill inside of the bar, based on the value, the orientation, the
+    /// segments, and the indeterminate mode.
+    fn get_fill_areas(&self) -> Vec<Rect> {
+        let bounds = self.config.get_size(CONFIG_SIZE);
+        let (width, height) = (bounds[SIZE_WIDTH], bounds[SIZE_HEIGHT]);
+        let length = match self.orientation {
+            ProgressOrientation::ProgressHorizontal => width.saturating_sub(2),
+            ProgressOrientation::ProgressVertical => height.saturating_sub(2),
+        };
+        let fraction = self.get_percentage() / 100.0;
+
+        // Each area is given as a start and a length along the direction of the bar.
+        let spans: Vec<(u32, u32)> = if self.indeterminate {
+            let block = (length / 4).max(1);
+            let t = self.busy_phase as f64 / BUSY_PERIOD as f64;
+            let travel = if t < 0.5 { t * 2.0 } else { 2.0 - t * 2.0 };
+
+            vec![(
+                (f64::from(length.saturating_sub(block)) * travel) as u32,
+                block,
+            )]
+        } else if self.segments > 0 {
+            let gaps = SEGMENT_GAP * (self.segments - 1);
+            let segment = (length.saturating_sub(gaps) / self.segments).max(1);
+            let filled = (fraction * f64::from(self.segments)).floor() as u32;
+
+            (0..filled)
+                .map(|i| (i * (segment + SEGMENT_GAP), segment))
+                .collect()
+        } else {
+            vec![(0, (f64::from(length) * fraction) as u32)]
+        };
+
+        spans
+            .into_iter()
+            .filter(|(_, size)| *size > 0)
+            .map(|(start, size)| match self.orientation {
+                ProgressOrientation::ProgressHorizontal => {
+                    Rect::new(1 + start as i32, 1, size, height.saturating_sub(2))
+                }
+                ProgressOrientation::ProgressVertical => Rect::new(
+                    1,
+                    height as i32 - 1 - (start + size) as i32,
+                    width.saturating_sub(2),
+                    size,
+                ),
+            })
+            .collect()
     }
 }
 
 /// This is the `Widget` implementation of the `ProgressWidget`.  It contains a `BaseWidget` within
 /// its bounds to draw the base background, then draws the progress fill over the top.
 impl Widget for ProgressWidget {
-    fn draw(&mut self, c: &mut Canvas<Window>, _t: &mut TextureCache) -> Option<&Texture> {
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
         if self.get_config().invalidated() {
             let bounds = self.get_config().get_size(CONFIG_SIZE);
 
@@ -85,21 +286,46 @@ impl Widget for ProgressWidget {
                 .create_or_resize_texture(c, bounds[0] as u32, bounds[1] as u32);
 
             let base_color = self.get_color(CONFIG_COLOR_SECONDARY);
-            let progress_width = (f64::from(self.get_size(CONFIG_SIZE)[0])
-                * (f64::from(self.progress))
-                / 100.0) as u32;
-            let progress_height = self.get_size(CONFIG_SIZE)[1] - 2;
+            let fill_areas = self.get_fill_areas();
             let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
-            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let text_color = self.get_config().get_color(CONFIG_COLOR_TEXT);
+            let font_size = self.get_config().get_numeric(CONFIG_FONT_SIZE);
+            let label = self.get_label();
 
             c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
                 texture.set_draw_color(Color::RGB(255, 255, 255));
                 texture.clear();
 
                 texture.set_draw_color(base_color);
-                texture
-                    .fill_rect(Rect::new(1, 1, progress_width, progress_height))
-                    .unwrap();
+
+                for fill_area in fill_areas {
+                    texture.fill_rect(fill_area).unwrap();
+                }
+
+                if !label.is_empty() {
+                    let (font_texture, font_width, font_height) = t.render_text(
+                        texture,
+                        String::from("assets/OpenSans-Regular.ttf"),
+                        font_size as u16,
+                        sdl2::ttf::FontStyle::NORMAL,
+                        label,
+                        text_color,
+                        bounds[SIZE_WIDTH],
+                    );
+
+                    texture
+                        .copy(
+                            &font_texture,
+                            None,
+                            Rect::new(
+                                (bounds[SIZE_WIDTH] as i32 - font_width as i32) / 2,
+                                (bounds[SIZE_HEIGHT] as i32 - font_height as i32) / 2,
+                                font_width,
+                                font_height,
+                            ),
+                        )
+                        .unwrap();
+                }
 
                 texture.set_draw_color(border_color);
                 texture
@@ -112,6 +338,20 @@ impl Widget for ProgressWidget {
         self.texture_store.get_optional_ref()
     }
 
+    /// Moves the busy indicator while the progress bar is indeterminate.
+    fn tick(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.indeterminate {
+            let busy_phase = time_ms().saturating_sub(self.busy_start) % BUSY_PERIOD;
+
+            if busy_phase != self.busy_phase {
+                self.busy_phase = busy_phase;
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        self.tick_callback(_widgets, _layouts);
+    }
+
     default_widget_functions!();
     default_widget_properties!();
     default_widget_callbacks!();
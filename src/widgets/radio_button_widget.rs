@@ -0,0 +1,385 @@
+// Pushrod Widget Library
+// Radio Button Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+use crate::render::{
+    make_points, make_size, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH,
+};
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::canvas_helper::CanvasHelper;
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::widget_config::CompassPosition::Center;
+use crate::widgets::image_widget::ImageWidget;
+use crate::widgets::text_widget::{TextJustify, TextWidget};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// This is the callback type that is used when an `on_selection_changed` callback is triggered
+/// from a `RadioGroup`.  It receives the `RadioButtonWidget` that was selected, and its index in
+/// the group.
+pub type OnSelectionChangedCallbackType =
+    Option<Box<dyn FnMut(&mut RadioButtonWidget, &[WidgetContainer], &[LayoutContainer], usize)>>;
+
+/// This is the storage object for the state shared between members of a `RadioGroup`.
+struct RadioGroupState {
+    members: usize,
+    selected: Option<usize>,
+    on_selection_changed: OnSelectionChangedCallbackType,
+}
+
+/// This is a `RadioGroup`, which links a set of `RadioButtonWidget`s together, so that only one of
+/// them can be selected at a time.  A `RadioGroup` is a shared handle: cloning it refers to the same
+/// group.  Each `RadioButtonWidget` created with the group is given the next index in the group,
+/// starting at `0`.
+#[derive(Clone)]
+pub struct RadioGroup {
+    state: Rc<RefCell<RadioGroupState>>,
+}
+
+/// This is the implementation of the `RadioGroup`.
+impl RadioGroup {
+    /// Creates a new, empty `RadioGroup`, with no selection.
+    pub fn new() -> Self {
+        Self {
+            state: Rc::new(RefCell::new(RadioGroupState {
+                members: 0,
+                selected: None,
+                on_selection_changed: None,
+            })),
+        }
+    }
+
+    /// Returns the index of the currently selected `RadioButtonWidget`, or `None` if no member of
+    /// the group is selected.
+    pub fn get_selected(&self) -> Option<usize> {
+        self.state.borrow().selected
+    }
+
+    /// Selects the `RadioButtonWidget` at `index` in the group, deselecting all of the others.
+    /// Members of the group update their display on the next tick.  This does not trigger the
+    /// `on_selection_changed` callback.
+    pub fn set_selected(&self, index: Option<usize>) {
+        self.state.borrow_mut().selected = index;
+    }
+
+    /// Returns the number of `RadioButtonWidget`s in the group.
+    pub fn len(&self) -> usize {
+        self.state.borrow().members
+    }
+
+    /// Indicates whether or not the group has any members.
+    pub fn is_empty(&self) -> bool {
+        self.state.borrow().members == 0
+    }
+
+    /// Assigns the callback closure that will be used when the selection in the group is changed
+    /// by the user.
+    pub fn on_selection_changed<F>(&self, callback: F)
+    where
+        F: FnMut(&mut RadioButtonWidget, &[WidgetContainer], &[LayoutContainer], usize) + 'static,
+    {
+        self.state.borrow_mut().on_selection_changed = Some(Box::new(callback));
+    }
+
+    /// Internal function that adds a new member to the group, returning its index.
+    fn add_member(&self) -> usize {
+        let mut state = self.state.borrow_mut();
+
+        state.members += 1;
+        state.members - 1
+    }
+
+    /// Internal function that triggers the `on_selection_changed` callback.  The callback is
+    /// removed from the group while it is being called, so that it can use the group freely.
+    fn call_selection_changed_callback(
+        &self,
+        widget: &mut RadioButtonWidget,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        index: usize,
+    ) {
+        let callback = self.state.borrow_mut().on_selection_changed.take();
+
+        if let Some(mut cb) = callback {
+            cb(widget, widgets, layouts, index);
+            self.state.borrow_mut().on_selection_changed = Some(cb);
+        }
+    }
+}
+
+impl Default for RadioGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// This is the storage object for the `RadioButtonWidget`.  It stores the config, properties, callback
+/// registry, the `RadioGroup` it belongs to, and its index in that group.
+pub struct RadioButtonWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    text_widget: TextWidget,
+    unchecked_widget: ImageWidget,
+    checked_widget: ImageWidget,
+    active: bool,
+    selected: bool,
+    in_bounds: bool,
+    originated: bool,
+    group: RadioGroup,
+    index: usize,
+}
+
+/// This is the implementation of the `RadioButtonWidget` that draws a radio button on the screen.
+/// Selecting a `RadioButtonWidget` deselects all of the other members of its `RadioGroup`.  A
+/// selected `RadioButtonWidget` cannot be deselected by clicking on it.
+impl RadioButtonWidget {
+    /// Creates a new `RadioButtonWidget` given the `x, y, w, h` coordinates, the `text` to display
+    /// next to the button, `font_size` of the font to display, and the `RadioGroup` it belongs to.
+    /// The `RadioButtonWidget` is added to the end of the group.
+    pub fn new(
+        points: Points,
+        size: Size,
+        text: String,
+        font_size: i32,
+        group: RadioGroup,
+    ) -> Self {
+        let mut text_widget = TextWidget::new(
+            String::from("assets/OpenSans-Regular.ttf"),
+            sdl2::ttf::FontStyle::NORMAL,
+            font_size,
+            TextJustify::Left,
+            text,
+            make_points(
+                points[POINT_X] + size[SIZE_HEIGHT] as i32 + 6,
+                points[POINT_Y] + 2,
+            ),
+            make_size(
+                size[SIZE_WIDTH] - size[SIZE_HEIGHT] - 10,
+                size[SIZE_HEIGHT] - 4,
+            ),
+        );
+
+        let mut config = WidgetConfig::new(points.clone(), size.clone());
+        let mut unchecked_widget = ImageWidget::new(
+            String::from("assets/radio_unselected.png"),
+            make_points(points[POINT_X] + 2, points[POINT_Y] + 2),
+            make_size(size[SIZE_HEIGHT] - 4, size[SIZE_HEIGHT] - 4),
+            true,
+        );
+        let mut checked_widget = ImageWidget::new(
+            String::from("assets/radio_selected.png"),
+            make_points(points[POINT_X] + 2, points[POINT_Y] + 2),
+            make_size(size[SIZE_HEIGHT] - 4, size[SIZE_HEIGHT] - 4),
+            true,
+        );
+
+        text_widget.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        unchecked_widget.set_compass(CONFIG_IMAGE_POSITION, Center);
+        checked_widget.set_compass(CONFIG_IMAGE_POSITION, Center);
+
+        let index = group.add_member();
+        let selected = group.get_selected() == Some(index);
+
+        config.set_toggle(CONFIG_SELECTED_STATE, selected);
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            text_widget,
+            unchecked_widget,
+            checked_widget,
+            active: false,
+            selected,
+            in_bounds: false,
+            originated: false,
+            group,
+            index,
+        }
+    }
+
+    /// Returns the `RadioGroup` that this `RadioButtonWidget` belongs to.
+    pub fn get_group(&self) -> RadioGroup {
+        self.group.clone()
+    }
+
+    /// Returns the index of this `RadioButtonWidget` in its `RadioGroup`.
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    /// Indicates whether or not this `RadioButtonWidget` is selected.
+    pub fn is_selected(&self) -> bool {
+        self.group.get_selected() == Some(self.index)
+    }
+
+    /// Selects this `RadioButtonWidget`, deselecting the other members of its `RadioGroup`.  This
+    /// does not trigger the `on_selection_changed` callback.
+    pub fn select(&mut self) {
+        self.group.set_selected(Some(self.index));
+        self.sync_selected();
+    }
+
+    /// Internal function that updates the selected state of this `Widget` from its `RadioGroup`.
+    fn sync_selected(&mut self) {
+        let selected = self.is_selected();
+
+        if selected != self.selected {
+            self.selected = selected;
+            self.set_toggle(CONFIG_SELECTED_STATE, selected);
+            self.get_config().set_invalidated(true);
+        }
+    }
+}
+
+impl CanvasHelper for RadioButtonWidget {}
+
+/// This is the `Widget` implementation of the `RadioButtonWidget`.
+impl Widget for RadioButtonWidget {
+    /// Draws the `RadioButtonWidget` contents.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let base_color = self.get_color(CONFIG_COLOR_BASE);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            // Paint the base widget first.  Forcing a draw() call here will ignore invalidation.
+            // Invalidation is controlled by the top level widget (this box).
+            let radio_widget_texture = if self.active {
+                if self.in_bounds || self.selected {
+                    self.checked_widget.draw(c, t).unwrap()
+                } else {
+                    self.unchecked_widget.draw(c, t).unwrap()
+                }
+            } else if self.selected {
+                self.checked_widget.draw(c, t).unwrap()
+            } else {
+                self.unchecked_widget.draw(c, t).unwrap()
+            };
+
+            let text_widget_texture = self.text_widget.draw(c, t).unwrap();
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                texture
+                    .copy(
+                        text_widget_texture,
+                        None,
+                        Rect::new(
+                            2 + bounds[1] as i32 + 6,
+                            0,
+                            bounds[0] - bounds[1],
+                            bounds[1] - 4,
+                        ),
+                    )
+                    .unwrap();
+
+                texture
+                    .copy(
+                        radio_widget_texture,
+                        None,
+                        Rect::new(2, 2, bounds[1] - 4, bounds[1] - 4),
+                    )
+                    .unwrap();
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, bounds[0], bounds[1]))
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// When a mouse enters the bounds of the `Widget`, this function is triggered.
+    fn mouse_entered(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = true;
+        self.mouse_entered_callback(_widgets, _layouts);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// When a mouse exits the bounds of the `Widget`, this function is triggered.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = false;
+        self.mouse_exited_callback(_widgets, _layouts);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Keeps the selected state of this `Widget` in sync with its `RadioGroup`, so that it is
+    /// deselected when another member of the group is selected.
+    fn tick(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.sync_selected();
+        self.tick_callback(_widgets, _layouts);
+    }
+
+    /// Overrides the `button_clicked` callback to handle selection.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 {
+            if _state {
+                self.active = true;
+                self.originated = true;
+            } else {
+                self.active = false;
+
+                if self.in_bounds && self.originated && !self.is_selected() {
+                    let group = self.group.clone();
+                    let index = self.index;
+
+                    self.select();
+                    group.call_selection_changed_callback(self, _widgets, _layouts, index);
+                }
+
+                self.originated = false;
+            }
+
+            self.get_config().set_invalidated(true);
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
@@ -0,0 +1,252 @@
+// Pushrod Widget Library
+// Scroll Bar
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::widgets::scroll_bar::ScrollBarOrientation::{ScrollBarHorizontal, ScrollBarVertical};
+
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::Canvas;
+use sdl2::video::Window;
+
+/// This is the default thickness of a `ScrollBar` track, in pixels.
+pub const SCROLL_BAR_THICKNESS: u32 = 14;
+
+/// This is the smallest length that a `ScrollBar` thumb will be drawn at, in pixels.
+const MIN_THUMB_LENGTH: u32 = 16;
+
+/// These are the possible scroll bar orientations.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ScrollBarOrientation {
+    /// Indicates a scroll bar that scrolls content left and right.
+    ScrollBarHorizontal,
+
+    /// Indicates a scroll bar that scrolls content up and down.
+    ScrollBarVertical,
+}
+
+/// This is the storage object for a `ScrollBar`.  A `ScrollBar` is not a `Widget`: it is a helper
+/// that a `Widget` uses to track a scroll position, draw a scroll bar inside its own texture, and
+/// handle dragging and paging with the mouse.  All coordinates passed to a `ScrollBar` are relative
+/// to the `Widget` that owns it.
+#[derive(Clone, Debug)]
+pub struct ScrollBar {
+    orientation: ScrollBarOrientation,
+    content_length: u32,
+    viewport_length: u32,
+    position: u32,
+    drag_origin: Option<(i32, u32)>,
+}
+
+/// This is the implementation of the `ScrollBar`.
+impl ScrollBar {
+    /// Creates a new `ScrollBar` with the specified `orientation`.  The scroll position starts at
+    /// `0`, with no content to scroll.
+    pub fn new(orientation: ScrollBarOrientation) -> Self {
+        Self {
+            orientation,
+            content_length: 0,
+            viewport_length: 0,
+            position: 0,
+            drag_origin: None,
+        }
+    }
+
+    /// Sets the length of the content being scrolled, and the length of the visible portion of
+    /// that content.  The scroll position is clamped to the new range.
+    pub fn set_lengths(&mut self, content_length: u32, viewport_length: u32) {
+        self.content_length = content_length;
+        self.viewport_length = viewport_length;
+        self.position = self.position.min(self.get_max_position());
+    }
+
+    /// Returns the length of the content being scrolled.
+    pub fn get_content_length(&self) -> u32 {
+        self.content_length
+    }
+
+    /// Returns the length of the visible portion of the content.
+    pub fn get_viewport_length(&self) -> u32 {
+        self.viewport_length
+    }
+
+    /// Returns the largest scroll position possible for the current content and viewport lengths.
+    pub fn get_max_position(&self) -> u32 {
+        self.content_length.saturating_sub(self.viewport_length)
+    }
+
+    /// Returns the current scroll position.
+    pub fn get_position(&self) -> u32 {
+        self.position
+    }
+
+    /// Sets the scroll position, clamping it to the scrollable range.  Returns `true` if the
+    /// position changed, `false` otherwise.
+    pub fn set_position(&mut self, position: i32) -> bool {
+        let position = position.max(0).min(self.get_max_position() as i32) as u32;
+        let changed = position != self.position;
+
+        self.position = position;
+        changed
+    }
+
+    /// Moves the scroll position by `amount` pixels.  Returns `true` if the position changed.
+    pub fn scroll_by(&mut self, amount: i32) -> bool {
+        self.set_position(self.position as i32 + amount)
+    }
+
+    /// Indicates whether or not the content is larger than the viewport, and needs to be scrolled.
+    pub fn is_needed(&self) -> bool {
+        self.content_length > self.viewport_length
+    }
+
+    /// Indicates whether or not the thumb is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag_origin.is_some()
+    }
+
+    /// Returns the area of the thumb inside of the `track` area.
+    pub fn get_thumb_area(&self, track: Rect) -> Rect {
+        let track_length = self.get_track_length(track);
+
+        if !self.is_needed() {
+            return track;
+        }
+
+        let thumb_length = ((u64::from(track_length) * u64::from(self.viewport_length)
+            / u64::from(self.content_length)) as u32)
+            .max(MIN_THUMB_LENGTH)
+            .min(track_length);
+        let thumb_offset = (u64::from(track_length - thumb_length) * u64::from(self.position)
+            / u64::from(self.get_max_position())) as i32;
+
+        match self.orientation {
+            ScrollBarHorizontal => Rect::new(
+                track.x() + thumb_offset,
+                track.y(),
+                thumb_length,
+                track.height(),
+            ),
+            ScrollBarVertical => Rect::new(
+                track.x(),
+                track.y() + thumb_offset,
+                track.width(),
+                thumb_length,
+            ),
+        }
+    }
+
+    /// Handles a mouse button press at `x, y` inside of the `track` area.  Pressing on the thumb
+    /// starts dragging it, and pressing on the track above or below the thumb pages the content by
+    /// one viewport length.  Returns `true` if the press was inside the `track`, `false` otherwise.
+    pub fn button_down(&mut self, track: Rect, x: i32, y: i32) -> bool {
+        if !self.is_needed() || !track.contains_point((x, y)) {
+            return false;
+        }
+
+        let thumb = self.get_thumb_area(track);
+        let coordinate = self.get_coordinate(x, y);
+
+        if thumb.contains_point((x, y)) {
+            self.drag_origin = Some((coordinate, self.position));
+        } else {
+            let thumb_start = match self.orientation {
+                ScrollBarHorizontal => thumb.x(),
+                ScrollBarVertical => thumb.y(),
+            };
+
+            if coordinate < thumb_start {
+                self.scroll_by(-(self.viewport_length as i32));
+            } else {
+                self.scroll_by(self.viewport_length as i32);
+            }
+        }
+
+        true
+    }
+
+    /// Handles a mouse move to `x, y` while the thumb is being dragged inside of the `track` area.
+    /// Returns `true` if the scroll position changed.
+    pub fn mouse_moved(&mut self, track: Rect, x: i32, y: i32) -> bool {
+        match self.drag_origin {
+            Some((start_coordinate, start_position)) => {
+                let thumb = self.get_thumb_area(track);
+                let thumb_length = match self.orientation {
+                    ScrollBarHorizontal => thumb.width(),
+                    ScrollBarVertical => thumb.height(),
+                };
+                let travel = self.get_track_length(track).saturating_sub(thumb_length);
+
+                if travel == 0 {
+                    return false;
+                }
+
+                let delta = i64::from(self.get_coordinate(x, y) - start_coordinate)
+                    * i64::from(self.get_max_position())
+                    / i64::from(travel);
+
+                self.set_position(start_position as i32 + delta as i32)
+            }
+            None => false,
+        }
+    }
+
+    /// Handles a mouse button release, ending any thumb drag in progress.
+    pub fn button_up(&mut self) {
+        self.drag_origin = None;
+    }
+
+    /// Draws the scroll bar into the `track` area of the `Canvas`, using `track_color` for the
+    /// track, and `thumb_color` for the thumb.  If the content does not need to be scrolled, only
+    /// the track is drawn.
+    pub fn draw(
+        &self,
+        c: &mut Canvas<Window>,
+        track: Rect,
+        track_color: Color,
+        thumb_color: Color,
+    ) {
+        c.set_draw_color(track_color);
+        c.fill_rect(track).unwrap();
+
+        if self.is_needed() {
+            let thumb = self.get_thumb_area(track);
+
+            c.set_draw_color(thumb_color);
+            c.fill_rect(Rect::new(
+                thumb.x() + 2,
+                thumb.y() + 2,
+                thumb.width().saturating_sub(4).max(1),
+                thumb.height().saturating_sub(4).max(1),
+            ))
+            .unwrap();
+        }
+    }
+
+    // Private functions
+
+    fn get_track_length(&self, track: Rect) -> u32 {
+        match self.orientation {
+            ScrollBarHorizontal => track.width(),
+            ScrollBarVertical => track.height(),
+        }
+    }
+
+    fn get_coordinate(&self, x: i32, y: i32) -> i32 {
+        match self.orientation {
+            ScrollBarHorizontal => x,
+            ScrollBarVertical => y,
+        }
+    }
+}
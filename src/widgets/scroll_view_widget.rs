@@ -0,0 +1,376 @@
+// Pushrod Widget Library
+// Scroll View Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y};
+
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::widgets::scroll_bar::ScrollBarOrientation::{ScrollBarHorizontal, ScrollBarVertical};
+use crate::widgets::scroll_bar::{ScrollBar, SCROLL_BAR_THICKNESS};
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the number of pixels that the content is scrolled for each step of the mouse wheel.
+const SCROLL_STEP: i32 = 20;
+
+/// This is the callback type that is used when an `on_scrolled` callback is triggered from this
+/// `Widget`.  It receives the new scroll position as X and Y points.
+pub type OnScrolledCallbackType =
+    Option<Box<dyn FnMut(&mut ScrollViewWidget, &[WidgetContainer], &[LayoutContainer], Points)>>;
+
+/// This is the storage object for the `ScrollViewWidget`.  It stores the config, properties, callback
+/// registry, the size of the content area, the horizontal and vertical `ScrollBar`s, and the last
+/// known position of the mouse inside the `Widget`.
+pub struct ScrollViewWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    content_size: Size,
+    horizontal: ScrollBar,
+    vertical: ScrollBar,
+    mouse_point: Points,
+    on_scrolled: OnScrolledCallbackType,
+}
+
+/// This is the implementation of the `ScrollViewWidget`, which hosts child `Widget`s inside of a
+/// virtual content area that can be larger than the `Widget` itself.  Children are added with
+/// `Engine::add_widget_to_parent`, using points of origin relative to the top left of the content
+/// area.  Children are clipped to the visible portion of the content area, and are moved as the
+/// content is scrolled.  Scroll bars are shown along the right and bottom edges when the content
+/// is larger than the visible area, and can be dragged or paged with the mouse.  The mouse wheel
+/// scrolls the content when the mouse is over the `ScrollViewWidget`, or any of its children.
+impl ScrollViewWidget {
+    /// Creates a new `ScrollViewWidget` given the `x, y, w, h` coordinates, and the `w, h` size of
+    /// the content area.
+    pub fn new(points: Points, size: Size, content_size: Size) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_SCROLLABLE, true);
+        config.set_toggle(CONFIG_MOUSE_CAPTURE, true);
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(224, 224, 224));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            content_size,
+            horizontal: ScrollBar::new(ScrollBarHorizontal),
+            vertical: ScrollBar::new(ScrollBarVertical),
+            mouse_point: make_points(0, 0),
+            on_scrolled: None,
+        }
+    }
+
+    /// Sets the size of the content area.  The scroll position is clamped to the new size.
+    pub fn set_content_size(&mut self, content_size: Size) {
+        self.content_size = content_size;
+        self.update_scroll_bars();
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the size of the content area.
+    pub fn get_content_size(&self) -> Size {
+        self.content_size.clone()
+    }
+
+    /// Returns the size of the visible portion of the content area, which is the size of the
+    /// `Widget`, minus its border and any scroll bars that are shown.
+    pub fn get_viewport_size(&mut self) -> Size {
+        self.update_scroll_bars();
+
+        vec![
+            self.horizontal.get_viewport_length(),
+            self.vertical.get_viewport_length(),
+        ]
+    }
+
+    /// Scrolls the content so that the point `x, y` of the content area is shown at the top left
+    /// of the visible area.  The position is clamped so that the content never scrolls past its
+    /// edges.  This does not trigger the `on_scrolled` callback.
+    pub fn scroll_to(&mut self, x: i32, y: i32) {
+        self.update_scroll_bars();
+
+        let changed_x = self.horizontal.set_position(x);
+        let changed_y = self.vertical.set_position(y);
+
+        if changed_x || changed_y {
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Returns the current scroll position as X and Y points of the content area.
+    pub fn get_scroll_position(&self) -> Points {
+        make_points(
+            self.horizontal.get_position() as i32,
+            self.vertical.get_position() as i32,
+        )
+    }
+
+    /// Assigns the callback closure that will be used when the content is scrolled by the user.
+    pub fn on_scrolled<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut ScrollViewWidget, &[WidgetContainer], &[LayoutContainer], Points) + 'static,
+    {
+        self.on_scrolled = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_scrolled` callback.
+    fn call_scrolled_callback(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+        if let Some(mut cb) = self.on_scrolled.take() {
+            let position = self.get_scroll_position();
+
+            cb(self, widgets, layouts, position);
+            self.on_scrolled = Some(cb);
+        }
+    }
+
+    /// Internal function that works out which scroll bars are shown for the current size of the
+    /// `Widget` and its content, and updates the `ScrollBar`s to match.  Returns the track areas
+    /// of the horizontal and vertical scroll bars, relative to the `Widget`, if they are shown.
+    fn update_scroll_bars(&mut self) -> (Option<Rect>, Option<Rect>) {
+        let size = self.get_config().get_size(CONFIG_SIZE);
+        let inner_width = size[0].saturating_sub(2);
+        let inner_height = size[1].saturating_sub(2);
+        let mut show_vertical = self.content_size[1] > inner_height;
+        let show_horizontal = self.content_size[0]
+            > inner_width.saturating_sub(if show_vertical {
+                SCROLL_BAR_THICKNESS
+            } else {
+                0
+            });
+
+        if show_horizontal && !show_vertical {
+            show_vertical =
+                self.content_size[1] > inner_height.saturating_sub(SCROLL_BAR_THICKNESS);
+        }
+
+        let viewport_width = if show_vertical {
+            inner_width.saturating_sub(SCROLL_BAR_THICKNESS)
+        } else {
+            inner_width
+        };
+        let viewport_height = if show_horizontal {
+            inner_height.saturating_sub(SCROLL_BAR_THICKNESS)
+        } else {
+            inner_height
+        };
+
+        self.horizontal
+            .set_lengths(self.content_size[0], viewport_width);
+        self.vertical
+            .set_lengths(self.content_size[1], viewport_height);
+
+        (
+            if show_horizontal {
+                Some(Rect::new(
+                    1,
+                    1 + viewport_height as i32,
+                    viewport_width.max(1),
+                    SCROLL_BAR_THICKNESS,
+                ))
+            } else {
+                None
+            },
+            if show_vertical {
+                Some(Rect::new(
+                    1 + viewport_width as i32,
+                    1,
+                    SCROLL_BAR_THICKNESS,
+                    viewport_height.max(1),
+                ))
+            } else {
+                None
+            },
+        )
+    }
+}
+
+/// This is the `Widget` implementation of the `ScrollViewWidget`.
+impl Widget for ScrollViewWidget {
+    /// Draws the `ScrollViewWidget` background, its border, and its scroll bars.
+    fn draw(&mut self, c: &mut Canvas<Window>, _t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let base_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let track_color = self.get_config().get_color(CONFIG_COLOR_HOVER);
+            let thumb_color = self.get_config().get_color(CONFIG_COLOR_SECONDARY);
+            let (horizontal_track, vertical_track) = self.update_scroll_bars();
+            let horizontal = &self.horizontal;
+            let vertical = &self.vertical;
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                if let Some(track) = horizontal_track {
+                    horizontal.draw(texture, track, track_color, thumb_color);
+                }
+
+                if let Some(track) = vertical_track {
+                    vertical.draw(texture, track, track_color, thumb_color);
+                }
+
+                // The corner between both scroll bars is filled in with the track color.
+                if let (Some(h_track), Some(v_track)) = (horizontal_track, vertical_track) {
+                    texture.set_draw_color(track_color);
+                    texture
+                        .fill_rect(Rect::new(
+                            v_track.x(),
+                            h_track.y(),
+                            SCROLL_BAR_THICKNESS,
+                            SCROLL_BAR_THICKNESS,
+                        ))
+                        .unwrap();
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, bounds[0], bounds[1]))
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Children are clipped to the visible portion of the content area.
+    fn get_client_area(&mut self) -> Rect {
+        let viewport = self.get_viewport_size();
+
+        Rect::new(
+            self.get_config().to_x(1),
+            self.get_config().to_y(1),
+            viewport[0].max(1),
+            viewport[1].max(1),
+        )
+    }
+
+    /// Children are offset by the current scroll position.
+    fn get_content_offset(&mut self) -> Points {
+        self.get_scroll_position()
+    }
+
+    /// Drags the thumb of a scroll bar, if one is being dragged.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = _points[POINT_X] - origin[POINT_X];
+        let y = _points[POINT_Y] - origin[POINT_Y];
+        let (horizontal_track, vertical_track) = self.update_scroll_bars();
+        let mut changed = false;
+
+        self.mouse_point = make_points(x, y);
+
+        if let Some(track) = horizontal_track {
+            changed |= self.horizontal.mouse_moved(track, x, y);
+        }
+
+        if let Some(track) = vertical_track {
+            changed |= self.vertical.mouse_moved(track, x, y);
+        }
+
+        if changed {
+            self.get_config().set_invalidated(true);
+            self.call_scrolled_callback(_widgets, _layouts);
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Scrolls the content vertically, or horizontally for horizontal wheel movement.
+    fn mouse_scrolled(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        self.update_scroll_bars();
+
+        let changed_x = self.horizontal.scroll_by(_points[POINT_X] * SCROLL_STEP);
+        let changed_y = self.vertical.scroll_by(-_points[POINT_Y] * SCROLL_STEP);
+
+        if changed_x || changed_y {
+            self.get_config().set_invalidated(true);
+            self.call_scrolled_callback(_widgets, _layouts);
+        }
+
+        self.mouse_scrolled_callback(_widgets, _layouts, _points);
+    }
+
+    /// Starts dragging or paging a scroll bar when the mouse button is pressed over it, and stops
+    /// dragging when the mouse button is released.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 {
+            if _state {
+                let (horizontal_track, vertical_track) = self.update_scroll_bars();
+                let position = self.get_scroll_position();
+                let x = self.mouse_point[POINT_X];
+                let y = self.mouse_point[POINT_Y];
+
+                if let Some(track) = horizontal_track {
+                    self.horizontal.button_down(track, x, y);
+                }
+
+                if let Some(track) = vertical_track {
+                    self.vertical.button_down(track, x, y);
+                }
+
+                if position != self.get_scroll_position() {
+                    self.get_config().set_invalidated(true);
+                    self.call_scrolled_callback(_widgets, _layouts);
+                }
+            } else {
+                self.horizontal.button_up();
+                self.vertical.button_up();
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
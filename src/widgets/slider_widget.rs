@@ -67,7 +67,8 @@ pub struct SliderWidget {
 /// draggable slider.
 impl SliderWidget {
     /// Creates a new `SliderWidget` given the `x, y, w, h` coordinates, sets the `min` and `max` values,
-    /// the `current` value, and the `orientation` of the slider as drawn.
+    /// the `current` value, and the `orientation` of the slider as drawn.  The slider responds to
+    /// the mouse wheel, so it is marked as scrollable.
     pub fn new(
         points: Points,
         size: Size,
@@ -76,8 +77,12 @@ impl SliderWidget {
         current: u32,
         orientation: SliderOrientation,
     ) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_SCROLLABLE, true);
+
         Self {
-            config: WidgetConfig::new(points, size),
+            config,
             system_properties: HashMap::new(),
             callback_registry: CallbackRegistry::new(),
             texture_store: TextureStore::default(),
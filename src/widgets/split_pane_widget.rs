@@ -0,0 +1,508 @@
+// Pushrod Widget Library
+// Split Pane Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::layout::{place_item, set_item_visible, LayoutItem};
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+use crate::render::{Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the thickness of the divider between the two panes, in pixels.
+const DIVIDER_SIZE: u32 = 6;
+
+/// These are the possible split pane orientations.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SplitPaneOrientation {
+    /// Indicates that the panes are shown side by side, with a vertical divider between them.
+    SplitPaneHorizontal,
+
+    /// Indicates that the panes are shown one above the other, with a horizontal divider between
+    /// them.
+    SplitPaneVertical,
+}
+
+/// These are the two panes of a `SplitPaneWidget`.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SplitPaneSide {
+    /// Indicates the left pane, or the top pane of a vertical split.
+    SplitPaneFirst,
+
+    /// Indicates the right pane, or the bottom pane of a vertical split.
+    SplitPaneSecond,
+}
+
+/// This is the callback type that is used when an `on_divider_moved` callback is triggered from
+/// this `Widget`.  Returns the new position of the divider, which is the size of the first pane.
+pub type OnDividerMovedCallbackType =
+    Option<Box<dyn FnMut(&mut SplitPaneWidget, &[WidgetContainer], &[LayoutContainer], u32)>>;
+
+/// This is the storage object for the `SplitPaneWidget`.  It stores the config, properties,
+/// callback registry, the items shown in each pane, and the state of the divider.
+pub struct SplitPaneWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    orientation: SplitPaneOrientation,
+    panes: [Option<LayoutItem>; 2],
+    min_sizes: [u32; 2],
+    divider_position: u32,
+    collapsed: Option<SplitPaneSide>,
+    hidden_pane: Option<SplitPaneSide>,
+    collapse_side: SplitPaneSide,
+    mouse_position: i32,
+    hovered: bool,
+    drag_offset: Option<i32>,
+    placed_area: Option<Rect>,
+    panes_invalidated: bool,
+    on_divider_moved: OnDividerMovedCallbackType,
+}
+
+/// This is the implementation of the `SplitPaneWidget`, which divides its area into two panes
+/// separated by a divider that can be dragged with the mouse.  Each pane shows a `LayoutItem`:
+/// either a `Widget`, which is moved and sized to fill the pane, or a `Layout`, whose bounds are
+/// set to the pane and which is laid out again whenever the divider moves.  The `Widget`s in the
+/// panes should be added to the `Engine` as children of the `SplitPaneWidget`, so that they are
+/// clipped to it, and move along with it.
+///
+/// Each pane can be given a minimum size, which the divider cannot be dragged past.  Double
+/// clicking the divider collapses one of the panes, and double clicking it again restores it.
+/// The position of the divider can be read and set, so that it can be saved and restored by the
+/// application.
+impl SplitPaneWidget {
+    /// Creates a new `SplitPaneWidget`, given the `x, y, w, h` coordinates, and the orientation of
+    /// the split.  The divider starts in the middle of the `Widget`.
+    pub fn new(points: Points, size: Size, orientation: SplitPaneOrientation) -> Self {
+        let mut config = WidgetConfig::new(points, size.clone());
+
+        config.set_color(CONFIG_COLOR_BASE, Color::RGB(255, 255, 255));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(224, 224, 224));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(192, 192, 192));
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(128, 128, 128));
+        config.set_toggle(CONFIG_MOUSE_CAPTURE, true);
+
+        let extent = match orientation {
+            SplitPaneOrientation::SplitPaneHorizontal => size[SIZE_WIDTH],
+            SplitPaneOrientation::SplitPaneVertical => size[SIZE_HEIGHT],
+        };
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            orientation,
+            panes: [None, None],
+            min_sizes: [0, 0],
+            divider_position: extent.saturating_sub(DIVIDER_SIZE) / 2,
+            collapsed: None,
+            hidden_pane: None,
+            collapse_side: SplitPaneSide::SplitPaneFirst,
+            mouse_position: 0,
+            hovered: false,
+            drag_offset: None,
+            placed_area: None,
+            panes_invalidated: true,
+            on_divider_moved: None,
+        }
+    }
+
+    /// Sets the item shown in the first pane: the left pane, or the top pane of a vertical split.
+    pub fn set_first_pane(&mut self, item: LayoutItem) {
+        self.panes[0] = Some(item);
+        self.forget_hidden_pane(SplitPaneSide::SplitPaneFirst);
+        self.panes_invalidated = true;
+    }
+
+    /// Sets the item shown in the second pane: the right pane, or the bottom pane of a vertical
+    /// split.
+    pub fn set_second_pane(&mut self, item: LayoutItem) {
+        self.panes[1] = Some(item);
+        self.forget_hidden_pane(SplitPaneSide::SplitPaneSecond);
+        self.panes_invalidated = true;
+    }
+
+    /// Sets the minimum size of the first and second pane, in pixels.  The divider cannot be
+    /// dragged past these sizes, unless a pane is collapsed.
+    pub fn set_min_sizes(&mut self, first: u32, second: u32) {
+        self.min_sizes = [first, second];
+        self.invalidate_panes();
+    }
+
+    /// Returns the position of the divider, which is the size of the first pane when neither pane
+    /// is collapsed.  This can be stored by the application, and restored with
+    /// `set_divider_position`.
+    pub fn get_divider_position(&self) -> u32 {
+        self.clamp_position(self.divider_position as i32)
+    }
+
+    /// Moves the divider to `position`, which is the size of the first pane.  The position is kept
+    /// within the minimum sizes of the panes.  If a pane is collapsed, it is restored.
+    pub fn set_divider_position(&mut self, position: u32) {
+        self.divider_position = position;
+        self.collapsed = None;
+        self.invalidate_panes();
+    }
+
+    /// Sets the pane that is collapsed when the divider is double clicked.  Defaults to the first
+    /// pane.
+    pub fn set_collapse_side(&mut self, side: SplitPaneSide) {
+        self.collapse_side = side;
+    }
+
+    /// Collapses the pane specified by `side`, giving the other pane all of the space.  The divider
+    /// position is remembered, and used again when the pane is restored with `expand`.
+    pub fn collapse(&mut self, side: SplitPaneSide) {
+        self.collapsed = Some(side);
+        self.invalidate_panes();
+    }
+
+    /// Restores the collapsed pane, if any, moving the divider back to its previous position.
+    pub fn expand(&mut self) {
+        self.collapsed = None;
+        self.invalidate_panes();
+    }
+
+    /// Returns the pane that is currently collapsed, if any.
+    pub fn get_collapsed(&self) -> Option<SplitPaneSide> {
+        self.collapsed
+    }
+
+    /// Assigns the callback closure that will be used when the divider is moved with the mouse, or
+    /// a pane is collapsed or restored by double clicking the divider.
+    pub fn on_divider_moved<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut SplitPaneWidget, &[WidgetContainer], &[LayoutContainer], u32) + 'static,
+    {
+        self.on_divider_moved = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_divider_moved` callback.
+    fn call_divider_moved_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        position: u32,
+    ) {
+        if let Some(mut cb) = self.on_divider_moved.take() {
+            cb(self, widgets, layouts, position);
+            self.on_divider_moved = Some(cb);
+        }
+    }
+
+    /// Marks the panes to be placed again on the next tick, and redraws the divider.
+    fn invalidate_panes(&mut self) {
+        self.panes_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the length of the `Widget` in the direction of the split.
+    fn get_extent(&self) -> u32 {
+        let size = self.config.get_size(CONFIG_SIZE);
+
+        match self.orientation {
+            SplitPaneOrientation::SplitPaneHorizontal => size[SIZE_WIDTH],
+            SplitPaneOrientation::SplitPaneVertical => size[SIZE_HEIGHT],
+        }
+    }
+
+    /// Returns the space shared by both panes, which is the length of the `Widget` without the
+    /// divider.
+    fn get_available(&self) -> u32 {
+        self.get_extent().saturating_sub(DIVIDER_SIZE)
+    }
+
+    /// Keeps a divider `position` within the minimum sizes of the panes.  If both minimum sizes do
+    /// not fit, the minimum size of the first pane wins.
+    fn clamp_position(&self, position: i32) -> u32 {
+        let available = self.get_available();
+        let max_position = available.saturating_sub(self.min_sizes[1]);
+        let min_position = self.min_sizes[0].min(available);
+
+        (position.max(0) as u32).min(max_position).max(min_position)
+    }
+
+    /// Returns the position at which the divider is currently drawn, taking a collapsed pane into
+    /// account.
+    fn get_current_position(&self) -> u32 {
+        match self.collapsed {
+            Some(SplitPaneSide::SplitPaneFirst) => 0,
+            Some(SplitPaneSide::SplitPaneSecond) => self.get_available(),
+            None => self.get_divider_position(),
+        }
+    }
+
+    /// Returns the areas of the first pane, the divider, and the second pane, relative to the
+    /// `Widget`.  Collapsed panes are given an area of one pixel.
+    fn get_areas(&self) -> (Rect, Rect, Rect) {
+        let size = self.config.get_size(CONFIG_SIZE);
+        let position = self.get_current_position();
+        let remaining = self.get_available() - position;
+
+        match self.orientation {
+            SplitPaneOrientation::SplitPaneHorizontal => (
+                Rect::new(0, 0, position.max(1), size[SIZE_HEIGHT]),
+                Rect::new(position as i32, 0, DIVIDER_SIZE, size[SIZE_HEIGHT]),
+                Rect::new(
+                    (position + DIVIDER_SIZE) as i32,
+                    0,
+                    remaining.max(1),
+                    size[SIZE_HEIGHT],
+                ),
+            ),
+            SplitPaneOrientation::SplitPaneVertical => (
+                Rect::new(0, 0, size[SIZE_WIDTH], position.max(1)),
+                Rect::new(0, position as i32, size[SIZE_WIDTH], DIVIDER_SIZE),
+                Rect::new(
+                    0,
+                    (position + DIVIDER_SIZE) as i32,
+                    size[SIZE_WIDTH],
+                    remaining.max(1),
+                ),
+            ),
+        }
+    }
+
+    /// Internal function that marks the item in the pane on `side` as not hidden by this `Widget`,
+    /// after it has been replaced, so that a new item is hidden if the pane is collapsed.
+    fn forget_hidden_pane(&mut self, side: SplitPaneSide) {
+        if self.hidden_pane == Some(side) {
+            self.hidden_pane = None;
+        }
+    }
+
+    /// Moves and sizes the items in both panes to fill their areas.  `Layout`s are laid out again
+    /// within their new bounds.  When a pane is collapsed, its item is hidden, along with every
+    /// `Widget` in it, and it is shown again when the pane is restored.  Visibility is only changed
+    /// when the collapsed pane changes, so the application can hide the items on its own.
+    fn place_panes(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+        let (first_area, _, second_area) = self.get_areas();
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let sides = [
+            SplitPaneSide::SplitPaneFirst,
+            SplitPaneSide::SplitPaneSecond,
+        ];
+
+        for (i, area) in [first_area, second_area].iter().enumerate() {
+            if let Some(item) = self.panes[i] {
+                if self.hidden_pane != self.collapsed {
+                    if self.hidden_pane == Some(sides[i]) {
+                        set_item_visible(item, widgets, layouts, true);
+                    } else if self.collapsed == Some(sides[i]) {
+                        set_item_visible(item, widgets, layouts, false);
+                    }
+                }
+
+                place_item(
+                    item,
+                    widgets,
+                    layouts,
+                    vec![origin[POINT_X] + area.x(), origin[POINT_Y] + area.y()],
+                    vec![area.width(), area.height()],
+                );
+            }
+        }
+
+        self.hidden_pane = self.collapsed;
+        self.placed_area = Some(self.get_drawing_area());
+        self.panes_invalidated = false;
+    }
+
+    /// Returns the position of the mouse along the direction of the split, relative to the
+    /// `Widget`.
+    fn get_mouse_position(&self, points: &[i32]) -> i32 {
+        let origin = self.config.get_point(CONFIG_ORIGIN);
+
+        match self.orientation {
+            SplitPaneOrientation::SplitPaneHorizontal => points[POINT_X] - origin[POINT_X],
+            SplitPaneOrientation::SplitPaneVertical => points[POINT_Y] - origin[POINT_Y],
+        }
+    }
+
+    /// Indicates whether or not the mouse position is over the divider.
+    fn is_over_divider(&self, mouse_position: i32) -> bool {
+        let position = self.get_current_position() as i32;
+
+        mouse_position >= position && mouse_position < position + DIVIDER_SIZE as i32
+    }
+}
+
+/// This is the `Widget` implementation of the `SplitPaneWidget`.
+impl Widget for SplitPaneWidget {
+    /// Draws the background and the divider.  The items in the panes are drawn as children of this
+    /// `Widget`.
+    fn draw(&mut self, c: &mut Canvas<Window>, _t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let base_color = self.get_color(CONFIG_COLOR_BASE);
+            let divider_color = if self.hovered || self.drag_offset.is_some() {
+                self.get_color(CONFIG_COLOR_HOVER)
+            } else {
+                self.get_color(CONFIG_COLOR_SECONDARY)
+            };
+            let border_color = self.get_color(CONFIG_COLOR_BORDER);
+            let (_, divider_area, _) = self.get_areas();
+            let center = divider_area.center();
+            let grip_offsets = [-6, 0, 6];
+            let orientation = self.orientation;
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[SIZE_WIDTH], bounds[SIZE_HEIGHT]);
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                texture.set_draw_color(divider_color);
+                texture.fill_rect(divider_area).unwrap();
+
+                texture.set_draw_color(border_color);
+
+                for offset in grip_offsets.iter() {
+                    let grip = match orientation {
+                        SplitPaneOrientation::SplitPaneHorizontal => {
+                            Rect::new(center.x() - 1, center.y() + offset - 1, 2, 2)
+                        }
+                        SplitPaneOrientation::SplitPaneVertical => {
+                            Rect::new(center.x() + offset - 1, center.y() - 1, 2, 2)
+                        }
+                    };
+
+                    texture.fill_rect(grip).unwrap();
+                }
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Places the items in the panes when the divider has moved, or when this `Widget` has been
+    /// moved or resized.
+    fn tick(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        let area = self.get_drawing_area();
+
+        if self.panes_invalidated || self.placed_area != Some(area) {
+            self.place_panes(_widgets, _layouts);
+            self.get_config().set_invalidated(true);
+        }
+
+        self.tick_callback(_widgets, _layouts);
+    }
+
+    /// Clears the divider highlight when the mouse leaves the `Widget`.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.hovered {
+            self.hovered = false;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Highlights the divider when the mouse is over it, and moves the divider while it is being
+    /// dragged.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        self.mouse_position = self.get_mouse_position(&_points);
+
+        if let Some(drag_offset) = self.drag_offset {
+            let position = self.clamp_position(self.mouse_position - drag_offset);
+
+            if self.collapsed.is_some() || position != self.divider_position {
+                self.divider_position = position;
+                self.collapsed = None;
+                self.place_panes(_widgets, _layouts);
+                self.get_config().set_invalidated(true);
+                self.call_divider_moved_callback(_widgets, _layouts, position);
+            }
+        } else {
+            let hovered = self.is_over_divider(self.mouse_position);
+
+            if hovered != self.hovered {
+                self.hovered = hovered;
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Starts dragging the divider when it is pressed, and stops when the mouse button is released.
+    /// Double clicking the divider collapses a pane, or restores it if it is already collapsed.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 {
+            if !_state {
+                if self.drag_offset.take().is_some() {
+                    self.hovered = self.is_over_divider(self.mouse_position);
+                    self.get_config().set_invalidated(true);
+                }
+            } else if self.hovered && _clicks == 2 {
+                if self.collapsed.is_some() {
+                    self.collapsed = None;
+                } else {
+                    self.collapsed = Some(self.collapse_side);
+                }
+
+                self.drag_offset = None;
+                self.place_panes(_widgets, _layouts);
+                self.get_config().set_invalidated(true);
+
+                let position = self.get_current_position();
+
+                self.call_divider_moved_callback(_widgets, _layouts, position);
+            } else if self.hovered {
+                self.drag_offset = Some(self.mouse_position - self.get_current_position() as i32);
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Places the panes again when this `Widget` is resized.
+    fn on_config_changed(&mut self, _k: u8, _v: Config) {
+        if _k == CONFIG_SIZE {
+            self.panes_invalidated = true;
+        }
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
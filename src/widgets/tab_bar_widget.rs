@@ -97,34 +97,9 @@ impl TabBarWidget {
 
     /// Adjusts the widgets being displayed on screen.  Internal function.
     fn adjust_widgets(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) {
-        let ttf_context = t.get_ttf_context();
-        let texture_creator = c.texture_creator();
-        let num_tabs = self.tab_items.len();
-        let mut font = ttf_context
-            .load_font(Path::new(&String::from("assets/OpenSans-Regular.ttf")), 10)
-            .unwrap();
-        let mut tab_widths = Vec::new();
         let bounds = self.get_config().get_size(CONFIG_SIZE);
 
-        font.set_style(sdl2::ttf::FontStyle::NORMAL);
-
-        for i in 0..num_tabs {
-            let surface = font
-                .render(&self.tab_items[i])
-                .blended_wrapped(Color::RGB(0, 0, 0), bounds[SIZE_WIDTH])
-                .map_err(|e| e.to_string())
-                .unwrap();
-            let font_texture = texture_creator
-                .create_texture_from_surface(&surface)
-                .map_err(|e| e.to_string())
-                .unwrap();
-
-            let TextureQuery { width, .. } = font_texture.query();
-
-            tab_widths.push((width + 20) as u32);
-        }
-
-        self.tab_widths = tab_widths;
+        self.tab_widths = measure_tab_widths(c, t, &self.tab_items, bounds[SIZE_WIDTH]);
         self.calculated = true;
     }
 
@@ -147,6 +122,49 @@ impl TabBarWidget {
     }
 }
 
+/// Measures the width of the text of each tab item in `tab_items`, wrapped to `max_width`, and
+/// returns the widths with 20 pixels of padding added to each.  This is shared by the `Widget`s that
+/// draw tabs.
+pub fn measure_tab_widths(
+    c: &mut Canvas<Window>,
+    t: &mut TextureCache,
+    tab_items: &[String],
+    max_width: u32,
+) -> Vec<u32> {
+    let ttf_context = t.get_ttf_context();
+    let texture_creator = c.texture_creator();
+    let mut font = ttf_context
+        .load_font(Path::new(&String::from("assets/OpenSans-Regular.ttf")), 10)
+        .unwrap();
+    let mut tab_widths = Vec::new();
+
+    font.set_style(sdl2::ttf::FontStyle::NORMAL);
+
+    for tab_item in tab_items {
+        // An empty title cannot be rendered, so its tab is only as wide as its padding.
+        if tab_item.is_empty() {
+            tab_widths.push(20);
+            continue;
+        }
+
+        let surface = font
+            .render(tab_item)
+            .blended_wrapped(Color::RGB(0, 0, 0), max_width)
+            .map_err(|e| e.to_string())
+            .unwrap();
+        let font_texture = texture_creator
+            .create_texture_from_surface(&surface)
+            .map_err(|e| e.to_string())
+            .unwrap();
+
+        let TextureQuery { width, .. } = font_texture.query();
+
+        tab_widths.push((width + 20) as u32);
+    }
+
+    tab_widths
+}
+
 /// This is the `Widget` implementation of the `TabBarWidget`.
 impl Widget for TabBarWidget {
     fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
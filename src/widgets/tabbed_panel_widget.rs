@@ -0,0 +1,737 @@
+// Pushrod Widget Library
+// Tabbed Panel Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+use crate::render::{Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout::{place_item, set_item_visible, LayoutItem};
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::widgets::tab_bar_widget::measure_tab_widths;
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the X position of the first tab.
+const TAB_START_X: i32 = 10;
+
+/// This is the amount of space added to the measured width of each tab.
+const TAB_SPACING: u32 = 30;
+
+/// This is the width of the close button drawn in closable tabs.
+const CLOSE_WIDTH: u32 = 16;
+
+/// This is the width of each of the arrow buttons shown when the tabs do not fit.
+const ARROW_WIDTH: u32 = 20;
+
+/// This is the callback type that is used when an `on_tab_selected` callback is triggered from this
+/// `Widget`.  Returns a number indicating the tab that was selected, starting from `0`.
+pub type OnTabbedPanelSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut TabbedPanelWidget, &[WidgetContainer], &[LayoutContainer], u16)>>;
+
+/// This is the callback type that is used when an `on_tab_closed` callback is triggered from this
+/// `Widget`.  Returns the number of the tab that was closed, and its page.
+pub type OnTabbedPanelClosedCallbackType = Option<
+    Box<dyn FnMut(&mut TabbedPanelWidget, &[WidgetContainer], &[LayoutContainer], u16, LayoutItem)>,
+>;
+
+/// This is a tab in a `TabbedPanelWidget`: the title shown in the tab, and the page shown when the
+/// tab is active.
+struct TabPage {
+    title: String,
+    page: LayoutItem,
+    closable: bool,
+}
+
+/// This identifies the part of the tab strip under the mouse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TabPart {
+    Tab(usize),
+    Close(usize),
+    Previous,
+    Next,
+}
+
+/// This is the storage object for the `TabbedPanelWidget`.  It stores the config, properties,
+/// callback registry, the tabs and their pages, and other storage values for internal rendering.
+pub struct TabbedPanelWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    tabs: Vec<TabPage>,
+    tab_widths: Vec<u32>,
+    calculated: bool,
+    tab_height: u32,
+    active_tab: Option<usize>,
+    hovered_part: Option<TabPart>,
+    first_tab: usize,
+    overflow: bool,
+    scroll_to_active: bool,
+    pages_invalidated: bool,
+    removed_pages: Vec<LayoutItem>,
+    on_tab_selected: OnTabbedPanelSelectedCallbackType,
+    on_tab_closed: OnTabbedPanelClosedCallbackType,
+}
+
+/// This is the implementation of the `TabbedPanelWidget`, which displays a row of tabs, and a page
+/// below them for the active tab.  Each page is a `LayoutItem`, assigned to a tab with `add_tab`:
+/// either a `Widget`, which can contain any number of children of its own, or a `Layout`.  The
+/// `Widget`s of a page should be added to the `Engine` as children of the `TabbedPanelWidget`, so
+/// that each tab shows a full subtree of `Widget`s.  The `TabbedPanelWidget` places each page in
+/// the area below the tabs, and hides all pages except the page of the active tab.
+///
+/// Tabs can be added, removed and renamed while the application is running.  Closable tabs show a
+/// close button, which removes the tab and triggers the `on_tab_closed` callback.  When there are
+/// more tabs than fit in the width of the `Widget`, arrow buttons are shown to scroll through them.
+impl TabbedPanelWidget {
+    /// Creates a new `TabbedPanelWidget`, given the `x, y, w, h` coordinates.  Tabs are added with
+    /// `add_tab`.
+    pub fn new(points: Points, size: Size) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_color(CONFIG_COLOR_BASE, Color::RGB(255, 255, 255));
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            tabs: vec![],
+            tab_widths: vec![],
+            calculated: false,
+            tab_height: 30,
+            active_tab: None,
+            hovered_part: None,
+            first_tab: 0,
+            overflow: false,
+            scroll_to_active: false,
+            pages_invalidated: true,
+            removed_pages: vec![],
+            on_tab_selected: None,
+            on_tab_closed: None,
+        }
+    }
+
+    /// Adds a tab at the end of the tab strip, with the `title` to display, and the `page` that is
+    /// shown when the tab is active.  A `Widget` page is moved and sized to fill the area below the
+    /// tabs, and a `Layout` page is laid out within it.  The first tab that is added becomes the active tab.  Returns the number of the new
+    /// tab.
+    pub fn add_tab(&mut self, title: String, page: LayoutItem) -> usize {
+        self.insert_tab(self.tabs.len(), title, page)
+    }
+
+    /// Inserts a tab before the tab numbered `index`, with the `title` to display, and the `page`
+    /// that is shown when the tab is active.  Returns the number of the new tab.
+    pub fn insert_tab(&mut self, index: usize, title: String, page: LayoutItem) -> usize {
+        let index = index.min(self.tabs.len());
+
+        self.tabs.insert(
+            index,
+            TabPage {
+                title,
+                page,
+                closable: false,
+            },
+        );
+
+        self.active_tab = match self.active_tab {
+            Some(active_tab) if active_tab >= index => Some(active_tab + 1),
+            Some(active_tab) => Some(active_tab),
+            None => Some(index),
+        };
+        self.invalidate_tabs();
+
+        index
+    }
+
+    /// Removes the tab numbered `index`, and hides its page.  The `Widget`s of the page remain in
+    /// the `Engine`, and the page can be assigned to a tab again later.  If the active tab is removed, the tab
+    /// after it becomes active.
+    pub fn remove_tab(&mut self, index: usize) {
+        if index >= self.tabs.len() {
+            return;
+        }
+
+        let tab = self.tabs.remove(index);
+
+        self.removed_pages.push(tab.page);
+        self.active_tab = match self.active_tab {
+            _ if self.tabs.is_empty() => None,
+            Some(active_tab) if active_tab > index || active_tab >= self.tabs.len() => {
+                Some(active_tab - 1)
+            }
+            active_tab => active_tab,
+        };
+        self.first_tab = self.first_tab.min(self.tabs.len().saturating_sub(1));
+        self.invalidate_tabs();
+    }
+
+    /// Changes the title of the tab numbered `index`.
+    pub fn rename_tab(&mut self, index: usize, title: String) {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            tab.title = title;
+            self.invalidate_tabs();
+        }
+    }
+
+    /// Sets whether or not the tab numbered `index` shows a close button.
+    pub fn set_tab_closable(&mut self, index: usize, closable: bool) {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            tab.closable = closable;
+            self.invalidate_tabs();
+        }
+    }
+
+    /// Returns the number of tabs.
+    pub fn get_tab_count(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Returns the title of the tab numbered `index`, or `None` if there is no such tab.
+    pub fn get_tab_title(&self, index: usize) -> Option<&str> {
+        self.tabs.get(index).map(|tab| tab.title.as_str())
+    }
+
+    /// Returns the page of the tab numbered `index`, or `None` if there is no such tab.
+    pub fn get_page(&self, index: usize) -> Option<LayoutItem> {
+        self.tabs.get(index).map(|tab| tab.page)
+    }
+
+    /// Returns the number of the active tab, or `None` if there are no tabs.
+    pub fn get_active_tab(&self) -> Option<usize> {
+        self.active_tab
+    }
+
+    /// Makes the tab numbered `index` the active tab, showing its page, and scrolling the tab strip
+    /// so that the tab is visible.  This does not trigger the `on_tab_selected` callback.
+    pub fn set_active_tab(&mut self, index: usize) {
+        if index < self.tabs.len() && self.active_tab != Some(index) {
+            self.active_tab = Some(index);
+            self.scroll_to_active = true;
+            self.pages_invalidated = true;
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Sets the height of the tab strip, in pixels.  Defaults to `30`.
+    pub fn set_tab_height(&mut self, tab_height: u32) {
+        self.tab_height = tab_height;
+        self.pages_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Assigns the callback closure that will be used when a tab is selected.
+    pub fn on_tab_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TabbedPanelWidget, &[WidgetContainer], &[LayoutContainer], u16) + 'static,
+    {
+        self.on_tab_selected = Some(Box::new(callback));
+    }
+
+    /// Assigns the callback closure that will be used when a tab is closed with its close button.
+    pub fn on_tab_closed<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TabbedPanelWidget, &[WidgetContainer], &[LayoutContainer], u16, LayoutItem)
+            + 'static,
+    {
+        self.on_tab_closed = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_tab_selected` callback.
+    fn call_tab_selected_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        tab: u16,
+    ) {
+        if let Some(mut cb) = self.on_tab_selected.take() {
+            cb(self, widgets, layouts, tab);
+            self.on_tab_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that triggers the `on_tab_closed` callback.
+    fn call_tab_closed_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        tab: u16,
+        page: LayoutItem,
+    ) {
+        if let Some(mut cb) = self.on_tab_closed.take() {
+            cb(self, widgets, layouts, tab, page);
+            self.on_tab_closed = Some(cb);
+        }
+    }
+
+    /// Internal function that marks the tabs as needing to be measured again, and the pages as
+    /// needing to be shown or hidden.
+    fn invalidate_tabs(&mut self) {
+        self.calculated = false;
+        self.hovered_part = None;
+        self.scroll_to_active = true;
+        self.pages_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the width of the tab numbered `index`.
+    fn get_tab_width(&self, index: usize) -> u32 {
+        let close_width = if self.tabs[index].closable {
+            CLOSE_WIDTH
+        } else {
+            0
+        };
+
+        self.tab_widths[index] + TAB_SPACING + close_width
+    }
+
+    /// Returns the X position at which the tab strip ends, which is before the arrow buttons when
+    /// they are shown.
+    fn get_strip_right(&mut self) -> i32 {
+        let width = self.get_config().get_size(CONFIG_SIZE)[SIZE_WIDTH] as i32;
+
+        if self.overflow {
+            width - (ARROW_WIDTH * 2) as i32
+        } else {
+            width
+        }
+    }
+
+    /// Returns the areas of the visible tabs, relative to the `Widget`, along with the number of
+    /// each tab.  This is only valid once the tabs have been measured.
+    fn get_tab_areas(&mut self) -> Vec<(usize, Rect)> {
+        let strip_right = self.get_strip_right();
+        let mut areas = Vec::new();
+        let mut x = TAB_START_X;
+
+        for i in self.first_tab..self.tab_widths.len().min(self.tabs.len()) {
+            if x >= strip_right {
+                break;
+            }
+
+            let width = self.get_tab_width(i);
+
+            areas.push((i, Rect::new(x, 0, width, self.tab_height)));
+            x += width as i32 + 1;
+        }
+
+        areas
+    }
+
+    /// Returns the areas of the previous and next arrow buttons, relative to the `Widget`.
+    fn get_arrow_areas(&mut self) -> (Rect, Rect) {
+        let strip_right = self.get_strip_right();
+
+        (
+            Rect::new(strip_right, 0, ARROW_WIDTH, self.tab_height),
+            Rect::new(
+                strip_right + ARROW_WIDTH as i32,
+                0,
+                ARROW_WIDTH,
+                self.tab_height,
+            ),
+        )
+    }
+
+    /// Indicates whether or not the tabs can be scrolled to show tabs after the last visible tab.
+    fn can_scroll_next(&mut self) -> bool {
+        let strip_right = self.get_strip_right();
+
+        self.get_tab_areas()
+            .last()
+            .is_some_and(|(i, area)| area.right() > strip_right || *i + 1 < self.tabs.len())
+    }
+
+    /// Determines the part of the tab strip at the point `x, y`, relative to the `Widget`.
+    fn find_part(&mut self, x: i32, y: i32) -> Option<TabPart> {
+        if !self.calculated || y < 0 || y >= self.tab_height as i32 {
+            return None;
+        }
+
+        if self.overflow {
+            let (previous_area, next_area) = self.get_arrow_areas();
+
+            if previous_area.contains_point((x, y)) {
+                return Some(TabPart::Previous);
+            } else if next_area.contains_point((x, y)) {
+                return Some(TabPart::Next);
+            }
+        }
+
+        for (i, area) in self.get_tab_areas() {
+            if area.contains_point((x, y)) {
+                return if self.tabs[i].closable && x >= area.right() - CLOSE_WIDTH as i32 - 6 {
+                    Some(TabPart::Close(i))
+                } else {
+                    Some(TabPart::Tab(i))
+                };
+            }
+        }
+
+        None
+    }
+
+    /// Internal function that measures the tabs, determines whether or not they fit in the width
+    /// of the `Widget`, and scrolls the tab strip so that the active tab is visible.
+    fn adjust_widgets(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) {
+        if !self.calculated {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let titles: Vec<String> = self.tabs.iter().map(|tab| tab.title.clone()).collect();
+
+            self.tab_widths = measure_tab_widths(c, t, &titles, bounds[SIZE_WIDTH]);
+            self.calculated = true;
+
+            let total_width: u32 = (0..self.tabs.len())
+                .map(|i| self.get_tab_width(i) + 1)
+                .sum();
+
+            self.overflow = TAB_START_X + total_width as i32 > bounds[SIZE_WIDTH] as i32;
+
+            if !self.overflow {
+                self.first_tab = 0;
+            }
+        }
+
+        if self.scroll_to_active {
+            if let Some(active_tab) = self.active_tab {
+                let strip_right = self.get_strip_right();
+
+                if active_tab < self.first_tab {
+                    self.first_tab = active_tab;
+                }
+
+                while self.first_tab < active_tab
+                    && self
+                        .get_tab_areas()
+                        .iter()
+                        .find(|(i, _)| *i == active_tab)
+                        .is_none_or(|(_, area)| area.right() > strip_right)
+                {
+                    self.first_tab += 1;
+                }
+            }
+
+            self.scroll_to_active = false;
+        }
+    }
+
+    /// Internal function that hides the pages of removed tabs, and shows the page of the active tab,
+    /// placing it in the area below the tabs.  All other pages are hidden.
+    fn update_pages(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+        let page_area = self.get_client_area();
+
+        for page in self.removed_pages.drain(..) {
+            set_item_visible(page, widgets, layouts, false);
+        }
+
+        for (i, tab) in self.tabs.iter().enumerate() {
+            if self.active_tab == Some(i) {
+                place_item(
+                    tab.page,
+                    widgets,
+                    layouts,
+                    vec![page_area.x(), page_area.y()],
+                    vec![page_area.width(), page_area.height()],
+                );
+                set_item_visible(tab.page, widgets, layouts, true);
+            } else {
+                set_item_visible(tab.page, widgets, layouts, false);
+            }
+        }
+
+        self.pages_invalidated = false;
+    }
+}
+
+/// This is the `Widget` implementation of the `TabbedPanelWidget`.
+impl Widget for TabbedPanelWidget {
+    /// Draws the tab strip, the arrow buttons when the tabs do not fit, and the border around the
+    /// page area.  The active page is drawn as a child of this `Widget`.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if !self.calculated || self.scroll_to_active {
+            self.adjust_widgets(c, t);
+        }
+
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let base_color = self.get_color(CONFIG_COLOR_BASE);
+            let border_color = self.get_color(CONFIG_COLOR_BORDER);
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[SIZE_WIDTH], bounds[SIZE_HEIGHT]);
+
+            let tab_areas = self.get_tab_areas();
+            let (previous_area, next_area) = self.get_arrow_areas();
+            let strip_right = self.get_strip_right();
+            let can_scroll_previous = self.first_tab > 0;
+            let can_scroll_next = self.can_scroll_next();
+            let tabs = &self.tabs;
+            let tab_height = self.tab_height;
+            let active_tab = self.active_tab;
+            let hovered_part = self.hovered_part;
+            let overflow = self.overflow;
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+                texture.set_clip_rect(Rect::new(
+                    0,
+                    0,
+                    strip_right.max(1) as u32,
+                    bounds[SIZE_HEIGHT],
+                ));
+
+                for (i, area) in &tab_areas {
+                    let mut font_color = Color::RGB(0, 0, 0);
+
+                    if active_tab == Some(*i) {
+                        texture.set_draw_color(Color::RGB(128, 128, 128));
+                        font_color = Color::RGB(255, 255, 255);
+                    } else if hovered_part == Some(TabPart::Tab(*i))
+                        || hovered_part == Some(TabPart::Close(*i))
+                    {
+                        texture.set_draw_color(Color::RGB(192, 192, 192));
+                    } else {
+                        texture.set_draw_color(Color::RGB(224, 224, 224));
+                    }
+
+                    texture.fill_rect(*area).unwrap();
+
+                    if !tabs[*i].title.is_empty() {
+                        let (font_texture, font_width, font_height) = t.render_text(
+                            texture,
+                            String::from("assets/OpenSans-Regular.ttf"),
+                            14,
+                            sdl2::ttf::FontStyle::NORMAL,
+                            tabs[*i].title.clone(),
+                            font_color,
+                            bounds[SIZE_WIDTH],
+                        );
+
+                        texture
+                            .copy(
+                                &font_texture,
+                                None,
+                                Rect::new(
+                                    area.x() + 10,
+                                    tab_height as i32 / 2 - 10,
+                                    font_width,
+                                    font_height,
+                                ),
+                            )
+                            .unwrap();
+                    }
+
+                    // The close button is an "x", drawn in a box when the mouse is over it.
+                    if tabs[*i].closable {
+                        let close_x = area.right() - CLOSE_WIDTH as i32 / 2 - 8;
+                        let close_y = tab_height as i32 / 2;
+
+                        if hovered_part == Some(TabPart::Close(*i)) {
+                            texture.set_draw_color(Color::RGB(160, 160, 160));
+                            texture
+                                .fill_rect(Rect::new(close_x - 6, close_y - 6, 13, 13))
+                                .unwrap();
+                        }
+
+                        texture.set_draw_color(font_color);
+                        texture
+                            .draw_line(
+                                Point::new(close_x - 3, close_y - 3),
+                                Point::new(close_x + 3, close_y + 3),
+                            )
+                            .unwrap();
+                        texture
+                            .draw_line(
+                                Point::new(close_x - 3, close_y + 3),
+                                Point::new(close_x + 3, close_y - 3),
+                            )
+                            .unwrap();
+                    }
+                }
+
+                texture.set_clip_rect(None);
+
+                if overflow {
+                    for (area, enabled, hovered, direction) in &[
+                        (
+                            previous_area,
+                            can_scroll_previous,
+                            hovered_part == Some(TabPart::Previous),
+                            -1,
+                        ),
+                        (
+                            next_area,
+                            can_scroll_next,
+                            hovered_part == Some(TabPart::Next),
+                            1,
+                        ),
+                    ] {
+                        let arrow_x = area.x() + ARROW_WIDTH as i32 / 2;
+                        let arrow_y = tab_height as i32 / 2;
+
+                        texture.set_draw_color(if *hovered && *enabled {
+                            Color::RGB(192, 192, 192)
+                        } else {
+                            Color::RGB(224, 224, 224)
+                        });
+                        texture.fill_rect(*area).unwrap();
+                        texture.set_draw_color(if *enabled {
+                            Color::RGB(0, 0, 0)
+                        } else {
+                            Color::RGB(160, 160, 160)
+                        });
+
+                        for k in 0..5 {
+                            texture
+                                .draw_line(
+                                    Point::new(arrow_x + direction * (k - 2), arrow_y - 4 + k),
+                                    Point::new(arrow_x + direction * (k - 2), arrow_y + 4 - k),
+                                )
+                                .unwrap();
+                        }
+                    }
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(
+                        0,
+                        tab_height as i32 - 1,
+                        bounds[SIZE_WIDTH],
+                        bounds[SIZE_HEIGHT].saturating_sub(tab_height - 1).max(1),
+                    ))
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Returns the area below the tabs, inside of the border, which the pages are drawn in.
+    fn get_client_area(&mut self) -> Rect {
+        let area = self.get_drawing_area();
+
+        Rect::new(
+            area.x() + 1,
+            area.y() + self.tab_height as i32,
+            area.width().saturating_sub(2).max(1),
+            area.height().saturating_sub(self.tab_height + 1).max(1),
+        )
+    }
+
+    /// Shows and hides the pages when the tabs or the active tab have changed.
+    fn tick(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.pages_invalidated {
+            self.update_pages(_widgets, _layouts);
+        }
+
+        self.tick_callback(_widgets, _layouts);
+    }
+
+    /// Clears the highlighted tab when the mouse leaves the `Widget`.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.hovered_part.is_some() {
+            self.hovered_part = None;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Highlights the tab, close button or arrow button under the mouse.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let hovered_part = self.find_part(
+            _points[POINT_X] - origin[POINT_X],
+            _points[POINT_Y] - origin[POINT_Y],
+        );
+
+        if hovered_part != self.hovered_part {
+            self.hovered_part = hovered_part;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Selects or closes the tab under the mouse, or scrolls the tabs when an arrow button is
+    /// clicked.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 && _state {
+            let can_scroll_next = self.can_scroll_next();
+
+            match self.hovered_part {
+                Some(TabPart::Tab(i)) if self.active_tab != Some(i) => {
+                    self.set_active_tab(i);
+                    self.update_pages(_widgets, _layouts);
+                    self.call_tab_selected_callback(_widgets, _layouts, i as u16);
+                }
+                Some(TabPart::Close(i)) => {
+                    let page = self.tabs[i].page;
+
+                    self.remove_tab(i);
+                    self.update_pages(_widgets, _layouts);
+                    self.call_tab_closed_callback(_widgets, _layouts, i as u16, page);
+                }
+                Some(TabPart::Previous) if self.first_tab > 0 => {
+                    self.first_tab -= 1;
+                    self.get_config().set_invalidated(true);
+                }
+                Some(TabPart::Next) if can_scroll_next => {
+                    self.first_tab += 1;
+                    self.get_config().set_invalidated(true);
+                }
+                _ => (),
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Measures the tabs again, and resizes the active page, when the size of the `Widget` changes.
+    fn on_config_changed(&mut self, _k: u8, _v: Config) {
+        if _k == CONFIG_SIZE {
+            self.invalidate_tabs();
+        }
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
@@ -0,0 +1,1016 @@
+// Pushrod Widget Library
+// Table Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::modifiers::mod_state;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+use crate::widgets::list_widget::ListSelectionMode::SingleSelection;
+use crate::widgets::list_widget::{ListSelection, ListSelectionMode};
+use crate::widgets::scroll_bar::ScrollBarOrientation::{ScrollBarHorizontal, ScrollBarVertical};
+use crate::widgets::scroll_bar::{ScrollBar, SCROLL_BAR_THICKNESS};
+use crate::widgets::table_widget::SortOrder::{SortAscending, SortDescending};
+use crate::widgets::text_widget::TextJustify;
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use sdl2::ttf::FontStyle;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// This is the smallest width that a column can be resized to, in pixels.
+const MIN_COLUMN_WIDTH: u32 = 20;
+
+/// This is the distance from the edge of a column header, in pixels, in which the mouse will
+/// resize the column instead of sorting it.
+const RESIZE_MARGIN: i32 = 3;
+
+/// This is the callback type that is used when an `on_selected` callback is triggered from this
+/// `Widget`.  It receives the index of the selected row, or `-1` if the selection was cleared.
+pub type OnTableSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut TableWidget, &[WidgetContainer], &[LayoutContainer], i32)>>;
+
+/// This is the callback type that is used when an `on_cell_clicked` callback is triggered from this
+/// `Widget`.  It receives the row and column of the cell that was clicked.
+pub type OnCellClickedCallbackType =
+    Option<Box<dyn FnMut(&mut TableWidget, &[WidgetContainer], &[LayoutContainer], usize, usize)>>;
+
+/// These are the directions that a `TableWidget` can be sorted in.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum SortOrder {
+    /// Sorts rows from the smallest value to the largest.
+    SortAscending,
+
+    /// Sorts rows from the largest value to the smallest.
+    SortDescending,
+}
+
+/// This is a column definition for a `TableWidget`.
+#[derive(Clone, Debug)]
+pub struct TableColumn {
+    /// The title shown in the header of the column.
+    pub title: String,
+
+    /// The width of the column, in pixels.
+    pub width: u32,
+
+    /// The alignment of the text in the column.
+    pub alignment: TextJustify,
+
+    /// Indicates whether or not clicking on the header of the column sorts the table.
+    pub sortable: bool,
+}
+
+/// This is the implementation of the `TableColumn`.
+impl TableColumn {
+    /// Creates a new sortable `TableColumn` with a `title`, a `width` in pixels, and the `alignment`
+    /// of the text in the column.
+    pub fn new(title: String, width: u32, alignment: TextJustify) -> Self {
+        Self {
+            title,
+            width,
+            alignment,
+            sortable: true,
+        }
+    }
+}
+
+/// This is the storage object for the `TableWidget`.  It stores the config, properties, callback
+/// registry, the columns and rows of the table, the sort order, the selection, and the scroll
+/// bars.
+pub struct TableWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    columns: Vec<TableColumn>,
+    rows: Vec<Vec<String>>,
+    row_order: Vec<usize>,
+    sort: Option<(usize, SortOrder)>,
+    font_name: String,
+    font_size: i32,
+    row_height: u32,
+    highlighted_row: i32,
+    selection: ListSelection,
+    horizontal: ScrollBar,
+    vertical: ScrollBar,
+    mouse_point: Points,
+    in_bounds: bool,
+    resizing: Option<(usize, i32, u32)>,
+    header_pressed: i32,
+    on_selected: OnTableSelectedCallbackType,
+    on_cell_clicked: OnCellClickedCallbackType,
+}
+
+/// This is the implementation of the `TableWidget`, which displays rows of text in columns, under a
+/// header row.  Clicking on the header of a sortable column sorts the table by that column, and
+/// clicking it again reverses the sort order.  Dragging the right edge of a column header resizes
+/// the column.  Rows are selected and navigated the same way as the `ListWidget`, and the table
+/// scrolls horizontally and vertically when its contents are larger than the `Widget`.  Rows are
+/// always referred to by the index they were added at, regardless of how the table is sorted.
+impl TableWidget {
+    /// Creates a new `TableWidget` given the `x, y, w, h` coordinates, and the `columns` to
+    /// display.  Rows are 26 pixels high, and drawn using `assets/OpenSans-Regular.ttf` at size 14.
+    pub fn new(points: Points, size: Size, columns: Vec<TableColumn>) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+        config.set_toggle(CONFIG_SCROLLABLE, true);
+        config.set_toggle(CONFIG_MOUSE_CAPTURE, true);
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            columns,
+            rows: vec![],
+            row_order: vec![],
+            sort: None,
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size: 14,
+            row_height: 26,
+            highlighted_row: -1,
+            selection: ListSelection::new(SingleSelection),
+            horizontal: ScrollBar::new(ScrollBarHorizontal),
+            vertical: ScrollBar::new(ScrollBarVertical),
+            mouse_point: make_points(0, 0),
+            in_bounds: false,
+            resizing: None,
+            header_pressed: -1,
+            on_selected: None,
+            on_cell_clicked: None,
+        }
+    }
+
+    /// Adds a row of cells to the end of the table.  If the table is sorted, the row is shown in
+    /// its sorted position.  Returns the index of the row.
+    pub fn add_row(&mut self, row: Vec<String>) -> usize {
+        let index = self.rows.len();
+
+        self.rows.push(row);
+
+        // The row order is already sorted, so the new row is placed after the rows that sort
+        // before or equal to it, which is where a stable sort would place it.
+        let position = match self.sort {
+            Some(_) => self
+                .row_order
+                .partition_point(|other| self.compare_rows(*other, index) != Ordering::Greater),
+            None => index,
+        };
+
+        self.row_order.insert(position, index);
+        self.selection.items_inserted(position, 1);
+        self.highlighted_row = -1;
+        self.get_config().set_invalidated(true);
+
+        index
+    }
+
+    /// Removes the row at `index` from the table, removing it from the selection if it was
+    /// selected.  Rows after it are moved up by one.  Returns the removed row, or `None` if `index`
+    /// is out of range.
+    pub fn remove_row(&mut self, index: usize) -> Option<Vec<String>> {
+        if index >= self.rows.len() {
+            return None;
+        }
+
+        let position = self.row_order.iter().position(|x| *x == index).unwrap();
+        let row = self.rows.remove(index);
+
+        self.row_order.remove(position);
+
+        for other in self.row_order.iter_mut() {
+            if *other > index {
+                *other -= 1;
+            }
+        }
+
+        self.selection.items_removed(position, 1);
+        self.highlighted_row = -1;
+        self.get_config().set_invalidated(true);
+
+        Some(row)
+    }
+
+    /// Replaces all of the rows in the table, clearing the selection, and scrolling back to the top.
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        self.selection.clear();
+        self.vertical.set_position(0);
+        self.rebuild_order(vec![]);
+    }
+
+    /// Removes all of the rows from the table.
+    pub fn clear(&mut self) {
+        self.set_rows(vec![]);
+    }
+
+    /// Returns the rows of the table, in the order they were added.
+    pub fn get_rows(&self) -> &[Vec<String>] {
+        &self.rows
+    }
+
+    /// Sets the text of the cell at `row` and `column`, re-sorting the table if needed.  Cells that
+    /// are out of range are ignored.
+    pub fn set_cell(&mut self, row: usize, column: usize, text: String) {
+        if let Some(cells) = self.rows.get_mut(row) {
+            if column >= cells.len() {
+                cells.resize(column + 1, String::new());
+            }
+
+            cells[column] = text;
+
+            let selected = self.get_selected();
+
+            self.rebuild_order(selected);
+        }
+    }
+
+    /// Returns the text of the cell at `row` and `column`, if it exists.
+    pub fn get_cell(&self, row: usize, column: usize) -> Option<&String> {
+        self.rows.get(row).and_then(|x| x.get(column))
+    }
+
+    /// Returns the column definitions.
+    pub fn get_columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    /// Sets the width of the column at `column`, in pixels.
+    pub fn set_column_width(&mut self, column: usize, width: u32) {
+        if let Some(table_column) = self.columns.get_mut(column) {
+            table_column.width = width.max(MIN_COLUMN_WIDTH);
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Sorts the table by the values in `column`, in the specified `order`.  Values that are
+    /// numbers are sorted before all other values, and compared numerically.  All other values are
+    /// compared as text, ignoring case.
+    pub fn sort_by(&mut self, column: usize, order: SortOrder) {
+        let selected = self.get_selected();
+
+        self.sort = Some((column, order));
+        self.rebuild_order(selected);
+    }
+
+    /// Returns the column and order that the table is sorted by, if it is sorted.
+    pub fn get_sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Returns the indexes of the selected rows, in ascending order.
+    pub fn get_selected(&self) -> Vec<usize> {
+        let mut selected: Vec<usize> = self
+            .selection
+            .get_selected()
+            .into_iter()
+            .map(|x| self.row_order[x])
+            .collect();
+
+        selected.sort();
+        selected
+    }
+
+    /// Sets the selected rows by their indexes.  Indexes that are out of range are ignored.  This
+    /// does not trigger the `on_selected` callback.
+    pub fn set_selected(&mut self, rows: Vec<usize>) {
+        let mut display_index = vec![0; self.row_order.len()];
+
+        for (display_row, row) in self.row_order.iter().enumerate() {
+            display_index[*row] = display_row;
+        }
+
+        let display_rows = rows
+            .into_iter()
+            .filter(|x| *x < display_index.len())
+            .map(|x| display_index[x])
+            .collect();
+
+        self.selection.set_selected(display_rows, self.rows.len());
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the selection mode of the `TableWidget`.
+    pub fn set_selection_mode(&mut self, selection_mode: ListSelectionMode) {
+        self.selection.set_selection_mode(selection_mode);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the height of each row, including the header row, in pixels.
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.row_height = row_height.max(1);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the font used to draw the table, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.  The header is drawn in bold.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.font_name = font_name;
+        self.font_size = font_size;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Assigns the callback closure that will be used when a row is selected.  The row is the one
+    /// that was last clicked or navigated to: use `get_selected` to retrieve the full selection.
+    pub fn on_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TableWidget, &[WidgetContainer], &[LayoutContainer], i32) + 'static,
+    {
+        self.on_selected = Some(Box::new(callback));
+    }
+
+    /// Assigns the callback closure that will be used when a cell is clicked.
+    pub fn on_cell_clicked<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TableWidget, &[WidgetContainer], &[LayoutContainer], usize, usize) + 'static,
+    {
+        self.on_cell_clicked = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_selected` callback.
+    fn call_selected_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        selected_row: i32,
+    ) {
+        if let Some(mut cb) = self.on_selected.take() {
+            cb(self, widgets, layouts, selected_row);
+            self.on_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that triggers the `on_cell_clicked` callback.
+    fn call_cell_clicked_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        row: usize,
+        column: usize,
+    ) {
+        if let Some(mut cb) = self.on_cell_clicked.take() {
+            cb(self, widgets, layouts, row, column);
+            self.on_cell_clicked = Some(cb);
+        }
+    }
+
+    /// Internal function that rebuilds the display order of the rows from the current sort, then
+    /// selects the rows in `selected`.
+    fn rebuild_order(&mut self, selected: Vec<usize>) {
+        let mut row_order: Vec<usize> = (0..self.rows.len()).collect();
+
+        if self.sort.is_some() {
+            row_order.sort_by(|a, b| self.compare_rows(*a, *b));
+        }
+
+        self.row_order = row_order;
+        self.highlighted_row = -1;
+        self.selection.clear();
+        self.set_selected(selected);
+    }
+
+    /// Internal function that compares the rows at `a` and `b` by the current sort.  Rows compare
+    /// equal if the table is not sorted.
+    fn compare_rows(&self, a: usize, b: usize) -> Ordering {
+        match self.sort {
+            Some((column, order)) => {
+                let a_cell = self.rows[a].get(column).map(|x| x.as_str()).unwrap_or("");
+                let b_cell = self.rows[b].get(column).map(|x| x.as_str()).unwrap_or("");
+                let ordering = compare_cells(a_cell, b_cell);
+
+                match order {
+                    SortAscending => ordering,
+                    SortDescending => ordering.reverse(),
+                }
+            }
+            None => Ordering::Equal,
+        }
+    }
+
+    /// Internal function that returns the total width of all of the columns.
+    fn get_content_width(&self) -> u32 {
+        self.columns.iter().map(|x| x.width).sum()
+    }
+
+    /// Internal function that works out which scroll bars are shown, and updates the `ScrollBar`s
+    /// to match.  Returns the track areas of the horizontal and vertical scroll bars, relative to
+    /// the `Widget`, if they are shown.
+    fn update_scroll_bars(&mut self) -> (Option<Rect>, Option<Rect>) {
+        let size = self.get_config().get_size(CONFIG_SIZE);
+        let content_width = self.get_content_width();
+        let content_height = self.rows.len() as u32 * self.row_height;
+        let inner_width = size[SIZE_WIDTH].saturating_sub(2);
+        let inner_height = size[SIZE_HEIGHT].saturating_sub(2 + self.row_height);
+        let mut show_vertical = content_height > inner_height;
+        let show_horizontal = content_width
+            > inner_width.saturating_sub(if show_vertical {
+                SCROLL_BAR_THICKNESS
+            } else {
+                0
+            });
+
+        if show_horizontal && !show_vertical {
+            show_vertical = content_height > inner_height.saturating_sub(SCROLL_BAR_THICKNESS);
+        }
+
+        let viewport_width = if show_vertical {
+            inner_width.saturating_sub(SCROLL_BAR_THICKNESS)
+        } else {
+            inner_width
+        };
+        let viewport_height = if show_horizontal {
+            inner_height.saturating_sub(SCROLL_BAR_THICKNESS)
+        } else {
+            inner_height
+        };
+        let body_y = 1 + self.row_height as i32;
+
+        self.horizontal.set_lengths(content_width, viewport_width);
+        self.vertical.set_lengths(content_height, viewport_height);
+
+        (
+            if show_horizontal {
+                Some(Rect::new(
+                    1,
+                    body_y + viewport_height as i32,
+                    viewport_width.max(1),
+                    SCROLL_BAR_THICKNESS,
+                ))
+            } else {
+                None
+            },
+            if show_vertical {
+                Some(Rect::new(
+                    1 + viewport_width as i32,
+                    body_y,
+                    SCROLL_BAR_THICKNESS,
+                    viewport_height.max(1),
+                ))
+            } else {
+                None
+            },
+        )
+    }
+
+    /// Internal function that returns the column under the local X coordinate `x`, and whether or
+    /// not `x` is on the resizable right edge of that column.
+    fn get_column_at(&self, x: i32) -> Option<(usize, bool)> {
+        let content_x = x - 1 + self.horizontal.get_position() as i32;
+        let mut column_x = 0;
+
+        for (i, column) in self.columns.iter().enumerate() {
+            let right_edge = column_x + column.width as i32;
+
+            if (content_x - right_edge).abs() <= RESIZE_MARGIN {
+                return Some((i, true));
+            } else if content_x >= column_x && content_x < right_edge {
+                return Some((i, false));
+            }
+
+            column_x = right_edge;
+        }
+
+        None
+    }
+
+    /// Internal function that returns the display index of the row under the local coordinates
+    /// `x, y`, or `-1` if there is no row there.
+    fn get_row_at(&self, x: i32, y: i32) -> i32 {
+        let body_y = 1 + self.row_height as i32;
+        let viewport_width = self.horizontal.get_viewport_length() as i32;
+        let viewport_height = self.vertical.get_viewport_length() as i32;
+
+        if x < 1 || x > viewport_width || y < body_y || y >= body_y + viewport_height {
+            return -1;
+        }
+
+        let row = (y - body_y + self.vertical.get_position() as i32) / self.row_height as i32;
+
+        if row < self.rows.len() as i32 {
+            row
+        } else {
+            -1
+        }
+    }
+}
+
+/// Compares the text of two cells.  Cells that are numbers sort before all other cells, and are
+/// compared by value.  Other cells are compared as text, ignoring case.  Ties are broken by
+/// comparing the raw text, so that this is a total order.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    let ordering = match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a_value), Ok(b_value)) => a_value.total_cmp(&b_value),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.to_lowercase().cmp(&b.to_lowercase()),
+    };
+
+    ordering.then_with(|| a.cmp(b))
+}
+
+/// Draws `text` into `area` with the specified `alignment`, clipped to the `area`.
+fn draw_cell_text(
+    c: &mut Canvas<Window>,
+    t: &mut TextureCache,
+    font: (&str, i32, FontStyle),
+    text: &str,
+    color: Color,
+    area: Rect,
+    alignment: TextJustify,
+) {
+    let text_width = area.width().saturating_sub(12);
+
+    if text.is_empty() || text_width == 0 {
+        return;
+    }
+
+    let (font_name, font_size, font_style) = font;
+    let (font_texture, width, height) = t.render_text(
+        c,
+        String::from(font_name),
+        font_size as u16,
+        font_style,
+        String::from(text),
+        color,
+        text_width,
+    );
+    let width = width.min(text_width);
+    let height = height.min(area.height());
+    let text_x = match alignment {
+        TextJustify::Left => 6,
+        TextJustify::Center => (area.width() as i32 - width as i32) / 2,
+        TextJustify::Right => area.width() as i32 - width as i32 - 6,
+    };
+
+    c.copy(
+        &font_texture,
+        Rect::new(0, 0, width, height),
+        Rect::new(
+            area.x() + text_x,
+            area.y() + (area.height() as i32 - height as i32) / 2,
+            width,
+            height,
+        ),
+    )
+    .unwrap();
+}
+
+/// This is the `Widget` implementation of the `TableWidget`.
+impl Widget for TableWidget {
+    /// Draws the header and the visible rows of the `TableWidget`.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let (horizontal_track, vertical_track) = self.update_scroll_bars();
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            let base_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let hover_color = self.get_config().get_color(CONFIG_COLOR_HOVER);
+            let selected_color = self.get_config().get_color(CONFIG_COLOR_SELECTED);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let thumb_color = self.get_config().get_color(CONFIG_COLOR_SECONDARY);
+            let header_color = Color::RGB(224, 224, 224);
+            let grid_color = Color::RGB(208, 208, 208);
+            let focused = self.get_config().is_focused();
+            let row_height = self.row_height;
+            let viewport_width = self.horizontal.get_viewport_length().max(1);
+            let viewport_height = self.vertical.get_viewport_length().max(1);
+            let scroll_x = self.horizontal.get_position() as i32;
+            let scroll_y = self.vertical.get_position();
+            let body_y = 1 + row_height as i32;
+            let first_row = (scroll_y / row_height) as usize;
+            let last_row =
+                (((scroll_y + viewport_height) / row_height) as usize + 1).min(self.rows.len());
+            let font_name = self.font_name.clone();
+            let font_size = self.font_size;
+            let columns = &self.columns;
+            let rows = &self.rows;
+            let row_order = &self.row_order;
+            let selection = &self.selection;
+            let highlighted_row = self.highlighted_row;
+            let sort = self.sort;
+            let horizontal = &self.horizontal;
+            let vertical = &self.vertical;
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                // Rows are clipped to the body of the table.
+                texture.set_clip_rect(Rect::new(1, body_y, viewport_width, viewport_height));
+
+                for i in first_row..last_row {
+                    let row_y = body_y + (i as u32 * row_height) as i32 - scroll_y as i32;
+                    let row_area = Rect::new(1, row_y, viewport_width, row_height);
+                    let selected = selection.is_selected(i);
+                    let (color, text_color) = if selected {
+                        (selected_color, Color::RGB(255, 255, 255))
+                    } else if highlighted_row == i as i32 {
+                        (hover_color, Color::RGB(0, 0, 0))
+                    } else {
+                        (base_color, Color::RGB(0, 0, 0))
+                    };
+                    let mut column_x = 1 - scroll_x;
+
+                    texture.set_draw_color(color);
+                    texture.fill_rect(row_area).unwrap();
+
+                    for (j, column) in columns.iter().enumerate() {
+                        if column_x + (column.width as i32) > 0
+                            && column_x < 1 + viewport_width as i32
+                        {
+                            draw_cell_text(
+                                texture,
+                                t,
+                                (&font_name, font_size, FontStyle::NORMAL),
+                                rows[row_order[i]].get(j).map_or("", |x| x.as_str()),
+                                text_color,
+                                Rect::new(column_x, row_y, column.width, row_height),
+                                column.alignment,
+                            );
+                        }
+
+                        column_x += column.width as i32;
+                    }
+
+                    if focused && selection.get_cursor() == i as i32 {
+                        texture.set_draw_color(hover_color);
+                        texture.draw_rect(row_area).unwrap();
+                    }
+                }
+
+                // The header is drawn over the top of the body, and scrolls horizontally with it.
+                texture.set_clip_rect(Rect::new(1, 1, viewport_width, row_height));
+                texture.set_draw_color(header_color);
+                texture
+                    .fill_rect(Rect::new(1, 1, viewport_width, row_height))
+                    .unwrap();
+
+                let mut column_x = 1 - scroll_x;
+
+                for (j, column) in columns.iter().enumerate() {
+                    let header_area = Rect::new(column_x, 1, column.width, row_height);
+
+                    draw_cell_text(
+                        texture,
+                        t,
+                        (&font_name, font_size, FontStyle::BOLD),
+                        &column.title,
+                        Color::RGB(0, 0, 0),
+                        header_area,
+                        column.alignment,
+                    );
+
+                    if let Some((sort_column, order)) = sort {
+                        if sort_column == j {
+                            let center_x = header_area.right() - 10;
+                            let center_y = 1 + row_height as i32 / 2;
+
+                            texture.set_draw_color(Color::RGB(64, 64, 64));
+
+                            for k in 0..5 {
+                                let line_y = match order {
+                                    SortAscending => center_y - 2 + k,
+                                    SortDescending => center_y + 2 - k,
+                                };
+
+                                texture
+                                    .draw_line(
+                                        Point::new(center_x - k, line_y),
+                                        Point::new(center_x + k, line_y),
+                                    )
+                                    .unwrap();
+                            }
+                        }
+                    }
+
+                    column_x += column.width as i32;
+
+                    texture.set_draw_color(grid_color);
+                    texture
+                        .draw_line(
+                            Point::new(column_x - 1, 1),
+                            Point::new(column_x - 1, body_y + viewport_height as i32),
+                        )
+                        .unwrap();
+                }
+
+                texture.set_clip_rect(None);
+                texture.set_draw_color(grid_color);
+                texture
+                    .draw_line(
+                        Point::new(1, body_y - 1),
+                        Point::new(bounds[0] as i32 - 2, body_y - 1),
+                    )
+                    .unwrap();
+
+                if let Some(track) = horizontal_track {
+                    horizontal.draw(texture, track, base_color, thumb_color);
+                }
+
+                if let Some(track) = vertical_track {
+                    vertical.draw(texture, track, base_color, thumb_color);
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, bounds[0], bounds[1]))
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// When a mouse enters the bounds of the `Widget`, this function is triggered.
+    fn mouse_entered(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = true;
+        self.mouse_entered_callback(_widgets, _layouts);
+    }
+
+    /// When a mouse exits the bounds of the `Widget`, this function is triggered.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = false;
+        self.highlighted_row = -1;
+        self.get_config().set_invalidated(true);
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Highlights the row under the mouse, resizes a column, or drags a scroll bar thumb.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = _points[POINT_X] - origin[POINT_X];
+        let y = _points[POINT_Y] - origin[POINT_Y];
+        let (horizontal_track, vertical_track) = self.update_scroll_bars();
+        let previous_highlighted_row = self.highlighted_row;
+
+        self.mouse_point = make_points(x, y);
+
+        if let Some((column, start_x, start_width)) = self.resizing {
+            self.set_column_width(column, (start_width as i32 + x - start_x).max(0) as u32);
+        }
+
+        if horizontal_track.is_some_and(|track| self.horizontal.mouse_moved(track, x, y))
+            || vertical_track.is_some_and(|track| self.vertical.mouse_moved(track, x, y))
+        {
+            self.get_config().set_invalidated(true);
+        }
+
+        self.highlighted_row = if self.in_bounds {
+            self.get_row_at(x, y)
+        } else {
+            -1
+        };
+
+        if self.highlighted_row != previous_highlighted_row {
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Scrolls the table by one row for each step of the mouse wheel.
+    fn mouse_scrolled(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        self.update_scroll_bars();
+
+        let changed_x = self.horizontal.scroll_by(_points[POINT_X] * 20);
+        let changed_y = self
+            .vertical
+            .scroll_by(-_points[POINT_Y] * self.row_height as i32);
+
+        if changed_x || changed_y {
+            self.highlighted_row = -1;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_scrolled_callback(_widgets, _layouts, _points);
+    }
+
+    /// Selects rows, sorts and resizes columns, and handles the scroll bars.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        let x = self.mouse_point[POINT_X];
+        let y = self.mouse_point[POINT_Y];
+        let in_header = y >= 1
+            && y < 1 + self.row_height as i32
+            && x >= 1
+            && x < 1 + self.horizontal.get_viewport_length() as i32;
+
+        if _button == 1 && !_state {
+            self.horizontal.button_up();
+            self.vertical.button_up();
+
+            if self.resizing.take().is_none() && self.header_pressed >= 0 && self.in_bounds {
+                if let Some((column, false)) = self.get_column_at(x).filter(|_| in_header) {
+                    if column as i32 == self.header_pressed && self.columns[column].sortable {
+                        let order = match self.sort {
+                            Some((sort_column, SortAscending)) if sort_column == column => {
+                                SortDescending
+                            }
+                            _ => SortAscending,
+                        };
+
+                        self.sort_by(column, order);
+                    }
+                }
+            }
+
+            self.header_pressed = -1;
+        } else if _button == 1 && self.in_bounds {
+            let (horizontal_track, vertical_track) = self.update_scroll_bars();
+
+            if horizontal_track.is_some_and(|track| self.horizontal.button_down(track, x, y))
+                || vertical_track.is_some_and(|track| self.vertical.button_down(track, x, y))
+            {
+                self.get_config().set_invalidated(true);
+            } else if in_header {
+                match self.get_column_at(x) {
+                    Some((column, true)) => {
+                        self.resizing = Some((column, x, self.columns[column].width));
+                    }
+                    Some((column, false)) => self.header_pressed = column as i32,
+                    None => {}
+                }
+            } else {
+                let row = self.get_row_at(x, y);
+
+                self.get_config().set_invalidated(true);
+
+                if row >= 0 {
+                    let data_row = self.row_order[row as usize];
+
+                    self.selection.click_item(row as usize, mod_state());
+                    self.call_selected_callback(_widgets, _layouts, data_row as i32);
+
+                    if let Some((column, _)) = self.get_column_at(x) {
+                        self.call_cell_clicked_callback(_widgets, _layouts, data_row, column);
+                    }
+                } else {
+                    self.selection.deselect_all();
+                    self.call_selected_callback(_widgets, _layouts, -1);
+                }
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles keyboard navigation of the rows, and horizontal scrolling with `Left` and `Right`.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        self.update_scroll_bars();
+
+        let page_size = (self.vertical.get_viewport_length() / self.row_height) as usize;
+
+        match _keycode {
+            Keycode::Left | Keycode::Right => {
+                let amount = if _keycode == Keycode::Left { -20 } else { 20 };
+
+                if self.horizontal.scroll_by(amount) {
+                    self.get_config().set_invalidated(true);
+                }
+            }
+            _ => {
+                if let Some((row, changed)) =
+                    self.selection
+                        .key_pressed(_keycode, _keymod, self.rows.len(), page_size)
+                {
+                    let row_top = (row as u32 * self.row_height) as i32;
+                    let row_bottom = row_top + self.row_height as i32;
+                    let position = self.vertical.get_position() as i32;
+                    let viewport_height = self.vertical.get_viewport_length() as i32;
+
+                    if row_top < position {
+                        self.vertical.set_position(row_top);
+                    } else if row_bottom > position + viewport_height {
+                        self.vertical.set_position(row_bottom - viewport_height);
+                    }
+
+                    self.get_config().set_invalidated(true);
+
+                    if changed {
+                        let data_row = self.row_order[row] as i32;
+
+                        self.call_selected_callback(_widgets, _layouts, data_row);
+                    }
+                }
+            }
+        }
+
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Redraws the cursor when focus changes.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        self.get_config().set_invalidated(true);
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_cells_numbers_by_value() {
+        assert_eq!(compare_cells("9", "10"), Ordering::Less);
+        assert_eq!(compare_cells("-1.5", " 2 "), Ordering::Less);
+        assert_eq!(compare_cells("10", "10"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_compare_cells_numbers_before_text() {
+        assert_eq!(compare_cells("100", "abc"), Ordering::Less);
+        assert_eq!(compare_cells("abc", "100"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_compare_cells_text_ignores_case() {
+        assert_eq!(compare_cells("apple", "Banana"), Ordering::Less);
+        assert_eq!(compare_cells("Apple", "apple"), Ordering::Less);
+        assert_eq!(compare_cells("apple", "Apple"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_compare_cells_is_a_total_order() {
+        let cells = [
+            "b", "NaN", "10", "A", "", "1.0", "a", "-inf", "1", "B", "nan", "9",
+        ];
+        let mut sorted = cells.to_vec();
+
+        sorted.sort_by(|a, b| compare_cells(a, b));
+
+        for a in &cells {
+            assert_eq!(compare_cells(a, a), Ordering::Equal);
+
+            for b in &cells {
+                assert_eq!(compare_cells(a, b), compare_cells(b, a).reverse());
+            }
+        }
+
+        assert_eq!(
+            sorted,
+            vec!["-inf", "1", "1.0", "9", "10", "NaN", "nan", "", "A", "a", "B", "b"]
+        );
+    }
+}
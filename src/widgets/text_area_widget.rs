@@ -0,0 +1,1190 @@
+// Pushrod Widget Library
+// Text Area Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::widgets::scroll_bar::ScrollBarOrientation::ScrollBarVertical;
+use crate::widgets::scroll_bar::{ScrollBar, SCROLL_BAR_THICKNESS};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use sdl2::ttf::FontStyle;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the number of pixels between the border of the `TextAreaWidget` and the text.
+const TEXT_PADDING: i32 = 4;
+
+/// This is the width of the highlight drawn at the end of a line when its line break is selected.
+const NEWLINE_WIDTH: u32 = 6;
+
+/// This is the callback type that is used when an `on_text_changed` callback is triggered from this
+/// `Widget`.  Returns the current text of the `Widget`.
+pub type OnTextAreaCallbackType =
+    Option<Box<dyn FnMut(&mut TextAreaWidget, &[WidgetContainer], &[LayoutContainer], String)>>;
+
+/// This is a line of text in a `TextAreaWidget`, along with the character offsets measured for it,
+/// and the `Texture` it was rendered to.  Both are cleared when the line is changed.
+struct TextLine {
+    text: String,
+    offsets: Option<Vec<u32>>,
+    texture: Option<(Texture, u32, u32)>,
+}
+
+/// This is the implementation of the `TextLine`.
+impl TextLine {
+    /// Creates a new `TextLine` that has not been measured or rendered.
+    fn new(text: String) -> Self {
+        Self {
+            text,
+            offsets: None,
+            texture: None,
+        }
+    }
+
+    /// Returns the number of characters in the line.
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    /// Returns the X offset of the character position specified, or `0` if the line has not been
+    /// measured.
+    fn offset(&self, position: usize) -> u32 {
+        self.offsets
+            .as_ref()
+            .and_then(|offsets| offsets.get(position).cloned())
+            .unwrap_or(0)
+    }
+}
+
+/// This is a row of text as it is displayed: the range of characters from a line that fits on one
+/// row.  When word wrap is off, each line is displayed in a single row.
+#[derive(Clone, Copy, Debug)]
+struct TextRow {
+    line: usize,
+    start: usize,
+    end: usize,
+}
+
+/// This is the storage object for the `TextAreaWidget`.  It stores the config, properties, callback
+/// registry, the lines of text being edited, the rows they are displayed in, and the cursor and
+/// selection positions.
+pub struct TextAreaWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    font_name: String,
+    font_size: i32,
+    lines: Vec<TextLine>,
+    rows: Vec<TextRow>,
+    rows_valid: bool,
+    line_height: u32,
+    word_wrap: bool,
+    cursor: (usize, usize),
+    anchor: (usize, usize),
+    preferred_x: Option<u32>,
+    scroll_x: i32,
+    scroll_bar: ScrollBar,
+    scroll_to_cursor: bool,
+    selecting: bool,
+    mouse_point: Points,
+    on_text_changed: OnTextAreaCallbackType,
+}
+
+/// This is the implementation of the `TextAreaWidget`, a multi-line text editor.  Text is wrapped
+/// at word boundaries to the width of the `Widget`, or, with word wrap turned off, each line is
+/// shown on a single row, and the text is scrolled horizontally to follow the cursor.  A vertical
+/// scroll bar is shown when the text is taller than the `Widget`.
+///
+/// Each line is measured and rendered to its own `Texture`, which is kept until the line changes,
+/// so typing only renders the line being edited.  Only the lines that are visible are rendered.
+///
+/// The cursor and selection are stored as a line number and a character position in that line.
+/// `Up`, `Down`, `PageUp` and `PageDown` move between rows, keeping the horizontal position of the
+/// cursor; `Home` and `End` move to the start and end of the row, or of the text when `Ctrl` is
+/// held.  Holding `Shift` extends the selection, and `Return` starts a new line.
+impl TextAreaWidget {
+    /// Creates a new `TextAreaWidget` given the `x, y, w, h` coordinates, the initial `text` to
+    /// edit, and the `font_size` of the font to display.  Word wrap is on, and the cursor is placed
+    /// at the start of the text.
+    pub fn new(points: Points, size: Size, text: String, font_size: i32) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+        config.set_numeric(CONFIG_BORDER_WIDTH, 1);
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+        config.set_toggle(CONFIG_SCROLLABLE, true);
+        config.set_toggle(CONFIG_MOUSE_CAPTURE, true);
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size,
+            lines: split_lines(&text),
+            rows: vec![],
+            rows_valid: false,
+            line_height: 0,
+            word_wrap: true,
+            cursor: (0, 0),
+            anchor: (0, 0),
+            preferred_x: None,
+            scroll_x: 0,
+            scroll_bar: ScrollBar::new(ScrollBarVertical),
+            scroll_to_cursor: true,
+            selecting: false,
+            mouse_point: make_points(0, 0),
+            on_text_changed: None,
+        }
+    }
+
+    /// Retrieves the text being edited, with lines separated by `\n`.
+    pub fn get_text(&self) -> String {
+        self.lines
+            .iter()
+            .map(|line| line.text.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n")
+    }
+
+    /// Replaces the text being edited, moving the cursor to the start of the text.  This does not
+    /// trigger the `on_text_changed` callback.
+    pub fn set_text(&mut self, text: String) {
+        self.lines = split_lines(&text);
+        self.cursor = (0, 0);
+        self.anchor = (0, 0);
+        self.preferred_x = None;
+        self.scroll_x = 0;
+        self.scroll_bar.set_position(0);
+        self.invalidate_rows();
+    }
+
+    /// Adds `text` to the end of the text being edited, and moves the cursor to the end, scrolling
+    /// it into view.  This does not trigger the `on_text_changed` callback.
+    pub fn append_text(&mut self, text: &str) {
+        let end = self.get_end();
+
+        self.cursor = end;
+        self.anchor = end;
+        self.insert_text(text);
+        self.invalidate_rows();
+    }
+
+    /// Returns the number of lines in the text.
+    pub fn get_line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Turns word wrap on or off.  When word wrap is off, lines that are wider than the `Widget` are
+    /// scrolled horizontally.
+    pub fn set_word_wrap(&mut self, word_wrap: bool) {
+        self.word_wrap = word_wrap;
+        self.scroll_x = 0;
+        self.invalidate_rows();
+    }
+
+    /// Indicates whether or not word wrap is on.
+    pub fn is_word_wrap(&self) -> bool {
+        self.word_wrap
+    }
+
+    /// Sets the font used to draw the text, by the `font_name` of the TTF file, and the `font_size`
+    /// to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.font_name = font_name;
+        self.font_size = font_size;
+        self.clear_lines();
+    }
+
+    /// Returns the position of the cursor, as a line number, and a character position in that line.
+    pub fn get_cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Moves the cursor to the character position `column` in the line `line`, clearing the
+    /// selection.  The position is limited to the end of the text.
+    pub fn set_cursor(&mut self, line: usize, column: usize) {
+        let line = line.min(self.lines.len() - 1);
+        let position = (line, column.min(self.lines[line].len()));
+
+        self.move_cursor(position, false);
+    }
+
+    /// Selects all of the text in the `Widget`, placing the cursor at the end.
+    pub fn select_all(&mut self) {
+        self.anchor = (0, 0);
+        self.cursor = self.get_end();
+        self.preferred_x = None;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Retrieves the currently selected text, with lines separated by `\n`.  Returns a blank string
+    /// if there is no selection.
+    pub fn get_selected_text(&self) -> String {
+        let (start, end) = self.selection_range();
+        let mut selected = Vec::new();
+
+        for line in start.0..=end.0 {
+            let from = if line == start.0 { start.1 } else { 0 };
+            let to = if line == end.0 {
+                end.1
+            } else {
+                self.lines[line].len()
+            };
+
+            selected.push(
+                self.lines[line]
+                    .text
+                    .chars()
+                    .skip(from)
+                    .take(to - from)
+                    .collect::<String>(),
+            );
+        }
+
+        selected.join("\n")
+    }
+
+    /// Assigns the callback closure that will be used when the text is changed by the user.
+    pub fn on_text_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TextAreaWidget, &[WidgetContainer], &[LayoutContainer], String) + 'static,
+    {
+        self.on_text_changed = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_text_changed` callback.
+    fn call_text_changed_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+    ) {
+        if let Some(mut cb) = self.on_text_changed.take() {
+            let text = TextAreaWidget::get_text(self);
+
+            cb(self, widgets, layouts, text);
+            self.on_text_changed = Some(cb);
+        }
+    }
+
+    /// Internal function that discards the measurements and `Texture`s of all lines, so that they
+    /// are measured and rendered again.
+    fn clear_lines(&mut self) {
+        for line in self.lines.iter_mut() {
+            line.offsets = None;
+            line.texture = None;
+        }
+
+        self.invalidate_rows();
+    }
+
+    /// Internal function that marks the rows as needing to be laid out again, and scrolls the
+    /// cursor into view when they are.
+    fn invalidate_rows(&mut self) {
+        self.rows_valid = false;
+        self.scroll_to_cursor = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the position at the end of the text.
+    fn get_end(&self) -> (usize, usize) {
+        let last_line = self.lines.len() - 1;
+
+        (last_line, self.lines[last_line].len())
+    }
+
+    /// Returns the start and end positions of the selection, in order.
+    fn selection_range(&self) -> ((usize, usize), (usize, usize)) {
+        if self.anchor < self.cursor {
+            (self.anchor, self.cursor)
+        } else {
+            (self.cursor, self.anchor)
+        }
+    }
+
+    /// Converts a character position in a line into a byte position in that line.
+    fn byte_position(&self, line: usize, position: usize) -> usize {
+        let text = &self.lines[line].text;
+
+        text.char_indices()
+            .nth(position)
+            .map(|(byte_position, _)| byte_position)
+            .unwrap_or_else(|| text.len())
+    }
+
+    /// Removes the selected text, placing the cursor at the start of the selection.  Returns `true`
+    /// if text was removed.
+    fn delete_selection(&mut self) -> bool {
+        let (start, end) = self.selection_range();
+
+        if start == end {
+            return false;
+        }
+
+        let byte_start = self.byte_position(start.0, start.1);
+        let byte_end = self.byte_position(end.0, end.1);
+        let text = format!(
+            "{}{}",
+            &self.lines[start.0].text[..byte_start],
+            &self.lines[end.0].text[byte_end..]
+        );
+
+        self.lines.drain(start.0 + 1..=end.0);
+        self.lines[start.0] = TextLine::new(text);
+        self.cursor = start;
+        self.anchor = start;
+        self.invalidate_rows();
+
+        true
+    }
+
+    /// Inserts text at the cursor, replacing the selection.  Line breaks in the text start new
+    /// lines.  Returns `true` if the text changed.
+    fn insert_text(&mut self, text: &str) -> bool {
+        let changed = self.delete_selection();
+        let text = text.replace('\r', "");
+
+        if text.is_empty() {
+            return changed;
+        }
+
+        let (line, position) = self.cursor;
+        let byte_position = self.byte_position(line, position);
+        let head = self.lines[line].text[..byte_position].to_string();
+        let tail = self.lines[line].text[byte_position..].to_string();
+        let pieces: Vec<&str> = text.split('\n').collect();
+        let last_piece = pieces[pieces.len() - 1];
+        let mut new_lines = Vec::new();
+
+        for (i, piece) in pieces.iter().enumerate() {
+            let mut new_line = if i == 0 {
+                format!("{}{}", head, piece)
+            } else {
+                piece.to_string()
+            };
+
+            if i == pieces.len() - 1 {
+                new_line.push_str(&tail);
+            }
+
+            new_lines.push(TextLine::new(new_line));
+        }
+
+        self.lines.splice(line..=line, new_lines);
+        self.cursor = if pieces.len() == 1 {
+            (line, position + last_piece.chars().count())
+        } else {
+            (line + pieces.len() - 1, last_piece.chars().count())
+        };
+        self.anchor = self.cursor;
+        self.invalidate_rows();
+
+        true
+    }
+
+    /// Finds the start of the word before the position specified, moving to the end of the
+    /// previous line if the position is at the start of a line.
+    fn previous_word(&self, position: (usize, usize)) -> (usize, usize) {
+        let (line, mut column) = position;
+
+        if column == 0 {
+            return if line > 0 {
+                (line - 1, self.lines[line - 1].len())
+            } else {
+                position
+            };
+        }
+
+        let characters: Vec<char> = self.lines[line].text.chars().collect();
+
+        while column > 0 && characters[column - 1].is_whitespace() {
+            column -= 1;
+        }
+
+        while column > 0 && !characters[column - 1].is_whitespace() {
+            column -= 1;
+        }
+
+        (line, column)
+    }
+
+    /// Finds the end of the word after the position specified, moving to the start of the next line
+    /// if the position is at the end of a line.
+    fn next_word(&self, position: (usize, usize)) -> (usize, usize) {
+        let (line, mut column) = position;
+        let characters: Vec<char> = self.lines[line].text.chars().collect();
+
+        if column >= characters.len() {
+            return if line + 1 < self.lines.len() {
+                (line + 1, 0)
+            } else {
+                position
+            };
+        }
+
+        while column < characters.len() && characters[column].is_whitespace() {
+            column += 1;
+        }
+
+        while column < characters.len() && !characters[column].is_whitespace() {
+            column += 1;
+        }
+
+        (line, column)
+    }
+
+    /// Returns the position one character before the position specified, moving to the end of the
+    /// previous line if the position is at the start of a line.
+    fn previous_position(&self, position: (usize, usize)) -> (usize, usize) {
+        match position {
+            (0, 0) => position,
+            (line, 0) => (line - 1, self.lines[line - 1].len()),
+            (line, column) => (line, column - 1),
+        }
+    }
+
+    /// Returns the position one character after the position specified, moving to the start of the
+    /// next line if the position is at the end of a line.
+    fn next_position(&self, position: (usize, usize)) -> (usize, usize) {
+        let (line, column) = position;
+
+        if column < self.lines[line].len() {
+            (line, column + 1)
+        } else if line + 1 < self.lines.len() {
+            (line + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    /// Moves the cursor to a new position.  If `extend` is `true`, the selection is extended to the
+    /// new position, otherwise, the selection is cleared.
+    fn move_cursor(&mut self, position: (usize, usize), extend: bool) {
+        self.cursor = position;
+
+        if !extend {
+            self.anchor = position;
+        }
+
+        self.scroll_to_cursor = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the index of the row that the position is displayed in.  This is only valid while
+    /// the rows are up to date.
+    fn row_of(&self, position: (usize, usize)) -> usize {
+        let (line, column) = position;
+
+        for (i, row) in self.rows.iter().enumerate() {
+            let last_in_line = ends_line(&self.rows, i);
+
+            if row.line == line && column >= row.start && (column < row.end || last_in_line) {
+                return i;
+            }
+        }
+
+        0
+    }
+
+    /// Returns the horizontal position of a position within the row that it is displayed in.
+    fn x_in_row(&self, row: TextRow, column: usize) -> u32 {
+        let line = &self.lines[row.line];
+
+        line.offset(column).saturating_sub(line.offset(row.start))
+    }
+
+    /// Returns the position in the row at `row_index` that is closest to the horizontal position
+    /// `x`, measured from the start of the row.
+    fn position_in_row(&self, row_index: usize, x: i32) -> (usize, usize) {
+        let row = self.rows[row_index];
+        let last_in_line = ends_line(&self.rows, row_index);
+
+        // The end of a wrapped row is the same position as the start of the next row, so it is not
+        // used here, so that the cursor stays on this row.
+        let last_column = if last_in_line || row.end == row.start {
+            row.end
+        } else {
+            row.end - 1
+        };
+        let mut closest = row.start;
+
+        for column in row.start..=last_column {
+            if (self.x_in_row(row, column) as i32 - x).abs()
+                < (self.x_in_row(row, closest) as i32 - x).abs()
+            {
+                closest = column;
+            }
+        }
+
+        (row.line, closest)
+    }
+
+    /// Moves the cursor up or down by `count` rows, keeping its horizontal position.  If the rows
+    /// are not up to date, the cursor is moved by lines instead.
+    fn move_vertically(&mut self, count: i32, extend: bool) {
+        let position = if self.rows_valid && !self.rows.is_empty() {
+            let row_index = self.row_of(self.cursor);
+            let x = match self.preferred_x {
+                Some(x) => x,
+                None => self.x_in_row(self.rows[row_index], self.cursor.1),
+            };
+            let target = row_index as i32 + count;
+
+            self.preferred_x = Some(x);
+
+            if target < 0 {
+                (0, 0)
+            } else if target >= self.rows.len() as i32 {
+                self.get_end()
+            } else {
+                self.position_in_row(target as usize, x as i32)
+            }
+        } else {
+            let line = (self.cursor.0 as i32 + count)
+                .max(0)
+                .min(self.lines.len() as i32 - 1) as usize;
+
+            (line, self.cursor.1.min(self.lines[line].len()))
+        };
+
+        self.move_cursor(position, extend);
+    }
+
+    /// Returns the number of rows that fit in the `Widget`.
+    fn get_page_rows(&self) -> i32 {
+        (self.scroll_bar.get_viewport_length() / self.line_height.max(1)).max(1) as i32
+    }
+
+    /// Returns the area of the scroll bar track, relative to the `Widget`, if the scroll bar is
+    /// shown.
+    fn get_track_area(&mut self) -> Option<Rect> {
+        let bounds = self.get_config().get_size(CONFIG_SIZE);
+
+        if self.scroll_bar.is_needed() {
+            Some(Rect::new(
+                bounds[SIZE_WIDTH].saturating_sub(SCROLL_BAR_THICKNESS + 1) as i32,
+                1,
+                SCROLL_BAR_THICKNESS,
+                bounds[SIZE_HEIGHT].saturating_sub(2).max(1),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the width available for text, given whether or not the scroll bar is shown.
+    fn get_text_width(&mut self, scroll_bar_shown: bool) -> u32 {
+        let bounds = self.get_config().get_size(CONFIG_SIZE);
+        let track_width = if scroll_bar_shown {
+            SCROLL_BAR_THICKNESS + 1
+        } else {
+            0
+        };
+
+        bounds[SIZE_WIDTH]
+            .saturating_sub(TEXT_PADDING as u32 * 2 + track_width)
+            .max(1)
+    }
+
+    /// Determines the position closest to the point `x, y`, relative to the `Widget`.  Returns the
+    /// cursor position if the rows are not up to date.
+    fn position_at(&self, x: i32, y: i32) -> (usize, usize) {
+        if !self.rows_valid || self.rows.is_empty() {
+            return self.cursor;
+        }
+
+        let content_y = y - TEXT_PADDING + self.scroll_bar.get_position() as i32;
+        let row_index = (content_y / self.line_height.max(1) as i32)
+            .max(0)
+            .min(self.rows.len() as i32 - 1) as usize;
+
+        self.position_in_row(row_index, x - TEXT_PADDING + self.scroll_x)
+    }
+
+    /// Selects the word at the position specified.
+    fn select_word(&mut self, position: (usize, usize)) {
+        let (line, column) = position;
+        let characters: Vec<char> = self.lines[line].text.chars().collect();
+        let mut start = column;
+        let mut end = column;
+
+        while start > 0 && !characters[start - 1].is_whitespace() {
+            start -= 1;
+        }
+
+        while end < characters.len() && !characters[end].is_whitespace() {
+            end += 1;
+        }
+
+        self.anchor = (line, start);
+        self.move_cursor((line, end), true);
+    }
+
+    /// Internal function that measures the lines that have changed, and lays out the rows, if they
+    /// are not up to date.  The scroll bar is updated to match the rows.
+    fn update_rows(&mut self, t: &mut TextureCache) {
+        for line in self.lines.iter_mut() {
+            if line.offsets.is_none() {
+                let (offsets, line_height) = t.measure_text(
+                    self.font_name.clone(),
+                    self.font_size as u16,
+                    FontStyle::NORMAL,
+                    line.text.clone(),
+                );
+
+                line.offsets = Some(offsets);
+                self.line_height = line_height;
+            }
+        }
+
+        if self.rows_valid {
+            return;
+        }
+
+        let viewport_height = self.get_config().get_size(CONFIG_SIZE)[SIZE_HEIGHT]
+            .saturating_sub(2)
+            .max(1);
+
+        // The text is laid out at the full width first.  If it then needs a scroll bar, it is laid
+        // out again in the space that is left next to the scroll bar.
+        for scroll_bar_shown in &[false, true] {
+            let wrap_width = if self.word_wrap {
+                Some(self.get_text_width(*scroll_bar_shown))
+            } else {
+                None
+            };
+
+            self.rows.clear();
+
+            for (i, line) in self.lines.iter().enumerate() {
+                wrap_line(i, line, wrap_width, &mut self.rows);
+            }
+
+            self.scroll_bar.set_lengths(
+                self.rows.len() as u32 * self.line_height + TEXT_PADDING as u32 * 2,
+                viewport_height,
+            );
+
+            if !self.scroll_bar.is_needed() {
+                break;
+            }
+        }
+
+        self.rows_valid = true;
+    }
+
+    /// Internal function that scrolls the text so that the cursor is visible.
+    fn update_scroll_position(&mut self) {
+        if !self.scroll_to_cursor || self.rows.is_empty() {
+            return;
+        }
+
+        let row_index = self.row_of(self.cursor);
+        let row_top = TEXT_PADDING + (row_index as u32 * self.line_height) as i32;
+        let row_bottom = row_top + self.line_height as i32 + TEXT_PADDING;
+        let viewport_height = self.scroll_bar.get_viewport_length() as i32;
+        let position = self.scroll_bar.get_position() as i32;
+
+        if row_top - TEXT_PADDING < position {
+            self.scroll_bar.set_position(row_top - TEXT_PADDING);
+        } else if row_bottom > position + viewport_height {
+            self.scroll_bar.set_position(row_bottom - viewport_height);
+        }
+
+        if self.word_wrap {
+            self.scroll_x = 0;
+        } else {
+            let text_width = self.get_text_width(self.scroll_bar.is_needed()) as i32;
+            let cursor_x = self.x_in_row(self.rows[row_index], self.cursor.1) as i32;
+
+            if cursor_x - self.scroll_x > text_width - 1 {
+                self.scroll_x = cursor_x - text_width + 1;
+            } else if cursor_x < self.scroll_x {
+                self.scroll_x = cursor_x;
+            }
+        }
+
+        self.scroll_to_cursor = false;
+    }
+}
+
+/// Splits `text` into `TextLine`s at each line break.  There is always at least one line.
+fn split_lines(text: &str) -> Vec<TextLine> {
+    text.replace('\r', "")
+        .split('\n')
+        .map(|line| TextLine::new(line.to_string()))
+        .collect()
+}
+
+/// Indicates whether or not the row at `index` is the last row used to display its line.
+fn ends_line(rows: &[TextRow], index: usize) -> bool {
+    rows.get(index + 1)
+        .is_none_or(|next| next.line != rows[index].line)
+}
+
+/// Adds the rows used to display the line at `line_index` to `rows`.  If a `wrap_width` is given,
+/// the line is broken into rows no wider than it, after the last whitespace that fits in each row
+/// where possible.  Otherwise, the whole line is displayed in a single row.
+fn wrap_line(line_index: usize, line: &TextLine, wrap_width: Option<u32>, rows: &mut Vec<TextRow>) {
+    let length = line.len();
+    let characters: Vec<char> = line.text.chars().collect();
+    let mut start = 0;
+
+    loop {
+        let end = match wrap_width {
+            Some(width) if line.offset(length) - line.offset(start) > width => {
+                let mut end = start + 1;
+
+                while end < length && line.offset(end + 1) - line.offset(start) <= width {
+                    end += 1;
+                }
+
+                (start + 1..=end)
+                    .rev()
+                    .find(|k| characters[k - 1].is_whitespace())
+                    .unwrap_or(end)
+            }
+            _ => length,
+        };
+
+        rows.push(TextRow {
+            line: line_index,
+            start,
+            end,
+        });
+
+        if end >= length {
+            break;
+        }
+
+        start = end;
+    }
+}
+
+/// This is the `Widget` implementation of the `TextAreaWidget`.
+impl Widget for TextAreaWidget {
+    /// Draws the visible rows of text, the selection, the cursor, and the scroll bar.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+
+            self.update_rows(t);
+            self.update_scroll_position();
+            self.texture_store
+                .create_or_resize_texture(c, bounds[SIZE_WIDTH], bounds[SIZE_HEIGHT]);
+
+            let line_height = self.line_height.max(1);
+            let position = self.scroll_bar.get_position();
+            let first_row = (position.saturating_sub(TEXT_PADDING as u32) / line_height) as usize;
+            let last_row =
+                (((position + self.scroll_bar.get_viewport_length()) / line_height) as usize + 1)
+                    .min(self.rows.len());
+            let text_color = self.get_color(CONFIG_COLOR_TEXT);
+
+            // Only the lines that are visible are rendered, and each one is kept until it changes.
+            for row in &self.rows[first_row..last_row] {
+                let line = &mut self.lines[row.line];
+
+                if line.texture.is_none() && !line.text.is_empty() {
+                    line.texture = Some(t.render_text_line(
+                        c,
+                        self.font_name.clone(),
+                        self.font_size as u16,
+                        FontStyle::NORMAL,
+                        line.text.clone(),
+                        text_color,
+                    ));
+                }
+            }
+
+            let track = self.get_track_area();
+            let text_width = self.get_text_width(track.is_some());
+            let base_color = self.get_color(CONFIG_COLOR_BASE);
+            let border_color = self.get_color(CONFIG_COLOR_BORDER);
+            let selected_color = self.get_color(CONFIG_COLOR_SELECTED);
+            let thumb_color = self.get_color(CONFIG_COLOR_SECONDARY);
+            let border_width = self.get_numeric(CONFIG_BORDER_WIDTH);
+            let focused = self.get_config().is_focused();
+            let (selection_start, selection_end) = self.selection_range();
+            let cursor_row = self.row_of(self.cursor);
+            let cursor_x = self.x_in_row(self.rows[cursor_row], self.cursor.1) as i32;
+            let scroll_x = self.scroll_x;
+            let lines = &self.lines;
+            let rows = &self.rows;
+            let scroll_bar = &self.scroll_bar;
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+                texture.set_clip_rect(Rect::new(
+                    TEXT_PADDING,
+                    1,
+                    text_width,
+                    bounds[SIZE_HEIGHT].saturating_sub(2).max(1),
+                ));
+
+                for (i, row) in rows.iter().enumerate().take(last_row).skip(first_row) {
+                    let line = &lines[row.line];
+                    let row_x = TEXT_PADDING - scroll_x;
+                    let row_y = TEXT_PADDING + (i as u32 * line_height) as i32 - position as i32;
+                    let row_offset = line.offset(row.start);
+                    let last_in_line = ends_line(rows, i);
+
+                    // Highlight the part of the row that is selected, including the line break at
+                    // the end of the line if the selection continues onto the next line.
+                    if row.line >= selection_start.0 && row.line <= selection_end.0 {
+                        let from = if row.line == selection_start.0 {
+                            selection_start.1.max(row.start)
+                        } else {
+                            row.start
+                        };
+                        let to = if row.line == selection_end.0 {
+                            selection_end.1.min(row.end)
+                        } else {
+                            row.end
+                        };
+                        let newline_width = if last_in_line && row.line < selection_end.0 {
+                            NEWLINE_WIDTH
+                        } else {
+                            0
+                        };
+
+                        if from <= to && (from < to || newline_width > 0) {
+                            texture.set_draw_color(selected_color);
+                            texture
+                                .fill_rect(Rect::new(
+                                    row_x + (line.offset(from) - row_offset) as i32,
+                                    row_y,
+                                    line.offset(to) - line.offset(from) + newline_width,
+                                    line_height,
+                                ))
+                                .unwrap();
+                        }
+                    }
+
+                    if let Some((font_texture, _, height)) = &line.texture {
+                        let row_width = line.offset(row.end) - row_offset;
+
+                        if row_width > 0 {
+                            texture
+                                .copy(
+                                    font_texture,
+                                    Rect::new(row_offset as i32, 0, row_width, *height),
+                                    Rect::new(row_x, row_y, row_width, *height),
+                                )
+                                .unwrap();
+                        }
+                    }
+
+                    if focused && i == cursor_row {
+                        texture.set_draw_color(text_color);
+                        texture
+                            .fill_rect(Rect::new(row_x + cursor_x, row_y, 1, line_height))
+                            .unwrap();
+                    }
+                }
+
+                texture.set_clip_rect(None);
+
+                if let Some(track) = track {
+                    scroll_bar.draw(texture, track, base_color, thumb_color);
+                }
+
+                texture.set_draw_color(border_color);
+
+                for i in 0..border_width {
+                    texture
+                        .draw_rect(Rect::new(
+                            i,
+                            i,
+                            bounds[SIZE_WIDTH] - (i * 2) as u32,
+                            bounds[SIZE_HEIGHT] - (i * 2) as u32,
+                        ))
+                        .unwrap();
+                }
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Tracks the position of the mouse so that clicks can position the cursor.  When the mouse is
+    /// moved while the mouse button is held, the selection is extended to the position under the
+    /// mouse, or the scroll bar thumb is dragged.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = _points[POINT_X] - origin[POINT_X];
+        let y = _points[POINT_Y] - origin[POINT_Y];
+
+        self.mouse_point = make_points(x, y);
+
+        if let Some(track) = self.get_track_area() {
+            if self.scroll_bar.mouse_moved(track, x, y) {
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        if self.selecting {
+            let position = self.position_at(x, y);
+
+            if position != self.cursor {
+                self.move_cursor(position, true);
+            }
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Scrolls the text by one row for each step of the mouse wheel.
+    fn mouse_scrolled(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        if self
+            .scroll_bar
+            .scroll_by(-_points[POINT_Y] * self.line_height as i32)
+        {
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_scrolled_callback(_widgets, _layouts, _points);
+    }
+
+    /// Overrides the `button_clicked` callback to position the cursor, and to handle the scroll
+    /// bar.  A double click selects the word under the mouse.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 {
+            if _state {
+                let x = self.mouse_point[POINT_X];
+                let y = self.mouse_point[POINT_Y];
+
+                if let Some(track) = self.get_track_area() {
+                    if self.scroll_bar.button_down(track, x, y) {
+                        self.get_config().set_invalidated(true);
+                        return;
+                    }
+                }
+
+                let position = self.position_at(x, y);
+
+                self.preferred_x = None;
+
+                if _clicks == 2 {
+                    self.select_word(position);
+                } else {
+                    self.move_cursor(position, false);
+                    self.selecting = true;
+                }
+            } else {
+                self.selecting = false;
+                self.scroll_bar.button_up();
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles cursor movement, deletion, and new lines.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        let extend = _keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+        let control = _keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD);
+        let vertical = matches!(
+            _keycode,
+            Keycode::Up | Keycode::Down | Keycode::PageUp | Keycode::PageDown
+        );
+
+        if !vertical {
+            self.preferred_x = None;
+        }
+
+        match _keycode {
+            Keycode::Left => {
+                let position = if control {
+                    self.previous_word(self.cursor)
+                } else if !extend && self.cursor != self.anchor {
+                    self.selection_range().0
+                } else {
+                    self.previous_position(self.cursor)
+                };
+
+                self.move_cursor(position, extend);
+            }
+
+            Keycode::Right => {
+                let position = if control {
+                    self.next_word(self.cursor)
+                } else if !extend && self.cursor != self.anchor {
+                    self.selection_range().1
+                } else {
+                    self.next_position(self.cursor)
+                };
+
+                self.move_cursor(position, extend);
+            }
+
+            Keycode::Up => self.move_vertically(-1, extend),
+
+            Keycode::Down => self.move_vertically(1, extend),
+
+            Keycode::PageUp => self.move_vertically(-self.get_page_rows(), extend),
+
+            Keycode::PageDown => self.move_vertically(self.get_page_rows(), extend),
+
+            Keycode::Home | Keycode::End => {
+                let home = _keycode == Keycode::Home;
+                let position = if control {
+                    if home {
+                        (0, 0)
+                    } else {
+                        self.get_end()
+                    }
+                } else if self.rows_valid && !self.rows.is_empty() {
+                    let row = self.rows[self.row_of(self.cursor)];
+
+                    if home {
+                        (row.line, row.start)
+                    } else {
+                        self.position_in_row(self.row_of(self.cursor), i32::MAX)
+                    }
+                } else if home {
+                    (self.cursor.0, 0)
+                } else {
+                    (self.cursor.0, self.lines[self.cursor.0].len())
+                };
+
+                self.move_cursor(position, extend);
+            }
+
+            Keycode::A if control => self.select_all(),
+
+            Keycode::Backspace | Keycode::Delete => {
+                if !self.delete_selection() {
+                    self.anchor = match (_keycode, control) {
+                        (Keycode::Backspace, true) => self.previous_word(self.cursor),
+                        (Keycode::Backspace, false) => self.previous_position(self.cursor),
+                        (_, true) => self.next_word(self.cursor),
+                        (_, false) => self.next_position(self.cursor),
+                    };
+
+                    if !self.delete_selection() {
+                        return;
+                    }
+                }
+
+                self.call_text_changed_callback(_widgets, _layouts);
+            }
+
+            Keycode::Return | Keycode::KpEnter => {
+                self.insert_text("\n");
+                self.call_text_changed_callback(_widgets, _layouts);
+            }
+
+            _ => (),
+        }
+
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Inserts typed text at the cursor position, replacing any selected text.
+    fn text_input(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _text: String,
+    ) {
+        self.preferred_x = None;
+
+        if self.insert_text(&_text) {
+            self.call_text_changed_callback(_widgets, _layouts);
+        }
+
+        self.text_input_callback(_widgets, _layouts, _text);
+    }
+
+    /// Redraws the cursor when focus changes.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Monitors for changes in the text, color changes, font sizes, or the size of the `Widget`.
+    fn on_config_changed(&mut self, _k: u8, _v: Config) {
+        match _k {
+            CONFIG_COLOR_TEXT => self.clear_lines(),
+            CONFIG_COLOR_BASE | CONFIG_COLOR_BORDER | CONFIG_COLOR_SELECTED => {
+                self.get_config().set_invalidated(true)
+            }
+            CONFIG_SIZE => self.invalidate_rows(),
+            CONFIG_FONT_SIZE => {
+                if let Config::Numeric(size) = _v {
+                    self.font_size = size;
+                    self.clear_lines();
+                }
+            }
+            CONFIG_TEXT => {
+                if let Config::Text(text) = _v {
+                    self.set_text(text);
+                }
+            }
+
+            _ => (),
+        };
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
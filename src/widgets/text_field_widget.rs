@@ -0,0 +1,625 @@
+// Pushrod Widget Library
+// Text Field Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+use crate::render::{Points, Size, POINT_X, SIZE_HEIGHT, SIZE_WIDTH};
+
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the number of pixels between the border of the `TextFieldWidget` and the text.
+const TEXT_PADDING: i32 = 4;
+
+/// This is the callback type that is used when an `on_text_changed` or `on_submit` callback is
+/// triggered from this `Widget`.  Returns the current text of the `Widget`.
+pub type OnTextFieldCallbackType =
+    Option<Box<dyn FnMut(&mut TextFieldWidget, &[WidgetContainer], &[LayoutContainer], String)>>;
+
+/// This is the storage object for the `TextFieldWidget`.  It stores the config, properties, callback
+/// registry, the text being edited, and the cursor and selection positions.
+pub struct TextFieldWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    font_name: String,
+    font_size: i32,
+    text: String,
+    placeholder: String,
+    max_length: usize,
+    cursor: usize,
+    anchor: usize,
+    scroll_x: i32,
+    offsets: Vec<u32>,
+    line_height: u32,
+    measured: bool,
+    selecting: bool,
+    mouse_x: i32,
+    on_text_changed: OnTextFieldCallbackType,
+    on_submit: OnTextFieldCallbackType,
+}
+
+/// This is the implementation of the `TextFieldWidget`, a single line text entry box.  Text is
+/// scrolled horizontally when it is larger than the bounds of the `Widget`.  The cursor position and
+/// selection are stored as character positions in the text, not byte positions.
+impl TextFieldWidget {
+    /// Creates a new `TextFieldWidget` given the `x, y, w, h` coordinates, the initial `text` to
+    /// edit, and the `font_size` of the font to display.  The cursor is placed at the end of the text.
+    pub fn new(points: Points, size: Size, text: String, font_size: i32) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+        let cursor = text.chars().count();
+
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+        config.set_numeric(CONFIG_BORDER_WIDTH, 1);
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size,
+            text,
+            placeholder: String::from(""),
+            max_length: 0,
+            cursor,
+            anchor: cursor,
+            scroll_x: 0,
+            offsets: vec![0],
+            line_height: 0,
+            measured: false,
+            selecting: false,
+            mouse_x: 0,
+            on_text_changed: None,
+            on_submit: None,
+        }
+    }
+
+    /// Retrieves the text currently being edited.
+    pub fn get_text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Replaces the text being edited, moving the cursor to the end of the text.  If a maximum
+    /// length is set, the text is truncated to that length.  This does not trigger the
+    /// `on_text_changed` callback.
+    pub fn set_text(&mut self, text: String) {
+        self.text = if self.max_length > 0 {
+            text.chars().take(self.max_length).collect()
+        } else {
+            text
+        };
+        self.cursor = self.text.chars().count();
+        self.anchor = self.cursor;
+        self.measured = false;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the placeholder text, which is displayed using the `CONFIG_COLOR_SECONDARY` color when
+    /// the `Widget` contains no text.
+    pub fn set_placeholder(&mut self, placeholder: String) {
+        self.placeholder = placeholder;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the maximum number of characters that can be entered.  A `max_length` of `0` removes
+    /// the limit.  Existing text is not truncated.
+    pub fn set_max_length(&mut self, max_length: usize) {
+        self.max_length = max_length;
+    }
+
+    /// Selects all of the text in the `Widget`, placing the cursor at the end.
+    pub fn select_all(&mut self) {
+        self.anchor = 0;
+        self.cursor = self.text.chars().count();
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Retrieves the currently selected text.  Returns a blank string if there is no selection.
+    pub fn get_selected_text(&self) -> String {
+        let (start, end) = self.selection_range();
+
+        self.text.chars().skip(start).take(end - start).collect()
+    }
+
+    /// Assigns the callback closure that will be used when the text is changed by the user.
+    pub fn on_text_changed<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TextFieldWidget, &[WidgetContainer], &[LayoutContainer], String) + 'static,
+    {
+        self.on_text_changed = Some(Box::new(callback));
+    }
+
+    /// Assigns the callback closure that will be used when `Enter` is pressed in the `Widget`.
+    pub fn on_submit<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TextFieldWidget, &[WidgetContainer], &[LayoutContainer], String) + 'static,
+    {
+        self.on_submit = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_text_changed` callback.
+    fn call_text_changed_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+    ) {
+        if let Some(mut cb) = self.on_text_changed.take() {
+            cb(self, widgets, layouts, self.text.clone());
+            self.on_text_changed = Some(cb);
+        }
+    }
+
+    /// Internal function that triggers the `on_submit` callback.
+    fn call_submit_callback(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+        if let Some(mut cb) = self.on_submit.take() {
+            cb(self, widgets, layouts, self.text.clone());
+            self.on_submit = Some(cb);
+        }
+    }
+
+    /// Returns the start and end character positions of the selection, in order.
+    fn selection_range(&self) -> (usize, usize) {
+        if self.anchor < self.cursor {
+            (self.anchor, self.cursor)
+        } else {
+            (self.cursor, self.anchor)
+        }
+    }
+
+    /// Converts a character position into a byte position in the text.
+    fn byte_position(&self, position: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(position)
+            .map(|(byte_position, _)| byte_position)
+            .unwrap_or_else(|| self.text.len())
+    }
+
+    /// Removes the selected text, placing the cursor at the start of the selection.  Returns `true`
+    /// if text was removed.
+    fn delete_selection(&mut self) -> bool {
+        let (start, end) = self.selection_range();
+
+        if start == end {
+            return false;
+        }
+
+        let byte_start = self.byte_position(start);
+        let byte_end = self.byte_position(end);
+
+        self.text.replace_range(byte_start..byte_end, "");
+        self.cursor = start;
+        self.anchor = start;
+        self.measured = false;
+
+        true
+    }
+
+    /// Inserts text at the cursor, replacing the selection.  Text that would exceed the maximum
+    /// length is discarded.  Returns `true` if the text changed.
+    fn insert_text(&mut self, text: &str) -> bool {
+        let mut changed = self.delete_selection();
+        let current_length = self.text.chars().count();
+        let insert: String = if self.max_length > 0 {
+            text.chars()
+                .take(self.max_length.saturating_sub(current_length))
+                .collect()
+        } else {
+            text.to_string()
+        };
+
+        if !insert.is_empty() {
+            let byte_position = self.byte_position(self.cursor);
+
+            self.text.insert_str(byte_position, &insert);
+            self.cursor += insert.chars().count();
+            self.anchor = self.cursor;
+            self.measured = false;
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Finds the start of the word before the character position specified.
+    fn previous_word(&self, position: usize) -> usize {
+        let characters: Vec<char> = self.text.chars().collect();
+        let mut position = position;
+
+        while position > 0 && characters[position - 1].is_whitespace() {
+            position -= 1;
+        }
+
+        while position > 0 && !characters[position - 1].is_whitespace() {
+            position -= 1;
+        }
+
+        position
+    }
+
+    /// Finds the end of the word after the character position specified.
+    fn next_word(&self, position: usize) -> usize {
+        let characters: Vec<char> = self.text.chars().collect();
+        let mut position = position;
+
+        while position < characters.len() && characters[position].is_whitespace() {
+            position += 1;
+        }
+
+        while position < characters.len() && !characters[position].is_whitespace() {
+            position += 1;
+        }
+
+        position
+    }
+
+    /// Moves the cursor to a new position.  If `extend` is `true`, the selection is extended to the
+    /// new position, otherwise, the selection is cleared.
+    fn move_cursor(&mut self, position: usize, extend: bool) {
+        self.cursor = position;
+
+        if !extend {
+            self.anchor = position;
+        }
+
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Determines the character position closest to the X coordinate on the screen, based on the
+    /// character offsets calculated during the last draw.
+    fn position_at(&mut self, x: i32) -> usize {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let text_x = x - origin[POINT_X] - TEXT_PADDING + self.scroll_x;
+        let mut closest = 0;
+
+        for (i, offset) in self.offsets.iter().enumerate() {
+            if (*offset as i32 - text_x).abs() < (self.offsets[closest] as i32 - text_x).abs() {
+                closest = i;
+            }
+        }
+
+        closest
+    }
+}
+
+/// This is the `Widget` implementation of the `TextFieldWidget`.
+impl Widget for TextFieldWidget {
+    /// Draws the `TextFieldWidget` contents.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            if !self.measured {
+                let (offsets, line_height) = t.measure_text(
+                    self.font_name.clone(),
+                    self.font_size as u16,
+                    sdl2::ttf::FontStyle::NORMAL,
+                    self.text.clone(),
+                );
+
+                self.offsets = offsets;
+                self.line_height = line_height;
+                self.measured = true;
+            }
+
+            // Scroll the text so that the cursor is always visible.
+            let visible_width = bounds[SIZE_WIDTH] as i32 - TEXT_PADDING * 2;
+            let text_width = self.offsets[self.offsets.len() - 1] as i32;
+            let cursor_x = self.offsets[self.cursor] as i32;
+
+            if cursor_x - self.scroll_x > visible_width {
+                self.scroll_x = cursor_x - visible_width;
+            } else if cursor_x < self.scroll_x {
+                self.scroll_x = cursor_x;
+            }
+
+            if text_width - self.scroll_x < visible_width {
+                self.scroll_x = (text_width - visible_width).max(0);
+            }
+
+            let base_color = self.get_color(CONFIG_COLOR_BASE);
+            let border_color = self.get_color(CONFIG_COLOR_BORDER);
+            let text_color = self.get_color(CONFIG_COLOR_TEXT);
+            let selected_color = self.get_color(CONFIG_COLOR_SELECTED);
+            let placeholder_color = self.get_color(CONFIG_COLOR_SECONDARY);
+            let border_width = self.get_numeric(CONFIG_BORDER_WIDTH);
+            let focused = self.get_config().is_focused();
+            let (selection_start, selection_end) = self.selection_range();
+            let selection_x = self.offsets[selection_start] as i32;
+            let selection_width = self.offsets[selection_end] - self.offsets[selection_start];
+            let scroll_x = self.scroll_x;
+            let text_y = (bounds[SIZE_HEIGHT] as i32 - self.line_height as i32) / 2;
+            let line_height = self.line_height;
+
+            let text_texture = if !self.text.is_empty() {
+                Some(t.render_text_line(
+                    c,
+                    self.font_name.clone(),
+                    self.font_size as u16,
+                    sdl2::ttf::FontStyle::NORMAL,
+                    self.text.clone(),
+                    text_color,
+                ))
+            } else if !self.placeholder.is_empty() {
+                Some(t.render_text_line(
+                    c,
+                    self.font_name.clone(),
+                    self.font_size as u16,
+                    sdl2::ttf::FontStyle::NORMAL,
+                    self.placeholder.clone(),
+                    placeholder_color,
+                ))
+            } else {
+                None
+            };
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                if selection_width > 0 {
+                    texture.set_draw_color(selected_color);
+                    texture
+                        .fill_rect(Rect::new(
+                            TEXT_PADDING + selection_x - scroll_x,
+                            text_y,
+                            selection_width,
+                            line_height,
+                        ))
+                        .unwrap();
+                }
+
+                if let Some((font_texture, width, height)) = text_texture {
+                    texture
+                        .copy(
+                            &font_texture,
+                            None,
+                            Rect::new(TEXT_PADDING - scroll_x, text_y, width, height),
+                        )
+                        .unwrap();
+                }
+
+                if focused {
+                    texture.set_draw_color(text_color);
+                    texture
+                        .fill_rect(Rect::new(
+                            TEXT_PADDING + cursor_x - scroll_x,
+                            text_y,
+                            1,
+                            line_height,
+                        ))
+                        .unwrap();
+                }
+
+                texture.set_draw_color(border_color);
+
+                for i in 0..border_width {
+                    texture
+                        .draw_rect(Rect::new(
+                            i,
+                            i,
+                            bounds[SIZE_WIDTH] - (i * 2) as u32,
+                            bounds[SIZE_HEIGHT] - (i * 2) as u32,
+                        ))
+                        .unwrap();
+                }
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Tracks the position of the mouse so that clicks can position the cursor.  When the mouse is
+    /// moved while the mouse button is held, the selection is extended to the character under the
+    /// mouse.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        points: Points,
+    ) {
+        self.mouse_x = points[POINT_X];
+
+        if self.selecting {
+            let position = self.position_at(points[POINT_X]);
+
+            if position != self.cursor {
+                self.move_cursor(position, true);
+            }
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, points);
+    }
+
+    /// Overrides the `button_clicked` callback to position the cursor.  A double click selects
+    /// all of the text in the `Widget`.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 {
+            if _state {
+                if _clicks == 2 {
+                    self.select_all();
+                } else {
+                    let position = self.position_at(self.mouse_x);
+
+                    self.move_cursor(position, false);
+                    self.selecting = true;
+                }
+            } else {
+                self.selecting = false;
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles cursor movement, deletion, and submission of the text.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        let extend = _keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+        let by_word = _keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD);
+        let length = self.text.chars().count();
+
+        match _keycode {
+            Keycode::Left => {
+                let position = if by_word {
+                    self.previous_word(self.cursor)
+                } else if !extend && self.cursor != self.anchor {
+                    self.selection_range().0
+                } else {
+                    self.cursor.saturating_sub(1)
+                };
+
+                self.move_cursor(position, extend);
+            }
+
+            Keycode::Right => {
+                let position = if by_word {
+                    self.next_word(self.cursor)
+                } else if !extend && self.cursor != self.anchor {
+                    self.selection_range().1
+                } else {
+                    (self.cursor + 1).min(length)
+                };
+
+                self.move_cursor(position, extend);
+            }
+
+            Keycode::Home => self.move_cursor(0, extend),
+
+            Keycode::End => self.move_cursor(length, extend),
+
+            Keycode::A if by_word => self.select_all(),
+
+            Keycode::Backspace => {
+                let mut deleted = self.delete_selection();
+
+                if !deleted && self.cursor > 0 {
+                    self.anchor = if by_word {
+                        self.previous_word(self.cursor)
+                    } else {
+                        self.cursor - 1
+                    };
+                    deleted = self.delete_selection();
+                }
+
+                if deleted {
+                    self.get_config().set_invalidated(true);
+                    self.call_text_changed_callback(_widgets, _layouts);
+                }
+            }
+
+            Keycode::Delete => {
+                let mut deleted = self.delete_selection();
+
+                if !deleted && self.cursor < length {
+                    self.anchor = if by_word {
+                        self.next_word(self.cursor)
+                    } else {
+                        self.cursor + 1
+                    };
+                    deleted = self.delete_selection();
+                }
+
+                if deleted {
+                    self.get_config().set_invalidated(true);
+                    self.call_text_changed_callback(_widgets, _layouts);
+                }
+            }
+
+            Keycode::Return | Keycode::KpEnter => {
+                self.call_submit_callback(_widgets, _layouts);
+            }
+
+            _ => (),
+        }
+
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Inserts typed text at the cursor position, replacing any selected text.
+    fn text_input(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _text: String,
+    ) {
+        if self.insert_text(&_text) {
+            self.get_config().set_invalidated(true);
+            self.call_text_changed_callback(_widgets, _layouts);
+        }
+
+        self.text_input_callback(_widgets, _layouts, _text);
+    }
+
+    /// Monitors for changes in the text, color changes, or font sizes.
+    fn on_config_changed(&mut self, _k: u8, _v: Config) {
+        match _k {
+            CONFIG_COLOR_TEXT | CONFIG_COLOR_BASE | CONFIG_COLOR_BORDER => {
+                self.get_config().set_invalidated(true)
+            }
+            CONFIG_FONT_SIZE => {
+                if let Config::Numeric(size) = _v {
+                    self.font_size = size;
+                    self.measured = false;
+                    self.get_config().set_invalidated(true);
+                }
+            }
+            CONFIG_TEXT => {
+                if let Config::Text(text) = _v {
+                    self.set_text(text);
+                }
+            }
+
+            _ => (),
+        };
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
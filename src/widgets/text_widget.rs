@@ -33,6 +33,7 @@ use std::path::Path;
 
 /// This enum is used by the `TextWidget`, which controls the justification of the text being
 /// rendered within the bounds of the `Widget`.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum TextJustify {
     /// Left-justified text.
     Left,
@@ -101,6 +101,20 @@ impl TileWidget {
         self.on_click = Some(Box::new(callback));
     }
 
+    /// Sets the selected state of the `TileWidget`.  This does not trigger the `on_click`
+    /// callback.
+    pub fn set_selected(&mut self, selected: bool) {
+        if self.selected != selected {
+            self.selected = selected;
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Indicates whether or not the `TileWidget` is selected.
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
     /// Internal function that triggers the `on_click` callback.
     fn call_click_callback(
         &mut self,
@@ -0,0 +1,772 @@
+// Pushrod Widget Library
+// Toolbar Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{BlendMode, Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+use crate::widgets::popup_menu::*;
+use crate::widgets::tile_widget::TileWidget;
+use crate::widgets::toolbar_widget::ToolbarOrientation::ToolbarHorizontal;
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the space between the edges of the `ToolbarWidget` and its items.
+const TOOLBAR_PADDING: i32 = 2;
+
+/// This is the space between items.
+const ITEM_SPACING: i32 = 2;
+
+/// This is the length of a separator along the toolbar.
+const SEPARATOR_SIZE: u32 = 9;
+
+/// This is the length of the chevron button along the toolbar, which is shown when the items do
+/// not fit.
+const CHEVRON_SIZE: u32 = 16;
+
+/// This is the callback type that is used when an `on_tool_selected` callback is triggered from
+/// this `Widget`.  Returns the name of the tool that was selected.
+pub type OnToolSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut ToolbarWidget, &[WidgetContainer], &[LayoutContainer], String)>>;
+
+/// These are the possible toolbar orientations.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum ToolbarOrientation {
+    /// Indicates a toolbar that lays out its items from left to right.
+    ToolbarHorizontal,
+
+    /// Indicates a toolbar that lays out its items from top to bottom.
+    ToolbarVertical,
+}
+
+/// This is an item in a `ToolbarWidget`: either a tool, which is the ID of a child `Widget`, or a
+/// separator, which has no `Widget`.  The area of the item is relative to the `ToolbarWidget`, and
+/// is `None` when the item does not fit, and is shown in the overflow menu instead.  `tile` and
+/// `selected` mirror the state of the tool when it is a `TileWidget`.
+struct ToolbarItem {
+    name: String,
+    widget_id: Option<i32>,
+    group: i32,
+    area: Option<Rect>,
+    tile: bool,
+    selected: bool,
+}
+
+/// This is the storage object for the `ToolbarWidget`.  It stores the config, properties, callback
+/// registry, the items of the toolbar, and the state of the overflow menu.
+pub struct ToolbarWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    overlay_store: TextureStore,
+    orientation: ToolbarOrientation,
+    items: Vec<ToolbarItem>,
+    overflow: bool,
+    items_invalidated: bool,
+    pressed_item: Option<usize>,
+    chevron_hovered: bool,
+    popup: PopupMenu,
+    popup_items: Vec<MenuItem>,
+    popup_invalidated: bool,
+    mouse_point: Points,
+    on_tool_selected: OnToolSelectedCallbackType,
+}
+
+/// This is the implementation of the `ToolbarWidget`, a horizontal or vertical strip of tools.
+/// Tools are `Widget`s, such as `TileWidget`s and `ImageButtonWidget`s, that are added to the
+/// `Engine` as children of the `ToolbarWidget` using `add_widget_to_parent`, and then added to the
+/// toolbar by ID with `add_tool`.  They are laid out in the order they were added, at their own
+/// sizes, and separators can be added between them.  Since tools are regular `Widget`s, they
+/// receive their own events, focus, and tooltips.
+///
+/// `TileWidget`s toggle when they are clicked.  Tiles can be placed in a toggle group with
+/// `set_tool_group`, so that only one tile in the group is selected at a time, like a set of
+/// drawing tools.
+///
+/// When the items do not fit in the `ToolbarWidget`, the items that do not fit are hidden, and a
+/// chevron button is shown at the end of the toolbar, which opens a menu containing them.
+///
+/// Whenever a tool is clicked, or chosen from the overflow menu, the `on_tool_selected` callback is
+/// called with the name of the tool.
+impl ToolbarWidget {
+    /// Creates a new `ToolbarWidget`, given the `x, y, w, h` coordinates, and the `orientation` in
+    /// which its items are laid out.
+    pub fn new(points: Points, size: Size, orientation: ToolbarOrientation) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_color(CONFIG_COLOR_BASE, Color::RGB(240, 240, 240));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_TEXT, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            overlay_store: TextureStore::default(),
+            orientation,
+            items: vec![],
+            overflow: false,
+            items_invalidated: false,
+            pressed_item: None,
+            chevron_hovered: false,
+            popup: PopupMenu::new(),
+            popup_items: vec![],
+            popup_invalidated: false,
+            mouse_point: make_points(0, 0),
+            on_tool_selected: None,
+        }
+    }
+
+    /// Adds the tool `Widget` with the ID `widget_id` to the end of the toolbar, identified by
+    /// `name`.  The `Widget` must have been added to the `Engine` as a child of this `Widget`.  The
+    /// position of the tool is set by the toolbar.
+    pub fn add_tool(&mut self, name: String, widget_id: i32) {
+        self.add_item(name, Some(widget_id));
+    }
+
+    /// Adds a separator to the end of the toolbar.
+    pub fn add_separator(&mut self) {
+        self.add_item(String::new(), None);
+    }
+
+    /// Places the tile identified by `name` in the toggle group `group`.  Only one tile in a toggle
+    /// group is selected at a time, and clicking the selected tile keeps it selected.  A `group` of
+    /// `0` removes the tile from its group, so that it toggles on its own.
+    pub fn set_tool_group(&mut self, name: &str, group: i32) {
+        if let Some(index) = self.index_of(name) {
+            self.items[index].group = group;
+        }
+    }
+
+    /// Selects the tile identified by `name`, deselecting the other tiles in its toggle group.
+    /// This does not trigger the `on_tool_selected` callback.
+    pub fn select_tool(&mut self, name: &str) {
+        if let Some(index) = self.index_of(name) {
+            self.set_tile_selected(index, true);
+        }
+    }
+
+    /// Indicates whether or not the tile identified by `name` is selected.
+    pub fn is_tool_selected(&self, name: &str) -> bool {
+        self.index_of(name)
+            .is_some_and(|index| self.items[index].selected)
+    }
+
+    /// Returns the name of the selected tile in the toggle group `group`, or `None` if no tile in
+    /// the group is selected.
+    pub fn get_selected_tool(&self, group: i32) -> Option<String> {
+        self.items
+            .iter()
+            .find(|item| item.widget_id.is_some() && item.group == group && item.selected)
+            .map(|item| item.name.clone())
+    }
+
+    /// Returns the ID of the `Widget` of the tool identified by `name`, or `None` if there is no
+    /// such tool.
+    pub fn get_tool_id(&self, name: &str) -> Option<i32> {
+        self.index_of(name)
+            .and_then(|index| self.items[index].widget_id)
+    }
+
+    /// Indicates whether or not the overflow menu is open.
+    pub fn is_open(&self) -> bool {
+        self.popup.is_open()
+    }
+
+    /// Assigns the callback closure that will be used when a tool is selected.
+    pub fn on_tool_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut ToolbarWidget, &[WidgetContainer], &[LayoutContainer], String) + 'static,
+    {
+        self.on_tool_selected = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_tool_selected` callback.
+    fn call_tool_selected_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        name: String,
+    ) {
+        if let Some(mut cb) = self.on_tool_selected.take() {
+            cb(self, widgets, layouts, name);
+            self.on_tool_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that adds an item to the end of the toolbar.
+    fn add_item(&mut self, name: String, widget_id: Option<i32>) {
+        self.items.push(ToolbarItem {
+            name,
+            widget_id,
+            group: 0,
+            area: None,
+            tile: false,
+            selected: false,
+        });
+        self.items_invalidated = true;
+    }
+
+    /// Returns the index of the tool identified by `name`.
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.items
+            .iter()
+            .position(|item| item.widget_id.is_some() && item.name == name)
+    }
+
+    /// Sets the selected state of the tile at `index`.  Selecting a tile in a toggle group deselects
+    /// the other tiles in the group.  The tiles are updated on the next tick.
+    fn set_tile_selected(&mut self, index: usize, selected: bool) {
+        let group = self.items[index].group;
+
+        if selected && group != 0 {
+            for item in self.items.iter_mut() {
+                if item.group == group {
+                    item.selected = false;
+                }
+            }
+        }
+
+        self.items[index].selected = selected;
+        self.items_invalidated = true;
+    }
+
+    /// Internal function that is called when the tool at `index` is clicked, or chosen from the
+    /// overflow menu.  A tile toggles, and the selected tile in a toggle group stays selected.
+    fn tool_activated(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        index: usize,
+    ) {
+        if self.items[index].tile {
+            let selected = self.items[index].group != 0 || !self.items[index].selected;
+
+            self.set_tile_selected(index, selected);
+
+            // A tile that is clicked has already toggled itself, so its state is corrected now,
+            // rather than on the next tick, in case it was selected in a toggle group.
+            self.update_tools(widgets);
+        }
+
+        let name = self.items[index].name.clone();
+
+        self.call_tool_selected_callback(widgets, layouts, name);
+    }
+
+    /// Internal function that positions the tools along the toolbar, showing the tools that fit,
+    /// and hiding the tools that do not fit, in which case the chevron button is shown.  Tiles are
+    /// set to the selected state stored by the toolbar.
+    fn update_tools(&mut self, widgets: &[WidgetContainer]) {
+        let bounds = self.get_config().get_size(CONFIG_SIZE);
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let horizontal = self.orientation == ToolbarHorizontal;
+        let (main_length, cross_length) = if horizontal {
+            (bounds[SIZE_WIDTH] as i32, bounds[SIZE_HEIGHT])
+        } else {
+            (bounds[SIZE_HEIGHT] as i32, bounds[SIZE_WIDTH])
+        };
+        let lengths: Vec<(u32, u32)> = self
+            .items
+            .iter_mut()
+            .map(
+                |item| match item.widget_id.and_then(|id| widgets.get(id as usize)) {
+                    Some(container) => {
+                        let mut widget = container.widget.borrow_mut();
+                        let size = widget.get_config().get_size(CONFIG_SIZE);
+
+                        item.tile = widget.as_any().downcast_mut::<TileWidget>().is_some();
+
+                        if horizontal {
+                            (size[SIZE_WIDTH], size[SIZE_HEIGHT])
+                        } else {
+                            (size[SIZE_HEIGHT], size[SIZE_WIDTH])
+                        }
+                    }
+                    None => (
+                        SEPARATOR_SIZE,
+                        cross_length.saturating_sub(TOOLBAR_PADDING as u32 * 2),
+                    ),
+                },
+            )
+            .collect();
+        let total_length: i32 = lengths
+            .iter()
+            .map(|(length, _)| *length as i32 + ITEM_SPACING)
+            .sum();
+
+        self.overflow = TOOLBAR_PADDING * 2 + total_length - ITEM_SPACING > main_length;
+
+        let limit = if self.overflow {
+            main_length - CHEVRON_SIZE as i32 - TOOLBAR_PADDING
+        } else {
+            main_length - TOOLBAR_PADDING
+        };
+        let mut position = TOOLBAR_PADDING;
+        let mut overflowed = false;
+
+        for (item, (length, cross)) in self.items.iter_mut().zip(lengths) {
+            overflowed = overflowed || position + length as i32 > limit;
+
+            item.area = if overflowed {
+                None
+            } else if horizontal {
+                Some(Rect::new(
+                    position,
+                    TOOLBAR_PADDING,
+                    length.max(1),
+                    cross.max(1),
+                ))
+            } else {
+                Some(Rect::new(
+                    TOOLBAR_PADDING,
+                    position,
+                    cross.max(1),
+                    length.max(1),
+                ))
+            };
+
+            if !overflowed {
+                position += length as i32 + ITEM_SPACING;
+            }
+
+            let container = match item.widget_id.and_then(|id| widgets.get(id as usize)) {
+                Some(container) => container,
+                None => continue,
+            };
+            let mut widget = container.widget.borrow_mut();
+
+            match item.area {
+                Some(area) => {
+                    widget.set_origin(make_points(
+                        origin[POINT_X] + area.x(),
+                        origin[POINT_Y] + area.y(),
+                    ));
+                    widget.get_config().show();
+                }
+                None => widget.get_config().hide(),
+            }
+
+            match widget.as_any().downcast_mut::<TileWidget>() {
+                Some(tile) if tile.is_selected() != item.selected => {
+                    tile.set_selected(item.selected)
+                }
+                Some(_) => (),
+                None => item.selected = false,
+            }
+        }
+
+        self.items_invalidated = false;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the area of the chevron button, relative to the `Widget`, if it is shown.
+    fn get_chevron_area(&mut self) -> Option<Rect> {
+        let bounds = self.get_config().get_size(CONFIG_SIZE);
+
+        if !self.overflow {
+            None
+        } else if self.orientation == ToolbarHorizontal {
+            Some(Rect::new(
+                bounds[SIZE_WIDTH] as i32 - CHEVRON_SIZE as i32 - TOOLBAR_PADDING,
+                TOOLBAR_PADDING,
+                CHEVRON_SIZE,
+                bounds[SIZE_HEIGHT]
+                    .saturating_sub(TOOLBAR_PADDING as u32 * 2)
+                    .max(1),
+            ))
+        } else {
+            Some(Rect::new(
+                TOOLBAR_PADDING,
+                bounds[SIZE_HEIGHT] as i32 - CHEVRON_SIZE as i32 - TOOLBAR_PADDING,
+                bounds[SIZE_WIDTH]
+                    .saturating_sub(TOOLBAR_PADDING as u32 * 2)
+                    .max(1),
+                CHEVRON_SIZE,
+            ))
+        }
+    }
+
+    /// Internal function that opens the overflow menu below the chevron button, listing the items
+    /// that do not fit.  The size of the `Window` is taken from the top-level `Widget`.
+    fn open_menu(&mut self, widgets: &[WidgetContainer]) {
+        let chevron_area = match self.get_chevron_area() {
+            Some(chevron_area) => chevron_area,
+            None => return,
+        };
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let window_size = widgets[0]
+            .widget
+            .borrow_mut()
+            .get_config()
+            .get_size(CONFIG_SIZE);
+        let mut popup_items = Vec::new();
+
+        for index in 0..self.items.len() {
+            if self.items[index].area.is_some() {
+                continue;
+            }
+
+            if self.items[index].widget_id.is_none() {
+                // Separators are not shown at the start of the menu.
+                if !popup_items.is_empty() {
+                    popup_items.push(MenuItem::separator());
+                }
+
+                continue;
+            }
+
+            let item = MenuItem::new(index as i32, self.items[index].name.clone());
+
+            popup_items.push(if self.items[index].tile {
+                item.with_check(self.items[index].selected)
+            } else {
+                item
+            });
+        }
+
+        self.popup_items = popup_items;
+        self.popup.open(
+            &self.popup_items,
+            Rect::new(
+                origin[POINT_X] + chevron_area.x(),
+                origin[POINT_Y] + chevron_area.y(),
+                chevron_area.width(),
+                chevron_area.height(),
+            ),
+            Rect::new(0, 0, window_size[SIZE_WIDTH], window_size[SIZE_HEIGHT]),
+        );
+        self.popup_invalidated = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Internal function that closes the overflow menu.
+    fn close_menu(&mut self) {
+        if self.popup.is_open() {
+            self.popup.close();
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Internal function that acts on a response from the overflow menu.
+    fn handle_response(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        response: MenuResponse,
+    ) {
+        match response {
+            MenuResponse::Changed => self.popup_invalidated = true,
+            MenuResponse::Activated(id) => {
+                self.close_menu();
+                self.tool_activated(widgets, layouts, id as usize);
+            }
+            MenuResponse::Closed => self.close_menu(),
+            _ => (),
+        }
+    }
+}
+
+/// This is the `Widget` implementation of the `ToolbarWidget`.
+impl Widget for ToolbarWidget {
+    /// Draws the separators between the tools, and the chevron button when some items do not fit.
+    /// The tools are drawn as children of this `Widget`.
+    fn draw(&mut self, c: &mut Canvas<Window>, _t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let base_color = self.get_color(CONFIG_COLOR_BASE);
+            let hover_color = self.get_color(CONFIG_COLOR_HOVER);
+            let border_color = self.get_color(CONFIG_COLOR_BORDER);
+            let text_color = self.get_color(CONFIG_COLOR_TEXT);
+            let separator_color = self.get_color(CONFIG_COLOR_SECONDARY);
+            let chevron_area = self.get_chevron_area();
+            let chevron_highlighted = self.chevron_hovered || self.popup.is_open();
+            let horizontal = self.orientation == ToolbarHorizontal;
+            let separators: Vec<Rect> = self
+                .items
+                .iter()
+                .filter(|item| item.widget_id.is_none())
+                .filter_map(|item| item.area)
+                .collect();
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[SIZE_WIDTH], bounds[SIZE_HEIGHT]);
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                texture.set_draw_color(separator_color);
+
+                for area in &separators {
+                    let center = if horizontal {
+                        area.x() + area.width() as i32 / 2
+                    } else {
+                        area.y() + area.height() as i32 / 2
+                    };
+
+                    if horizontal {
+                        texture
+                            .draw_line(
+                                Point::new(center, area.y() + 2),
+                                Point::new(center, area.bottom() - 3),
+                            )
+                            .unwrap();
+                    } else {
+                        texture
+                            .draw_line(
+                                Point::new(area.x() + 2, center),
+                                Point::new(area.right() - 3, center),
+                            )
+                            .unwrap();
+                    }
+                }
+
+                // The chevron button is drawn as two arrows pointing along the toolbar.
+                if let Some(area) = chevron_area {
+                    if chevron_highlighted {
+                        texture.set_draw_color(hover_color);
+                        texture.fill_rect(area).unwrap();
+                    }
+
+                    let center = area.center();
+
+                    texture.set_draw_color(text_color);
+
+                    for offset in &[-3, 2] {
+                        for k in 0..3 {
+                            if horizontal {
+                                texture
+                                    .draw_line(
+                                        Point::new(center.x() + offset + k, center.y() - 3 + k),
+                                        Point::new(center.x() + offset + k, center.y() + 3 - k),
+                                    )
+                                    .unwrap();
+                            } else {
+                                texture
+                                    .draw_line(
+                                        Point::new(center.x() - 3 + k, center.y() + offset + k),
+                                        Point::new(center.x() + 3 - k, center.y() + offset + k),
+                                    )
+                                    .unwrap();
+                            }
+                        }
+                    }
+                }
+
+                texture.set_draw_color(border_color);
+
+                if horizontal {
+                    texture
+                        .draw_line(
+                            Point::new(0, bounds[SIZE_HEIGHT] as i32 - 1),
+                            Point::new(bounds[SIZE_WIDTH] as i32, bounds[SIZE_HEIGHT] as i32 - 1),
+                        )
+                        .unwrap();
+                } else {
+                    texture
+                        .draw_line(
+                            Point::new(bounds[SIZE_WIDTH] as i32 - 1, 0),
+                            Point::new(bounds[SIZE_WIDTH] as i32 - 1, bounds[SIZE_HEIGHT] as i32),
+                        )
+                        .unwrap();
+                }
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// Returns the area covered by the overflow menu, if it is open.
+    fn get_overlay_area(&mut self) -> Option<Rect> {
+        self.popup.get_area()
+    }
+
+    /// Draws the overflow menu.
+    fn draw_overlay(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        let area = self.popup.get_area()?;
+
+        if self.popup_invalidated {
+            self.overlay_store
+                .create_or_resize_texture(c, area.width(), area.height());
+            self.overlay_store
+                .get_mut_ref()
+                .set_blend_mode(BlendMode::Blend);
+
+            let popup = &self.popup;
+            let items = &self.popup_items;
+            let config = &self.config;
+
+            c.with_texture_canvas(self.overlay_store.get_mut_ref(), |texture| {
+                popup.draw(texture, t, items, config);
+            })
+            .unwrap();
+
+            self.popup_invalidated = false;
+        }
+
+        self.overlay_store.get_optional_ref()
+    }
+
+    /// Closes the overflow menu when the mouse is clicked outside of it.
+    fn dismiss_overlay(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.close_menu();
+    }
+
+    /// Clears the highlighted chevron button when the mouse leaves the `Widget`.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.chevron_hovered {
+            self.chevron_hovered = false;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Highlights the chevron button when the mouse is over it, and the item under the mouse in the
+    /// overflow menu.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = _points[POINT_X] - origin[POINT_X];
+        let y = _points[POINT_Y] - origin[POINT_Y];
+        let chevron_hovered = self
+            .get_chevron_area()
+            .is_some_and(|area| area.contains_point((x, y)));
+
+        self.mouse_point = _points.clone();
+
+        if chevron_hovered != self.chevron_hovered {
+            self.chevron_hovered = chevron_hovered;
+            self.get_config().set_invalidated(true);
+        }
+
+        if self.popup.is_open() {
+            let response =
+                self.popup
+                    .mouse_moved(&self.popup_items, _points[POINT_X], _points[POINT_Y]);
+
+            self.handle_response(_widgets, _layouts, response);
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Opens and closes the overflow menu, and selects items from it.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 && _state {
+            let mouse_point = self.mouse_point.clone();
+            let in_menu = self.popup.get_area().is_some_and(|area| {
+                area.contains_point((mouse_point[POINT_X], mouse_point[POINT_Y]))
+            });
+
+            if in_menu {
+                let response = self.popup.button_down(
+                    &mut self.popup_items,
+                    mouse_point[POINT_X],
+                    mouse_point[POINT_Y],
+                );
+
+                self.handle_response(_widgets, _layouts, response);
+            } else if self.chevron_hovered {
+                if self.popup.is_open() {
+                    self.close_menu();
+                } else {
+                    self.open_menu(_widgets);
+                }
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Activates a tool when the mouse button is pressed and released over it.
+    fn child_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _widget_id: i32,
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button != 1 {
+            return;
+        }
+
+        let index = self
+            .items
+            .iter()
+            .position(|item| item.widget_id == Some(_widget_id));
+
+        if _state {
+            self.pressed_item = index;
+        } else {
+            let pressed_item = self.pressed_item.take();
+
+            if let Some(index) = index.filter(|index| pressed_item == Some(*index)) {
+                self.tool_activated(_widgets, _layouts, index);
+            }
+        }
+    }
+
+    /// Positions the tools, and updates the selected state of the tiles, when the tools or the
+    /// size of the `Widget` have changed.
+    fn tick(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        if self.items_invalidated {
+            self.update_tools(_widgets);
+        }
+
+        self.tick_callback(_widgets, _layouts);
+    }
+
+    /// Lays out the items again when the size of the `Widget` changes.
+    fn on_config_changed(&mut self, _k: u8, _v: Config) {
+        if _k == CONFIG_SIZE {
+            self.close_menu();
+            self.items_invalidated = true;
+        }
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
@@ -0,0 +1,896 @@
+// Pushrod Widget Library
+// Tree View Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+use crate::widgets::list_widget::ListSelection;
+use crate::widgets::list_widget::ListSelectionMode::SingleSelection;
+use crate::widgets::scroll_bar::ScrollBarOrientation::ScrollBarVertical;
+use crate::widgets::scroll_bar::{ScrollBar, SCROLL_BAR_THICKNESS};
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::rect::{Point, Rect};
+use std::any::Any;
+use std::collections::HashMap;
+
+/// This is the width of the area to the left of a node's text in which its expand/collapse arrow
+/// is drawn, in pixels.
+const ARROW_WIDTH: u32 = 16;
+
+/// This is the callback type that is used when an `on_selected` callback is triggered from this
+/// `Widget`.  It receives the ID of the selected node, or `-1` if the selection was cleared.
+pub type OnTreeSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut TreeViewWidget, &[WidgetContainer], &[LayoutContainer], i32)>>;
+
+/// This is the callback type that is used when an `on_expanded` callback is triggered from this
+/// `Widget`.  It receives the ID of the node, and `true` if it was expanded, `false` if it was
+/// collapsed.
+pub type OnExpandedCallbackType = Option<
+    Box<dyn FnMut(&mut TreeViewWidget, &[WidgetContainer], &[LayoutContainer], usize, bool)>,
+>;
+
+/// This is the callback type that is used to load the children of a node the first time that it
+/// is expanded.  It receives the ID of the node being expanded.
+pub type OnLoadChildrenCallbackType = Option<Box<dyn FnMut(&mut TreeViewWidget, usize)>>;
+
+/// This is the storage object for a single node in a `TreeViewWidget`.
+#[derive(Clone, Debug)]
+struct TreeNode {
+    label: String,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    expanded: bool,
+    lazy: bool,
+}
+
+/// This is the storage object for the `TreeViewWidget`.  It stores the config, properties, callback
+/// registry, the nodes of the tree, the visible rows, the selection, and the vertical `ScrollBar`.
+pub struct TreeViewWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    nodes: Vec<TreeNode>,
+    roots: Vec<usize>,
+    visible_rows: Vec<(usize, u32)>,
+    rows_changed: bool,
+    selected_node: Option<usize>,
+    font_name: String,
+    font_size: i32,
+    row_height: u32,
+    indent: u32,
+    highlighted_row: i32,
+    selection: ListSelection,
+    scroll_bar: ScrollBar,
+    mouse_point: Points,
+    in_bounds: bool,
+    on_selected: OnTreeSelectedCallbackType,
+    on_expanded: OnExpandedCallbackType,
+    on_load_children: OnLoadChildrenCallbackType,
+}
+
+/// This is the implementation of the `TreeViewWidget`, a control that displays a hierarchy of
+/// nodes.  Each node is identified by the ID returned when it was added.  Nodes with children show
+/// an arrow, which expands or collapses the node when clicked; double-clicking a node does the
+/// same.  A node can be marked as having children that have not been loaded yet, in which case the
+/// `on_load_children` callback is called to add them the first time the node is expanded.
+///
+/// When it has focus, the tree is navigated with the `Up`, `Down`, `Home`, `End`, `PageUp` and
+/// `PageDown` keys.  `Right` expands the selected node, or moves to its first child, `Left`
+/// collapses it, or moves to its parent, and `Return` toggles it.
+impl TreeViewWidget {
+    /// Creates a new `TreeViewWidget` given the `x, y, w, h` coordinates.  Rows are 24 pixels high,
+    /// each level is indented by 16 pixels, and nodes are drawn using
+    /// `assets/OpenSans-Regular.ttf` at size 14.
+    pub fn new(points: Points, size: Size) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+        config.set_toggle(CONFIG_SCROLLABLE, true);
+        config.set_toggle(CONFIG_MOUSE_CAPTURE, true);
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            nodes: vec![],
+            roots: vec![],
+            visible_rows: vec![],
+            rows_changed: false,
+            selected_node: None,
+            font_name: String::from("assets/OpenSans-Regular.ttf"),
+            font_size: 14,
+            row_height: 24,
+            indent: 16,
+            highlighted_row: -1,
+            selection: ListSelection::new(SingleSelection),
+            scroll_bar: ScrollBar::new(ScrollBarVertical),
+            mouse_point: make_points(0, 0),
+            in_bounds: false,
+            on_selected: None,
+            on_expanded: None,
+            on_load_children: None,
+        }
+    }
+
+    /// Adds a node with the text `label` to the end of the children of `parent`, or to the end of
+    /// the top level of the tree if `parent` is `None`.  Returns the ID of the new node.
+    pub fn add_node(&mut self, parent: Option<usize>, label: String) -> usize {
+        let id = self.nodes.len();
+
+        self.nodes.push(TreeNode {
+            label,
+            parent,
+            children: vec![],
+            expanded: false,
+            lazy: false,
+        });
+
+        match parent {
+            Some(parent) => self.nodes[parent].children.push(id),
+            None => self.roots.push(id),
+        }
+
+        self.rows_changed = true;
+        self.get_config().set_invalidated(true);
+
+        id
+    }
+
+    /// Marks `node` as having children that have not been loaded yet.  The node shows an expand
+    /// arrow, and the `on_load_children` callback is called to add its children the first time that
+    /// it is expanded.
+    pub fn set_has_children(&mut self, node: usize, has_children: bool) {
+        if let Some(tree_node) = self.nodes.get_mut(node) {
+            tree_node.lazy = has_children;
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Indicates whether or not `node` has children, or has children that have not been loaded yet.
+    pub fn has_children(&self, node: usize) -> bool {
+        self.nodes
+            .get(node)
+            .is_some_and(|x| x.lazy || !x.children.is_empty())
+    }
+
+    /// Removes all of the nodes from the tree, clearing the selection.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.roots.clear();
+        self.selected_node = None;
+        self.selection.clear();
+        self.highlighted_row = -1;
+        self.scroll_bar.set_position(0);
+        self.rows_changed = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the text of `node`, or `None` if there is no such node.
+    pub fn get_label(&self, node: usize) -> Option<&String> {
+        self.nodes.get(node).map(|x| &x.label)
+    }
+
+    /// Changes the text of `node`.
+    pub fn set_label(&mut self, node: usize, label: String) {
+        if let Some(tree_node) = self.nodes.get_mut(node) {
+            tree_node.label = label;
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Returns the parent of `node`, or `None` if it is at the top level of the tree.
+    pub fn get_parent(&self, node: usize) -> Option<usize> {
+        self.nodes.get(node).and_then(|x| x.parent)
+    }
+
+    /// Returns the IDs of the children of `node` that have been added so far.
+    pub fn get_children(&self, node: usize) -> &[usize] {
+        self.nodes.get(node).map_or(&[], |x| &x.children)
+    }
+
+    /// Returns the IDs of the nodes at the top level of the tree.
+    pub fn get_roots(&self) -> &[usize] {
+        &self.roots
+    }
+
+    /// Indicates whether or not `node` is expanded.
+    pub fn is_expanded(&self, node: usize) -> bool {
+        self.nodes.get(node).is_some_and(|x| x.expanded)
+    }
+
+    /// Expands `node`, showing its children.  If its children have not been loaded yet, the
+    /// `on_load_children` callback is called first.  This does not trigger the `on_expanded`
+    /// callback.
+    pub fn expand(&mut self, node: usize) {
+        if node >= self.nodes.len() || self.nodes[node].expanded {
+            return;
+        }
+
+        if self.nodes[node].lazy {
+            self.nodes[node].lazy = false;
+
+            if let Some(mut cb) = self.on_load_children.take() {
+                cb(self, node);
+                self.on_load_children = Some(cb);
+            }
+        }
+
+        self.nodes[node].expanded = true;
+        self.rows_changed = true;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Collapses `node`, hiding its children.  If the selected node is hidden as a result, `node`
+    /// becomes selected.  This does not trigger the `on_expanded` or `on_selected` callbacks.
+    pub fn collapse(&mut self, node: usize) {
+        if node >= self.nodes.len() || !self.nodes[node].expanded {
+            return;
+        }
+
+        self.nodes[node].expanded = false;
+
+        if let Some(selected_node) = self.selected_node {
+            if self.is_descendant(selected_node, node) {
+                self.selected_node = Some(node);
+            }
+        }
+
+        self.rows_changed = true;
+        self.highlighted_row = -1;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the ID of the selected node, or `None` if no node is selected.
+    pub fn get_selected(&self) -> Option<usize> {
+        self.selected_node
+    }
+
+    /// Selects `node`, expanding its ancestors and scrolling it into view, or clears the selection
+    /// if `node` is `None`.  This does not trigger the `on_selected` callback.
+    pub fn set_selected(&mut self, node: Option<usize>) {
+        match node {
+            Some(node) if node < self.nodes.len() => {
+                let mut ancestor = self.nodes[node].parent;
+
+                while let Some(parent) = ancestor {
+                    self.expand(parent);
+                    ancestor = self.nodes[parent].parent;
+                }
+
+                self.selected_node = Some(node);
+                self.rows_changed = true;
+                self.scroll_to_node(node);
+            }
+            _ => {
+                self.selected_node = None;
+                self.selection.clear();
+            }
+        }
+
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the height of each row in the tree, in pixels.
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.row_height = row_height.max(1);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the number of pixels that each level of the tree is indented by.
+    pub fn set_indent(&mut self, indent: u32) {
+        self.indent = indent;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the font used to draw the nodes, by the `font_name` of the TTF file, and the
+    /// `font_size` to draw it at.
+    pub fn set_font(&mut self, font_name: String, font_size: i32) {
+        self.font_name = font_name;
+        self.font_size = font_size;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Scrolls the tree so that `node` is fully visible, if it is shown.
+    pub fn scroll_to_node(&mut self, node: usize) {
+        self.update_scroll_bar();
+
+        if let Some(row) = self.visible_rows.iter().position(|x| x.0 == node) {
+            let row_top = row as i32 * self.row_height as i32;
+            let row_bottom = row_top + self.row_height as i32;
+            let viewport_height = self.scroll_bar.get_viewport_length() as i32;
+            let position = self.scroll_bar.get_position() as i32;
+
+            let changed = if row_top < position {
+                self.scroll_bar.set_position(row_top)
+            } else if row_bottom > position + viewport_height {
+                self.scroll_bar.set_position(row_bottom - viewport_height)
+            } else {
+                false
+            };
+
+            if changed {
+                self.get_config().set_invalidated(true);
+            }
+        }
+    }
+
+    /// Assigns the callback closure that will be used when a node is selected by the mouse or
+    /// keyboard.
+    pub fn on_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TreeViewWidget, &[WidgetContainer], &[LayoutContainer], i32) + 'static,
+    {
+        self.on_selected = Some(Box::new(callback));
+    }
+
+    /// Assigns the callback closure that will be used when a node is expanded or collapsed by the
+    /// mouse or keyboard.
+    pub fn on_expanded<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TreeViewWidget, &[WidgetContainer], &[LayoutContainer], usize, bool)
+            + 'static,
+    {
+        self.on_expanded = Some(Box::new(callback));
+    }
+
+    /// Assigns the callback closure that will be used to load the children of a node that was
+    /// marked with `set_has_children`, the first time that it is expanded.  The closure should add
+    /// the children with `add_node`.
+    pub fn on_load_children<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut TreeViewWidget, usize) + 'static,
+    {
+        self.on_load_children = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_selected` callback.
+    fn call_selected_callback(&mut self, widgets: &[WidgetContainer], layouts: &[LayoutContainer]) {
+        let selected_node = self.selected_node.map_or(-1, |x| x as i32);
+
+        if let Some(mut cb) = self.on_selected.take() {
+            cb(self, widgets, layouts, selected_node);
+            self.on_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that triggers the `on_expanded` callback.
+    fn call_expanded_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        node: usize,
+        expanded: bool,
+    ) {
+        if let Some(mut cb) = self.on_expanded.take() {
+            cb(self, widgets, layouts, node, expanded);
+            self.on_expanded = Some(cb);
+        }
+    }
+
+    /// Internal function that expands or collapses `node` in response to the mouse or keyboard,
+    /// triggering the `on_expanded` callback, and the `on_selected` callback if the selected node
+    /// was hidden.
+    fn toggle_node(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        node: usize,
+    ) {
+        if !self.has_children(node) {
+            return;
+        }
+
+        let selected_node = self.selected_node;
+        let expanded = !self.nodes[node].expanded;
+
+        if expanded {
+            self.expand(node);
+        } else {
+            self.collapse(node);
+        }
+
+        self.call_expanded_callback(widgets, layouts, node, expanded);
+
+        if self.selected_node != selected_node {
+            self.call_selected_callback(widgets, layouts);
+        }
+    }
+
+    /// Internal function that selects `node` in response to the mouse or keyboard, triggering the
+    /// `on_selected` callback.
+    fn select_node(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        node: usize,
+    ) {
+        self.selected_node = Some(node);
+        self.rows_changed = true;
+        self.scroll_to_node(node);
+        self.get_config().set_invalidated(true);
+        self.call_selected_callback(widgets, layouts);
+    }
+
+    /// Internal function that indicates whether or not `node` is below `ancestor` in the tree.
+    fn is_descendant(&self, node: usize, ancestor: usize) -> bool {
+        let mut parent = self.nodes[node].parent;
+
+        while let Some(id) = parent {
+            if id == ancestor {
+                return true;
+            }
+
+            parent = self.nodes[id].parent;
+        }
+
+        false
+    }
+
+    /// Internal function that rebuilds the list of visible rows, with the depth of each node, after
+    /// nodes have been added, expanded or collapsed.  The selection is moved to the row of the
+    /// selected node.
+    fn update_rows(&mut self) {
+        if !self.rows_changed {
+            return;
+        }
+
+        let mut rows = Vec::new();
+        let mut stack: Vec<(usize, u32)> = self.roots.iter().rev().map(|x| (*x, 0)).collect();
+
+        while let Some((node, depth)) = stack.pop() {
+            rows.push((node, depth));
+
+            if self.nodes[node].expanded {
+                stack.extend(
+                    self.nodes[node]
+                        .children
+                        .iter()
+                        .rev()
+                        .map(|x| (*x, depth + 1)),
+                );
+            }
+        }
+
+        self.visible_rows = rows;
+        self.rows_changed = false;
+
+        match self
+            .selected_node
+            .and_then(|node| self.visible_rows.iter().position(|x| x.0 == node))
+        {
+            Some(row) => self
+                .selection
+                .set_selected(vec![row], self.visible_rows.len()),
+            None => self.selection.clear(),
+        }
+    }
+
+    /// Internal function that updates the visible rows and the `ScrollBar` to match the current
+    /// size of the `Widget`.  Returns the track area of the scroll bar relative to the `Widget`, if
+    /// it is shown.
+    fn update_scroll_bar(&mut self) -> Option<Rect> {
+        self.update_rows();
+
+        let bounds = self.get_config().get_size(CONFIG_SIZE);
+        let viewport_height = bounds[SIZE_HEIGHT].saturating_sub(2);
+
+        self.scroll_bar.set_lengths(
+            self.visible_rows.len() as u32 * self.row_height,
+            viewport_height,
+        );
+
+        if self.scroll_bar.is_needed() {
+            Some(Rect::new(
+                bounds[SIZE_WIDTH].saturating_sub(SCROLL_BAR_THICKNESS + 1) as i32,
+                1,
+                SCROLL_BAR_THICKNESS,
+                viewport_height.max(1),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Internal function that returns the local X coordinate of the expand/collapse arrow of a node
+    /// at `depth`.
+    fn get_arrow_x(&self, depth: u32) -> i32 {
+        5 + (depth * self.indent) as i32
+    }
+
+    /// Internal function that returns the number of rows that fit in the visible area of the tree.
+    fn get_page_size(&self) -> usize {
+        (self.scroll_bar.get_viewport_length() / self.row_height).max(1) as usize
+    }
+
+    /// Internal function that moves the selection, or expands and collapses the selected node, in
+    /// response to a key press.
+    fn navigate(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        keycode: Keycode,
+        keymod: Mod,
+    ) {
+        self.update_scroll_bar();
+
+        if let Some(node) = self.selected_node {
+            match keycode {
+                Keycode::Right => {
+                    if !self.has_children(node) {
+                        return;
+                    }
+
+                    if self.nodes[node].expanded {
+                        if let Some(child) = self.nodes[node].children.first().cloned() {
+                            self.select_node(widgets, layouts, child);
+                        }
+                    } else {
+                        self.toggle_node(widgets, layouts, node);
+                    }
+
+                    return;
+                }
+                Keycode::Left => {
+                    if self.nodes[node].expanded {
+                        self.toggle_node(widgets, layouts, node);
+                    } else if let Some(parent) = self.nodes[node].parent {
+                        self.select_node(widgets, layouts, parent);
+                    }
+
+                    return;
+                }
+                Keycode::Return | Keycode::KpEnter => {
+                    self.toggle_node(widgets, layouts, node);
+                    return;
+                }
+                _ => (),
+            }
+        }
+
+        let page_size = self.get_page_size();
+
+        if let Some((row, changed)) =
+            self.selection
+                .key_pressed(keycode, keymod, self.visible_rows.len(), page_size)
+        {
+            let node = self.visible_rows[row].0;
+
+            if changed && self.selected_node != Some(node) {
+                self.select_node(widgets, layouts, node);
+            } else {
+                self.get_config().set_invalidated(true);
+            }
+        }
+    }
+}
+
+/// Draws an expand/collapse arrow centered on `center_x, center_y`, pointing down if `expanded`,
+/// and to the right otherwise.
+fn draw_arrow(c: &mut Canvas<Window>, center_x: i32, center_y: i32, expanded: bool) {
+    for k in 0..5 {
+        let (start, end) = if expanded {
+            (
+                Point::new(center_x - 4 + k, center_y - 2 + k),
+                Point::new(center_x + 4 - k, center_y - 2 + k),
+            )
+        } else {
+            (
+                Point::new(center_x - 2 + k, center_y - 4 + k),
+                Point::new(center_x - 2 + k, center_y + 4 - k),
+            )
+        };
+
+        c.draw_line(start, end).unwrap();
+    }
+}
+
+/// This is the `Widget` implementation of the `TreeViewWidget`.
+impl Widget for TreeViewWidget {
+    /// Draws the visible rows of the `TreeViewWidget`.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let track = self.update_scroll_bar();
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            let base_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let hover_color = self.get_config().get_color(CONFIG_COLOR_HOVER);
+            let selected_color = self.get_config().get_color(CONFIG_COLOR_SELECTED);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let thumb_color = self.get_config().get_color(CONFIG_COLOR_SECONDARY);
+            let focused = self.get_config().is_focused();
+            let row_height = self.row_height;
+            let row_width = match track {
+                Some(track) => track.x() as u32 - 1,
+                None => bounds[SIZE_WIDTH].saturating_sub(2),
+            }
+            .max(1);
+            let position = self.scroll_bar.get_position();
+            let first_row = (position / row_height) as usize;
+            let last_row =
+                (((position + self.scroll_bar.get_viewport_length()) / row_height) as usize + 1)
+                    .min(self.visible_rows.len());
+            let mut rows = Vec::new();
+
+            for i in first_row..last_row {
+                let (node, depth) = self.visible_rows[i];
+                let selected = self.selection.is_selected(i);
+                let color = if selected {
+                    selected_color
+                } else if self.highlighted_row == i as i32 {
+                    hover_color
+                } else {
+                    base_color
+                };
+                let text_color = if selected {
+                    Color::RGB(255, 255, 255)
+                } else {
+                    Color::RGB(0, 0, 0)
+                };
+                let arrow_x = self.get_arrow_x(depth);
+                let text_x = arrow_x + ARROW_WIDTH as i32 + 2;
+                let arrow = if self.has_children(node) {
+                    Some(self.nodes[node].expanded)
+                } else {
+                    None
+                };
+                // An empty label cannot be rendered, so only its row and arrow are drawn.
+                let label = if self.nodes[node].label.is_empty() {
+                    None
+                } else {
+                    Some(t.render_text(
+                        c,
+                        self.font_name.clone(),
+                        self.font_size as u16,
+                        sdl2::ttf::FontStyle::NORMAL,
+                        self.nodes[node].label.clone(),
+                        text_color,
+                        (row_width as i32 - text_x).max(1) as u32,
+                    ))
+                };
+                let row_y = 1 + (i as u32 * row_height) as i32 - position as i32;
+
+                rows.push((i, row_y, color, text_color, arrow_x, arrow, text_x, label));
+            }
+
+            let cursor_row = self.selection.get_cursor();
+            let scroll_bar = &self.scroll_bar;
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                // Rows are clipped so that partially visible rows do not draw over the border.
+                texture.set_clip_rect(Rect::new(
+                    1,
+                    1,
+                    row_width,
+                    bounds[SIZE_HEIGHT].saturating_sub(2).max(1),
+                ));
+
+                for (i, row_y, color, text_color, arrow_x, arrow, text_x, label) in &rows {
+                    let row_area = Rect::new(1, *row_y, row_width, row_height);
+
+                    texture.set_draw_color(*color);
+                    texture.fill_rect(row_area).unwrap();
+
+                    if let Some(expanded) = arrow {
+                        texture.set_draw_color(*text_color);
+                        draw_arrow(
+                            texture,
+                            *arrow_x + ARROW_WIDTH as i32 / 2,
+                            *row_y + row_height as i32 / 2,
+                            *expanded,
+                        );
+                    }
+
+                    if let Some((font_texture, width, height)) = label {
+                        texture
+                            .copy(
+                                font_texture,
+                                None,
+                                Rect::new(
+                                    *text_x,
+                                    *row_y + (row_height as i32 - *height as i32) / 2,
+                                    *width,
+                                    *height,
+                                ),
+                            )
+                            .unwrap();
+                    }
+
+                    if focused && *i as i32 == cursor_row {
+                        texture.set_draw_color(hover_color);
+                        texture.draw_rect(row_area).unwrap();
+                    }
+                }
+
+                texture.set_clip_rect(None);
+
+                if let Some(track) = track {
+                    scroll_bar.draw(texture, track, base_color, thumb_color);
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, bounds[0], bounds[1]))
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// When a mouse enters the bounds of the `Widget`, this function is triggered.
+    fn mouse_entered(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = true;
+        self.mouse_entered_callback(_widgets, _layouts);
+    }
+
+    /// When a mouse exits the bounds of the `Widget`, this function is triggered.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = false;
+        self.highlighted_row = -1;
+        self.get_config().set_invalidated(true);
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// When a mouse is moved in the bounds of this `Widget`, this function is triggered.  Highlights
+    /// the row under the mouse, or drags the scroll bar thumb.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = points[POINT_X] - origin[POINT_X];
+        let y = points[POINT_Y] - origin[POINT_Y];
+        let track = self.update_scroll_bar();
+
+        self.mouse_point = make_points(x, y);
+
+        if let Some(track) = track {
+            if self.scroll_bar.mouse_moved(track, x, y) {
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        if self.in_bounds {
+            let previous_highlighted_row = self.highlighted_row;
+            let in_track = track.is_some_and(|track| x >= track.x());
+
+            self.highlighted_row =
+                (y - 1 + self.scroll_bar.get_position() as i32) / self.row_height as i32;
+
+            if in_track || y < 1 || self.highlighted_row >= self.visible_rows.len() as i32 {
+                self.highlighted_row = -1;
+            }
+
+            if self.highlighted_row != previous_highlighted_row {
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, points);
+    }
+
+    /// Scrolls the tree by one row for each step of the mouse wheel.
+    fn mouse_scrolled(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        points: Points,
+    ) {
+        self.update_scroll_bar();
+
+        if self
+            .scroll_bar
+            .scroll_by(-points[POINT_Y] * self.row_height as i32)
+        {
+            self.highlighted_row = -1;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_scrolled_callback(_widgets, _layouts, points);
+    }
+
+    /// Overrides the `button_clicked` callback to handle selection, expanding and collapsing nodes,
+    /// and the scroll bar.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 && !_state {
+            self.scroll_bar.button_up();
+        } else if _button == 1 && self.in_bounds {
+            let track = self.update_scroll_bar();
+            let x = self.mouse_point[POINT_X];
+            let y = self.mouse_point[POINT_Y];
+
+            if track.is_some_and(|track| self.scroll_bar.button_down(track, x, y)) {
+                self.get_config().set_invalidated(true);
+            } else if self.highlighted_row >= 0 {
+                let (node, depth) = self.visible_rows[self.highlighted_row as usize];
+                let arrow_x = self.get_arrow_x(depth);
+
+                if x >= arrow_x && x < arrow_x + ARROW_WIDTH as i32 {
+                    self.toggle_node(_widgets, _layouts, node);
+                } else {
+                    if self.selected_node != Some(node) {
+                        self.select_node(_widgets, _layouts, node);
+                    }
+
+                    if _clicks == 2 {
+                        self.toggle_node(_widgets, _layouts, node);
+                    }
+                }
+
+                self.highlighted_row = -1;
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles keyboard navigation of the tree.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        self.navigate(_widgets, _layouts, _keycode, _keymod);
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Redraws the cursor when focus changes.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        self.get_config().set_invalidated(true);
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
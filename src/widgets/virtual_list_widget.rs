@@ -0,0 +1,648 @@
+// Pushrod Widget Library
+// Virtual List Widget
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::render::callbacks::CallbackRegistry;
+use crate::render::widget::*;
+use crate::render::widget_cache::WidgetContainer;
+use crate::render::widget_config::*;
+
+use sdl2::render::{Canvas, Texture};
+use sdl2::video::Window;
+
+use crate::render::layout_cache::LayoutContainer;
+use crate::render::modifiers::mod_state;
+use crate::render::texture_cache::TextureCache;
+use crate::render::texture_store::TextureStore;
+use crate::render::{make_points, Points, Size, POINT_X, POINT_Y, SIZE_HEIGHT, SIZE_WIDTH};
+use crate::widgets::list_widget::ListSelectionMode::SingleSelection;
+use crate::widgets::list_widget::{ListSelection, ListSelectionMode};
+use crate::widgets::scroll_bar::ScrollBarOrientation::ScrollBarVertical;
+use crate::widgets::scroll_bar::{ScrollBar, SCROLL_BAR_THICKNESS};
+use sdl2::keyboard::{Keycode, Mod};
+use sdl2::pixels::Color;
+use sdl2::rect::Rect;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// This is the callback type that is used when an `on_selected` callback is triggered from this
+/// `Widget`.
+pub type OnVirtualListSelectedCallbackType =
+    Option<Box<dyn FnMut(&mut VirtualListWidget, &[WidgetContainer], &[LayoutContainer], i32)>>;
+
+/// This is the data model for a `VirtualListWidget`.  The `VirtualListWidget` only asks the model
+/// for the rows that are visible, so the model can hold any number of rows, or generate them on
+/// demand.
+pub trait ListModel {
+    /// Returns the number of rows in the model.
+    fn row_count(&self) -> usize;
+
+    /// Returns the data for the row at `row`, as one `String` per column.
+    fn row_data(&self, row: usize) -> Vec<String>;
+}
+
+/// A `Vec` of rows can be used directly as a `ListModel`.
+impl ListModel for Vec<Vec<String>> {
+    fn row_count(&self) -> usize {
+        self.len()
+    }
+
+    fn row_data(&self, row: usize) -> Vec<String> {
+        self[row].clone()
+    }
+}
+
+/// A shared `ListModel` can be used as a `ListModel`, so that the application can keep a handle
+/// to the model, and change it after the `VirtualListWidget` has been added to the `Engine`.
+impl<M: ListModel> ListModel for Rc<RefCell<M>> {
+    fn row_count(&self) -> usize {
+        self.borrow().row_count()
+    }
+
+    fn row_data(&self, row: usize) -> Vec<String> {
+        self.borrow().row_data(row)
+    }
+}
+
+/// This is the state of a row that is passed to a `ListItemRenderer` when drawing it.
+#[derive(Clone, Debug)]
+pub struct ListItemState {
+    /// The index of the row in the model.
+    pub row: usize,
+
+    /// Indicates whether or not the row is selected.
+    pub selected: bool,
+
+    /// Indicates whether or not the mouse is over the row.
+    pub highlighted: bool,
+
+    /// Indicates whether or not the row is at the keyboard cursor.
+    pub cursor: bool,
+}
+
+/// This is a renderer that draws a single row of a `VirtualListWidget`.  The background of the row
+/// has already been filled in with the base, hover or selected color when it is called, so the
+/// renderer only needs to draw the contents of the row inside of `area`.  Closures with the same
+/// arguments as `draw_item` can be used as a `ListItemRenderer`.
+pub trait ListItemRenderer {
+    /// Draws the row `data` into `area` of the `Canvas`, given its `state`.
+    fn draw_item(
+        &mut self,
+        c: &mut Canvas<Window>,
+        t: &mut TextureCache,
+        data: &[String],
+        area: Rect,
+        state: &ListItemState,
+    );
+}
+
+impl<F> ListItemRenderer for F
+where
+    F: FnMut(&mut Canvas<Window>, &mut TextureCache, &[String], Rect, &ListItemState),
+{
+    fn draw_item(
+        &mut self,
+        c: &mut Canvas<Window>,
+        t: &mut TextureCache,
+        data: &[String],
+        area: Rect,
+        state: &ListItemState,
+    ) {
+        self(c, t, data, area, state)
+    }
+}
+
+/// This is the default `ListItemRenderer`, which draws each column of a row as text.  Columns are
+/// given the widths set by `set_column_widths`, and the remaining width is shared between any
+/// columns that do not have a width.  Text is drawn in black, or white if the row is selected.
+pub struct TextItemRenderer {
+    font_name: String,
+    font_size: i32,
+    column_widths: Vec<u32>,
+}
+
+/// This is the implementation of the `TextItemRenderer`.
+impl TextItemRenderer {
+    /// Creates a new `TextItemRenderer` that draws text using the TTF file `font_name`, at
+    /// `font_size`.
+    pub fn new(font_name: String, font_size: i32) -> Self {
+        Self {
+            font_name,
+            font_size,
+            column_widths: vec![],
+        }
+    }
+
+    /// Sets the widths of the columns, in pixels, starting with the first column.
+    pub fn set_column_widths(&mut self, column_widths: Vec<u32>) {
+        self.column_widths = column_widths;
+    }
+}
+
+impl ListItemRenderer for TextItemRenderer {
+    fn draw_item(
+        &mut self,
+        c: &mut Canvas<Window>,
+        t: &mut TextureCache,
+        data: &[String],
+        area: Rect,
+        state: &ListItemState,
+    ) {
+        let text_color = if state.selected {
+            Color::RGB(255, 255, 255)
+        } else {
+            Color::RGB(0, 0, 0)
+        };
+        let fixed_width: u32 = self.column_widths.iter().take(data.len()).sum();
+        let flexible_columns = data.len().saturating_sub(self.column_widths.len()) as u32;
+        let flexible_width = area
+            .width()
+            .saturating_sub(fixed_width)
+            .checked_div(flexible_columns)
+            .unwrap_or(0);
+        let mut column_x = area.x();
+
+        for (i, column) in data.iter().enumerate() {
+            let column_width = *self.column_widths.get(i).unwrap_or(&flexible_width);
+
+            if !column.is_empty() && column_width > 10 {
+                let (font_texture, width, height) = t.render_text(
+                    c,
+                    self.font_name.clone(),
+                    self.font_size as u16,
+                    sdl2::ttf::FontStyle::NORMAL,
+                    column.clone(),
+                    text_color,
+                    column_width - 10,
+                );
+
+                let width = width.min(column_width - 10);
+                let height = height.min(area.height());
+
+                c.copy(
+                    &font_texture,
+                    Rect::new(0, 0, width, height),
+                    Rect::new(
+                        column_x + 10,
+                        area.y() + (area.height() as i32 - height as i32) / 2,
+                        width,
+                        height,
+                    ),
+                )
+                .unwrap();
+            }
+
+            column_x += column_width as i32;
+        }
+    }
+}
+
+/// This is the storage object for the `VirtualListWidget`.  It stores the config, properties,
+/// callback registry, the `ListModel`, the `ListItemRenderer`, the selection, and the vertical
+/// `ScrollBar`.
+pub struct VirtualListWidget {
+    config: WidgetConfig,
+    system_properties: HashMap<i32, String>,
+    callback_registry: CallbackRegistry,
+    texture_store: TextureStore,
+    model: Box<dyn ListModel>,
+    renderer: Box<dyn ListItemRenderer>,
+    row_count: usize,
+    row_height: u32,
+    highlighted_item: i32,
+    selection: ListSelection,
+    scroll_bar: ScrollBar,
+    mouse_point: Points,
+    in_bounds: bool,
+    on_selected: OnVirtualListSelectedCallbackType,
+}
+
+/// This is the implementation of the `VirtualListWidget`, a list that displays rows from a
+/// `ListModel`, drawing only the rows that are visible with a `ListItemRenderer`.  It scrolls,
+/// highlights, selects and navigates rows the same way as the `ListWidget`.  The number of rows
+/// in the model is checked on every tick, so rows can be added to a shared model while the list is
+/// displayed.  If the contents of existing rows change, call `refresh` to redraw them.
+impl VirtualListWidget {
+    /// Creates a new `VirtualListWidget` given the `x, y, w, h` coordinates, and the `model` that
+    /// contains the rows to display.  Rows are 30 pixels high, and drawn with a `TextItemRenderer`
+    /// using `assets/OpenSans-Regular.ttf` at size 16, in `SingleSelection` mode.
+    pub fn new(points: Points, size: Size, model: Box<dyn ListModel>) -> Self {
+        let mut config = WidgetConfig::new(points, size);
+        let row_count = model.row_count();
+
+        config.set_toggle(CONFIG_FOCUSABLE, true);
+        config.set_toggle(CONFIG_SCROLLABLE, true);
+        config.set_toggle(CONFIG_MOUSE_CAPTURE, true);
+        config.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_HOVER, Color::RGB(0x90, 0x90, 0xFF));
+        config.set_color(CONFIG_COLOR_SELECTED, Color::RGB(0, 0, 0));
+        config.set_color(CONFIG_COLOR_SECONDARY, Color::RGB(160, 160, 160));
+
+        Self {
+            config,
+            system_properties: HashMap::new(),
+            callback_registry: CallbackRegistry::new(),
+            texture_store: TextureStore::default(),
+            model,
+            renderer: Box::new(TextItemRenderer::new(
+                String::from("assets/OpenSans-Regular.ttf"),
+                16,
+            )),
+            row_count,
+            row_height: 30,
+            highlighted_item: -1,
+            selection: ListSelection::new(SingleSelection),
+            scroll_bar: ScrollBar::new(ScrollBarVertical),
+            mouse_point: make_points(0, 0),
+            in_bounds: false,
+            on_selected: None,
+        }
+    }
+
+    /// Replaces the `ListModel`, clearing the selection, and scrolling back to the top of the list.
+    pub fn set_model(&mut self, model: Box<dyn ListModel>) {
+        self.model = model;
+        self.row_count = self.model.row_count();
+        self.selection.clear();
+        self.highlighted_item = -1;
+        self.scroll_bar.set_position(0);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the `ListItemRenderer` used to draw each row.
+    pub fn set_renderer(&mut self, renderer: Box<dyn ListItemRenderer>) {
+        self.renderer = renderer;
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Redraws the visible rows.  Call this when the data in the model changes without changing
+    /// the number of rows.
+    pub fn refresh(&mut self) {
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the height of each row in the list, in pixels.
+    pub fn set_row_height(&mut self, row_height: u32) {
+        self.row_height = row_height.max(1);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Returns the indexes of the selected rows, in ascending order.
+    pub fn get_selected(&self) -> Vec<usize> {
+        self.selection.get_selected()
+    }
+
+    /// Sets the selected rows by their indexes.  Indexes that are out of range are ignored.  In
+    /// `SingleSelection` mode, only the last index is selected.  This does not trigger the
+    /// `on_selected` callback.
+    pub fn set_selected(&mut self, rows: Vec<usize>) {
+        self.selection.set_selected(rows, self.row_count);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Sets the selection mode of the `VirtualListWidget`.
+    pub fn set_selection_mode(&mut self, selection_mode: ListSelectionMode) {
+        self.selection.set_selection_mode(selection_mode);
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Scrolls the list so that the row at `index` is fully visible.
+    pub fn scroll_to_item(&mut self, index: usize) {
+        self.update_scroll_bar();
+
+        let item_top = (index as u32 * self.row_height) as i32;
+        let item_bottom = item_top + self.row_height as i32;
+        let viewport_height = self.scroll_bar.get_viewport_length() as i32;
+        let position = self.scroll_bar.get_position() as i32;
+
+        let changed = if item_top < position {
+            self.scroll_bar.set_position(item_top)
+        } else if item_bottom > position + viewport_height {
+            self.scroll_bar.set_position(item_bottom - viewport_height)
+        } else {
+            false
+        };
+
+        if changed {
+            self.get_config().set_invalidated(true);
+        }
+    }
+
+    /// Assigns the callback closure that will be used when a row is selected.  The row is the one
+    /// that was last clicked or navigated to, or `-1` if the selection was cleared: use
+    /// `get_selected` to retrieve the full selection.
+    pub fn on_selected<F>(&mut self, callback: F)
+    where
+        F: FnMut(&mut VirtualListWidget, &[WidgetContainer], &[LayoutContainer], i32) + 'static,
+    {
+        self.on_selected = Some(Box::new(callback));
+    }
+
+    /// Internal function that triggers the `on_selected` callback.
+    fn call_selected_callback(
+        &mut self,
+        widgets: &[WidgetContainer],
+        layouts: &[LayoutContainer],
+        selected_item: i32,
+    ) {
+        if let Some(mut cb) = self.on_selected.take() {
+            cb(self, widgets, layouts, selected_item);
+            self.on_selected = Some(cb);
+        }
+    }
+
+    /// Internal function that updates the `ScrollBar` to match the current size of the `Widget` and
+    /// the number of rows.  Returns the track area of the scroll bar relative to the `Widget`, if
+    /// it is shown.
+    fn update_scroll_bar(&mut self) -> Option<Rect> {
+        let bounds = self.get_config().get_size(CONFIG_SIZE);
+        let viewport_height = bounds[SIZE_HEIGHT].saturating_sub(2);
+
+        self.scroll_bar
+            .set_lengths(self.row_count as u32 * self.row_height, viewport_height);
+
+        if self.scroll_bar.is_needed() {
+            Some(Rect::new(
+                bounds[SIZE_WIDTH].saturating_sub(SCROLL_BAR_THICKNESS + 1) as i32,
+                1,
+                SCROLL_BAR_THICKNESS,
+                viewport_height.max(1),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// This is the `Widget` implementation of the `VirtualListWidget`.
+impl Widget for VirtualListWidget {
+    /// Draws the visible rows of the `VirtualListWidget`.
+    fn draw(&mut self, c: &mut Canvas<Window>, t: &mut TextureCache) -> Option<&Texture> {
+        if self.get_config().invalidated() {
+            let bounds = self.get_config().get_size(CONFIG_SIZE);
+            let track = self.update_scroll_bar();
+
+            self.texture_store
+                .create_or_resize_texture(c, bounds[0], bounds[1]);
+
+            let base_color = self.get_config().get_color(CONFIG_COLOR_BASE);
+            let hover_color = self.get_config().get_color(CONFIG_COLOR_HOVER);
+            let selected_color = self.get_config().get_color(CONFIG_COLOR_SELECTED);
+            let border_color = self.get_config().get_color(CONFIG_COLOR_BORDER);
+            let thumb_color = self.get_config().get_color(CONFIG_COLOR_SECONDARY);
+            let focused = self.get_config().is_focused();
+            let row_height = self.row_height;
+            let row_width = match track {
+                Some(track) => track.x() as u32 - 1,
+                None => bounds[SIZE_WIDTH].saturating_sub(2),
+            }
+            .max(1);
+            let viewport_height = bounds[SIZE_HEIGHT].saturating_sub(2).max(1);
+            let position = self.scroll_bar.get_position();
+            let first_row = (position / row_height) as usize;
+            let last_row =
+                (((position + self.scroll_bar.get_viewport_length()) / row_height) as usize + 1)
+                    .min(self.row_count);
+            let rows: Vec<(ListItemState, Vec<String>, i32)> = (first_row..last_row)
+                .map(|row| {
+                    (
+                        ListItemState {
+                            row,
+                            selected: self.selection.is_selected(row),
+                            highlighted: self.highlighted_item == row as i32,
+                            cursor: self.selection.get_cursor() == row as i32,
+                        },
+                        self.model.row_data(row),
+                        1 + (row as u32 * row_height) as i32 - position as i32,
+                    )
+                })
+                .collect();
+            let renderer = &mut self.renderer;
+            let scroll_bar = &self.scroll_bar;
+
+            c.with_texture_canvas(self.texture_store.get_mut_ref(), |texture| {
+                texture.set_draw_color(base_color);
+                texture.clear();
+
+                // Rows are clipped so that partially visible rows do not draw over the border.
+                texture.set_clip_rect(Rect::new(1, 1, row_width, viewport_height));
+
+                for (state, data, row_y) in &rows {
+                    let area = Rect::new(1, *row_y, row_width, row_height);
+                    let color = if state.selected {
+                        selected_color
+                    } else if state.highlighted {
+                        hover_color
+                    } else {
+                        base_color
+                    };
+
+                    texture.set_draw_color(color);
+                    texture.fill_rect(area).unwrap();
+
+                    renderer.draw_item(texture, t, data, area, state);
+
+                    if focused && state.cursor {
+                        texture.set_draw_color(hover_color);
+                        texture.draw_rect(area).unwrap();
+                    }
+                }
+
+                texture.set_clip_rect(None);
+
+                if let Some(track) = track {
+                    scroll_bar.draw(texture, track, base_color, thumb_color);
+                }
+
+                texture.set_draw_color(border_color);
+                texture
+                    .draw_rect(Rect::new(0, 0, bounds[0], bounds[1]))
+                    .unwrap();
+            })
+            .unwrap();
+        }
+
+        self.texture_store.get_optional_ref()
+    }
+
+    /// When a mouse enters the bounds of the `Widget`, this function is triggered.
+    fn mouse_entered(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = true;
+        self.mouse_entered_callback(_widgets, _layouts);
+    }
+
+    /// When a mouse exits the bounds of the `Widget`, this function is triggered.
+    fn mouse_exited(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        self.in_bounds = false;
+        self.highlighted_item = -1;
+        self.get_config().set_invalidated(true);
+        self.mouse_exited_callback(_widgets, _layouts);
+    }
+
+    /// Highlights the row under the mouse, or drags the scroll bar thumb.
+    fn mouse_moved(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        let origin = self.get_config().get_point(CONFIG_ORIGIN);
+        let x = _points[POINT_X] - origin[POINT_X];
+        let y = _points[POINT_Y] - origin[POINT_Y];
+        let track = self.update_scroll_bar();
+
+        self.mouse_point = make_points(x, y);
+
+        if let Some(track) = track {
+            if self.scroll_bar.mouse_moved(track, x, y) {
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        if self.in_bounds {
+            let previous_highlighted_item = self.highlighted_item;
+            let in_track = track.is_some_and(|track| x >= track.x());
+
+            self.highlighted_item =
+                ((y - 1 + self.scroll_bar.get_position() as i32) / self.row_height as i32).max(-1);
+
+            if in_track || y < 1 || self.highlighted_item >= self.row_count as i32 {
+                self.highlighted_item = -1;
+            }
+
+            if self.highlighted_item != previous_highlighted_item {
+                self.get_config().set_invalidated(true);
+            }
+        }
+
+        self.mouse_moved_callback(_widgets, _layouts, _points);
+    }
+
+    /// Scrolls the list by one row for each step of the mouse wheel.
+    fn mouse_scrolled(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _points: Points,
+    ) {
+        self.update_scroll_bar();
+
+        if self
+            .scroll_bar
+            .scroll_by(-_points[POINT_Y] * self.row_height as i32)
+        {
+            self.highlighted_item = -1;
+            self.get_config().set_invalidated(true);
+        }
+
+        self.mouse_scrolled_callback(_widgets, _layouts, _points);
+    }
+
+    /// Selects rows, and handles the scroll bar.  In `MultipleSelection` mode, `Ctrl`-click toggles
+    /// a row, and `Shift`-click selects a range.
+    fn button_clicked(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _button: u8,
+        _clicks: u8,
+        _state: bool,
+    ) {
+        if _button == 1 && !_state {
+            self.scroll_bar.button_up();
+        } else if _button == 1 && self.in_bounds {
+            let track = self.update_scroll_bar();
+            let x = self.mouse_point[POINT_X];
+            let y = self.mouse_point[POINT_Y];
+
+            if track.is_some_and(|track| self.scroll_bar.button_down(track, x, y)) {
+                self.get_config().set_invalidated(true);
+            } else {
+                if self.highlighted_item >= 0 {
+                    self.selection
+                        .click_item(self.highlighted_item as usize, mod_state());
+                } else {
+                    self.selection.deselect_all();
+                }
+
+                self.get_config().set_invalidated(true);
+                self.call_selected_callback(_widgets, _layouts, self.highlighted_item);
+            }
+        }
+
+        self.button_clicked_callback(_widgets, _layouts, _button, _clicks, _state);
+    }
+
+    /// Handles keyboard navigation of the list.
+    fn key_pressed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _keycode: Keycode,
+        _keymod: Mod,
+    ) {
+        self.update_scroll_bar();
+
+        let page_size = (self.scroll_bar.get_viewport_length() / self.row_height) as usize;
+
+        if let Some((item, changed)) =
+            self.selection
+                .key_pressed(_keycode, _keymod, self.row_count, page_size)
+        {
+            self.get_config().set_invalidated(true);
+
+            if changed {
+                self.call_selected_callback(_widgets, _layouts, item as i32);
+            }
+
+            self.scroll_to_item(item);
+        }
+
+        self.key_pressed_callback(_widgets, _layouts, _keycode, _keymod);
+    }
+
+    /// Redraws the cursor when focus changes.
+    fn focus_changed(
+        &mut self,
+        _widgets: &[WidgetContainer],
+        _layouts: &[LayoutContainer],
+        _focused: bool,
+    ) {
+        self.get_config().set_invalidated(true);
+    }
+
+    /// Checks the number of rows in the model, redrawing the list if it has changed.
+    fn tick(&mut self, _widgets: &[WidgetContainer], _layouts: &[LayoutContainer]) {
+        let row_count = self.model.row_count();
+
+        if row_count != self.row_count {
+            if row_count < self.row_count {
+                self.selection.truncate(row_count);
+                self.highlighted_item = -1;
+            }
+
+            self.row_count = row_count;
+            self.update_scroll_bar();
+            self.get_config().set_invalidated(true);
+        }
+
+        self.tick_callback(_widgets, _layouts);
+    }
+
+    default_widget_functions!();
+    default_widget_properties!();
+    default_widget_callbacks!();
+}
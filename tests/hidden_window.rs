@@ -0,0 +1,43 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::engine::Engine;
+use pushrod::render::hidden_window::HiddenWindowCanvas;
+use pushrod::render::widget::{BaseWidget, Widget};
+use pushrod::render::widget_config::{CONFIG_COLOR_BASE, CONFIG_COLOR_BORDER};
+use pushrod::render::{make_points, make_size};
+use sdl2::hint::{self, Hint};
+use sdl2::pixels::Color;
+
+/// Draws two bordered `BaseWidget`s, and compares the result against a golden image.  Only
+/// filled and outlined rectangles are drawn, so the image does not depend on font rendering.
+#[test]
+fn test_base_widgets_match_golden_image() {
+    // Prefer the dummy video driver, so that no display is needed.  An SDL_VIDEODRIVER
+    // environment variable still takes precedence over this hint.
+    hint::set_with_priority("SDL_VIDEODRIVER", "dummy", &Hint::Default);
+
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let mut hidden_window = HiddenWindowCanvas::new(&video_subsystem, 64, 48).unwrap();
+    let mut engine = Engine::new(64, 48, 60);
+    let mut widget1 = BaseWidget::new(make_points(8, 8), make_size(24, 16));
+    let mut widget2 = BaseWidget::new(make_points(36, 20), make_size(20, 20));
+
+    widget1.set_color(CONFIG_COLOR_BASE, Color::RGB(255, 0, 0));
+    widget1.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 0, 0));
+    widget2.set_color(CONFIG_COLOR_BASE, Color::RGB(0, 0, 255));
+    widget2.set_color(CONFIG_COLOR_BORDER, Color::RGB(0, 255, 0));
+
+    engine.add_widget(Box::new(widget1), String::from("widget1"));
+    engine.add_widget(Box::new(widget2), String::from("widget2"));
+
+    hidden_window.render(&mut engine);
+
+    assert_eq!(
+        hidden_window
+            .compare_png("tests/golden/base_widgets.png", 2)
+            .unwrap(),
+        0
+    );
+}
@@ -0,0 +1,42 @@
+extern crate pushrod;
+extern crate sdl2;
+
+use pushrod::render::clock::set_manual_time;
+use pushrod::render::engine::Engine;
+use pushrod::render::event_recorder::EventPlayer;
+use pushrod::render::hidden_window::HiddenWindowCanvas;
+use pushrod::widgets::timer_widget::TimerWidget;
+use sdl2::hint::{self, Hint};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Replays a recording that spans one and a half seconds against an `Engine` containing a
+/// one-second `TimerWidget`, and checks that the timer fires exactly once.
+#[test]
+fn test_replay_fires_timer() {
+    hint::set_with_priority("SDL_VIDEODRIVER", "dummy", &Hint::Default);
+
+    let sdl_context = sdl2::init().unwrap();
+    let video_subsystem = sdl_context.video().unwrap();
+    let mut hidden_window = HiddenWindowCanvas::new(&video_subsystem, 64, 48).unwrap();
+    let mut player = EventPlayer::parse("0 mouse_motion 10 10\n1500 mouse_motion 20 20\n").unwrap();
+
+    player.start_clock(1_000_000);
+
+    let mut engine = Engine::new(64, 48, 60);
+    let mut timer = TimerWidget::new(1000, true);
+    let fired = Rc::new(Cell::new(0));
+    let timer_fired = fired.clone();
+
+    timer.on_timeout(move |_, _widgets, _layouts| {
+        timer_fired.set(timer_fired.get() + 1);
+    });
+
+    engine.add_widget(Box::new(timer), String::from("timer"));
+
+    player.play(&mut engine, hidden_window.get_canvas());
+    set_manual_time(None);
+
+    assert!(player.is_finished());
+    assert_eq!(fired.get(), 1);
+}